@@ -1,7 +1,9 @@
 use dotenvy::dotenv;
 use dropboxsign_client::{
     DropboxSignClient,
-    signature_request::{SendSignatureRequest, SubCustomField, SubSignatureRequestTemplateSigner},
+    signature_request::{
+        SendWithTemplateRequest, SubCustomField, SubSignatureRequestTemplateSigner,
+    },
 };
 
 #[tokio::main]
@@ -30,7 +32,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let client = DropboxSignClient::new(&api_key);
 
     let signature_request =
-        SendSignatureRequest::new(vec![signer], vec![template_id]).custom_fields(custom_fields);
+        SendWithTemplateRequest::new(vec![signer], vec![template_id]).custom_fields(custom_fields);
 
     let (response, warnings) = client.send_with_template(signature_request).await?;
 
@@ -0,0 +1,479 @@
+//! Data models and types for account operations.
+//!
+//! This module contains the data structures needed for reading and managing
+//! Dropbox Sign accounts through the API.
+
+use crate::id::{AccountId, ClientId};
+use crate::oauth::OAuthTokenResponse;
+use crate::validate::{Checks, Validate, ValidationError};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Request structure for updating account settings.
+///
+/// Only the fields that are set are sent, so unrelated settings are left untouched.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::account::UpdateAccountRequest;
+///
+/// let request = UpdateAccountRequest::new()
+///     .callback_url("https://example.com/dropboxsign/callback".to_string());
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpdateAccountRequest {
+    /// Identifier of the account to update (defaults to the caller's account)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<AccountId>,
+    /// URL that receives account callback events
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub callback_url: Option<String>,
+    /// Locale used for the account's emails and signing pages
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locale: Option<Locale>,
+}
+
+/// Request body for creating a new Dropbox Sign account.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateAccountRequest {
+    /// Email address of the account to create
+    pub email_address: String,
+    /// Client ID of the API app creating the account
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<ClientId>,
+    /// Client secret of the API app creating the account
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
+}
+
+/// Response data for a newly created account.
+///
+/// When the account is created through an API app, the response also carries
+/// OAuth credentials for acting on behalf of the new account.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AccountCreateResponse {
+    /// The newly created account
+    pub account: AccountResponse,
+    /// OAuth credentials issued to the API app for this account
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oauth_data: Option<OAuthTokenResponse>,
+}
+
+/// Request body for verifying whether an account exists.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VerifyAccountRequest {
+    /// Email address to look up
+    pub email_address: String,
+}
+
+/// Response data for an account verification.
+///
+/// `account` is only present when the email address belongs to an existing
+/// paid Dropbox Sign account.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AccountVerifyResponse {
+    /// The matching account, if one exists
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account: Option<AccountVerifyResponseAccount>,
+}
+
+/// Account found by an account verification.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AccountVerifyResponseAccount {
+    /// Email address of the existing account
+    pub email_address: String,
+}
+
+/// Complete response data for a Dropbox Sign account.
+///
+/// Contains the account identity, plan flags, callback configuration and the
+/// remaining quotas for the current billing period.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AccountResponse {
+    /// Unique identifier for this account
+    pub account_id: AccountId,
+    /// Email address associated with this account
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<String>,
+    /// Whether the account is locked (e.g. because of a billing problem)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_locked: Option<bool>,
+    /// Whether the account has a paid Dropbox Sign plan
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_paid_hs: Option<bool>,
+    /// Whether the account has a paid Dropbox Fax plan
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_paid_hf: Option<bool>,
+    /// Remaining quotas for the current billing period
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quotas: Option<AccountResponseQuotas>,
+    /// Usage of the account in the current billing period
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usage: Option<AccountResponseUsage>,
+    /// URL that receives account callback events
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub callback_url: Option<String>,
+    /// Role of the account within its team (a = admin, m = member, ...)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role_code: Option<String>,
+    /// Identifier of the team this account belongs to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+    /// Locale used for the account's emails and signing pages
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locale: Option<Locale>,
+    /// Fields returned by the API that this crate does not model yet
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "crate::strict::extra",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    pub extra: HashMap<String, Value>,
+}
+
+/// Remaining quotas for an account.
+///
+/// A missing value means the quota is unlimited on the account's plan.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AccountResponseQuotas {
+    /// API signature requests remaining
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_signature_requests_left: Option<i64>,
+    /// Signature requests remaining
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub documents_left: Option<i64>,
+    /// Total API templates allowed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub templates_total: Option<i64>,
+    /// API templates remaining
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub templates_left: Option<i64>,
+    /// SMS verifications remaining
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sms_verifications_left: Option<i64>,
+    /// Fax pages remaining
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_fax_pages_left: Option<i64>,
+}
+
+/// Usage of an account in the current billing period.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AccountResponseUsage {
+    /// Fax pages sent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fax_pages_sent: Option<i64>,
+}
+
+impl UpdateAccountRequest {
+    /// Creates an empty update request that leaves every setting unchanged.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the account to update.
+    ///
+    /// # Arguments
+    ///
+    /// * `account_id` - Identifier of the account to update
+    pub fn account_id(mut self, account_id: impl Into<AccountId>) -> Self {
+        self.account_id = Some(account_id.into());
+        self
+    }
+
+    /// Sets the URL that receives account callback events.
+    ///
+    /// # Arguments
+    ///
+    /// * `callback_url` - Publicly reachable callback URL
+    pub fn callback_url(mut self, callback_url: String) -> Self {
+        self.callback_url = Some(callback_url);
+        self
+    }
+
+    /// Sets the locale used for the account's emails and signing pages.
+    ///
+    /// # Arguments
+    ///
+    /// * `locale` - Locale of the account
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = Some(locale);
+        self
+    }
+}
+
+impl CreateAccountRequest {
+    /// Creates a new request for an account with the given email address.
+    ///
+    /// # Arguments
+    ///
+    /// * `email_address` - Email address of the account to create
+    pub fn new(email_address: String) -> Self {
+        Self {
+            email_address,
+            client_id: None,
+            client_secret: None,
+        }
+    }
+
+    /// Sets the client ID of the API app creating the account.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - Client ID of the API app
+    pub fn client_id(mut self, client_id: impl Into<ClientId>) -> Self {
+        self.client_id = Some(client_id.into());
+        self
+    }
+
+    /// Sets the client secret of the API app creating the account.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_secret` - Client secret of the API app
+    pub fn client_secret(mut self, client_secret: String) -> Self {
+        self.client_secret = Some(client_secret);
+        self
+    }
+}
+
+impl VerifyAccountRequest {
+    /// Creates a new request to look up the given email address.
+    ///
+    /// # Arguments
+    ///
+    /// * `email_address` - Email address to look up
+    pub fn new(email_address: String) -> Self {
+        Self { email_address }
+    }
+}
+
+impl AccountResponseQuotas {
+    /// Returns whether the account can send `count` more API signature requests.
+    ///
+    /// An unset quota is treated as unlimited.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - Number of signature requests about to be sent
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::DropboxSignClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::new("your-api-key");
+    /// let (account, _) = client.get_account().await?;
+    ///
+    /// if account.quotas.is_some_and(|quotas| quotas.can_send(10)) {
+    ///     // send the batch
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn can_send(&self, count: u64) -> bool {
+        quota_allows(self.api_signature_requests_left, count)
+    }
+
+    /// Returns whether the account can create `count` more API templates.
+    ///
+    /// An unset quota is treated as unlimited.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - Number of templates about to be created
+    pub fn can_create_templates(&self, count: u64) -> bool {
+        quota_allows(self.templates_left, count)
+    }
+
+    /// Returns whether the account can send `count` more SMS verifications.
+    ///
+    /// An unset quota is treated as unlimited.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - Number of SMS verifications about to be used
+    pub fn can_send_sms_verifications(&self, count: u64) -> bool {
+        quota_allows(self.sms_verifications_left, count)
+    }
+}
+
+/// Defines [`Locale`] from its variants and their locale codes.
+macro_rules! locales {
+    ($($(#[$doc:meta])* $variant:ident => $code:literal,)*) => {
+        /// Locales supported for emails and signing pages.
+        ///
+        /// The API falls back to English for a locale it does not support, so
+        /// requests with a [`Locale::Unknown`] locale fail validation instead of
+        /// being sent. Locales added to the API after this crate was released are
+        /// kept in [`Locale::Unknown`] when they appear in responses.
+        ///
+        /// # Examples
+        ///
+        /// ```no_run
+        /// use dropboxsign_client::account::{Locale, UpdateAccountRequest};
+        ///
+        /// let request = UpdateAccountRequest::new().locale(Locale::FrFr);
+        /// assert_eq!(Locale::from("fr-FR"), Locale::FrFr);
+        /// ```
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum Locale {
+            $($(#[$doc])* $variant,)*
+            /// A locale this crate does not know about
+            Unknown(String),
+        }
+
+        impl Locale {
+            /// Returns the locale code as it appears in API requests and
+            /// responses, e.g. `en-US`.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $code,)*
+                    Self::Unknown(code) => code,
+                }
+            }
+        }
+
+        impl From<&str> for Locale {
+            fn from(code: &str) -> Self {
+                match code {
+                    $($code => Self::$variant,)*
+                    other => Self::Unknown(other.to_string()),
+                }
+            }
+        }
+    };
+}
+
+locales! {
+    /// Czech (Czech Republic)
+    CsCz => "cs-CZ",
+    /// Danish (Denmark)
+    DaDk => "da-DK",
+    /// German (Germany)
+    DeDe => "de-DE",
+    /// English (United Kingdom)
+    EnGb => "en-GB",
+    /// English (United States)
+    EnUs => "en-US",
+    /// Spanish (Spain)
+    EsEs => "es-ES",
+    /// Spanish (Mexico)
+    EsMx => "es-MX",
+    /// Finnish (Finland)
+    FiFi => "fi-FI",
+    /// French (France)
+    FrFr => "fr-FR",
+    /// Indonesian (Indonesia)
+    IdId => "id-ID",
+    /// Italian (Italy)
+    ItIt => "it-IT",
+    /// Japanese (Japan)
+    JaJp => "ja-JP",
+    /// Korean (South Korea)
+    KoKr => "ko-KR",
+    /// Malay (Malaysia)
+    MsMy => "ms-MY",
+    /// Norwegian Bokmål (Norway)
+    NbNo => "nb-NO",
+    /// Dutch (Netherlands)
+    NlNl => "nl-NL",
+    /// Polish (Poland)
+    PlPl => "pl-PL",
+    /// Portuguese (Brazil)
+    PtBr => "pt-BR",
+    /// Russian (Russia)
+    RuRu => "ru-RU",
+    /// Swedish (Sweden)
+    SvSe => "sv-SE",
+    /// Thai (Thailand)
+    ThTh => "th-TH",
+    /// Turkish (Turkey)
+    TrTr => "tr-TR",
+    /// Ukrainian (Ukraine)
+    UkUa => "uk-UA",
+    /// Vietnamese (Vietnam)
+    ViVn => "vi-VN",
+    /// Chinese (China)
+    ZhCn => "zh-CN",
+    /// Chinese (Taiwan)
+    ZhTw => "zh-TW",
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for Locale {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Locale {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        Ok(Self::from(code.as_str()))
+    }
+}
+
+/// Checks a remaining quota, where `None` means the quota is unlimited.
+fn quota_allows(remaining: Option<i64>, count: u64) -> bool {
+    match remaining {
+        Some(remaining) => u64::try_from(remaining).is_ok_and(|remaining| remaining >= count),
+        None => true,
+    }
+}
+
+impl Validate for UpdateAccountRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut checks = Checks::new();
+
+        if let Some(account_id) = &self.account_id {
+            checks.not_blank(account_id.as_str(), "account_id");
+        }
+        if let Some(callback_url) = &self.callback_url {
+            checks.not_blank(callback_url, "callback_url");
+        }
+        if let Some(locale) = &self.locale {
+            checks.check(
+                !matches!(locale, Locale::Unknown(_)),
+                "locale",
+                "must be a supported locale",
+            );
+        }
+
+        checks.finish()
+    }
+}
+
+impl Validate for CreateAccountRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut checks = Checks::new();
+
+        checks.email(&self.email_address, "email_address");
+        checks.check(
+            self.client_id.is_some() == self.client_secret.is_some(),
+            "client_secret",
+            "must be set together with client_id",
+        );
+
+        checks.finish()
+    }
+}
+
+impl Validate for VerifyAccountRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut checks = Checks::new();
+
+        checks.email(&self.email_address, "email_address");
+
+        checks.finish()
+    }
+}
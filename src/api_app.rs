@@ -0,0 +1,784 @@
+//! Data models and types for API app operations.
+//!
+//! This module contains the data structures needed for reading and managing
+//! API apps, the configuration behind embedded flows and OAuth integrations.
+
+use crate::file::UploadFile;
+use crate::id::{AccountId, ClientId};
+use crate::validate::{Checks, Validate, ValidationError};
+use crate::{ListItem, Page};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Request structure for creating an API app.
+///
+/// Requires a name and at least one domain the app will be embedded on, with
+/// optional callback, OAuth, branding and logo configuration.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::api_app::*;
+///
+/// let request = CreateApiAppRequest::new(
+///     "Staging".to_string(),
+///     vec!["staging.example.com".to_string()]
+/// )
+/// .callback_url("https://staging.example.com/dropboxsign/callback".to_string());
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateApiAppRequest {
+    /// Name of the API app
+    pub name: String,
+    /// Domains the API app is allowed to be embedded on
+    pub domains: Vec<String>,
+    /// URL that receives app callback events
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub callback_url: Option<String>,
+    /// Logo image shown on the embedded pages, uploaded as multipart form data
+    #[serde(skip)]
+    pub custom_logo_file: Option<UploadFile>,
+    /// OAuth configuration for acting on behalf of users
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oauth: Option<SubOAuth>,
+    /// Additional options for the API app
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options: Option<SubOptions>,
+    /// Branding applied to the embedded pages
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub white_labeling_options: Option<SubWhiteLabelingOptions>,
+}
+
+/// Request structure for updating an API app.
+///
+/// Every field is optional and only the fields that are set are sent, so a
+/// partial update leaves the rest of the configuration untouched.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::api_app::UpdateApiAppRequest;
+///
+/// let request = UpdateApiAppRequest::new()
+///     .callback_url("https://example.com/dropboxsign/callback".to_string());
+/// ```
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct UpdateApiAppRequest {
+    /// Name of the API app
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Domains the API app is allowed to be embedded on
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domains: Option<Vec<String>>,
+    /// URL that receives app callback events
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub callback_url: Option<String>,
+    /// Logo image shown on the embedded pages, uploaded as multipart form data
+    #[serde(skip)]
+    pub custom_logo_file: Option<UploadFile>,
+    /// OAuth configuration for acting on behalf of users
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oauth: Option<SubOAuth>,
+    /// Additional options for the API app
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options: Option<SubOptions>,
+    /// Branding applied to the embedded pages
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub white_labeling_options: Option<SubWhiteLabelingOptions>,
+}
+
+/// OAuth configuration for an API app.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::api_app::*;
+///
+/// let oauth = SubOAuth::new(
+///     "https://example.com/oauth/callback".to_string(),
+///     vec![OAuthScope::BasicAccountInfo, OAuthScope::RequestSignature]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubOAuth {
+    /// URL users are redirected to after authorizing the app
+    pub callback_url: String,
+    /// Scopes the app requests from users
+    pub scopes: Vec<OAuthScope>,
+}
+
+/// Permissions an API app can request from users through OAuth.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OAuthScope {
+    /// Read the user's basic account information
+    BasicAccountInfo,
+    /// Send signature requests on behalf of the user
+    RequestSignature,
+    /// Manage the user's account settings
+    AccountAccess,
+    /// Read and manage the user's signature requests
+    SignatureRequestAccess,
+    /// Read and manage the user's templates
+    TemplateAccess,
+    /// Read and manage the user's team
+    TeamAccess,
+    /// Read and manage the user's API apps
+    ApiAppAccess,
+}
+
+/// Additional options for an API app.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::api_app::SubOptions;
+///
+/// let options = SubOptions::new(true);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubOptions {
+    /// Whether signers can "Insert Everywhere" when signing
+    pub can_insert_everywhere: bool,
+}
+
+/// Branding applied to the embedded pages of an API app.
+///
+/// Colors are hex strings such as `#1A1A1A`; unset fields keep their defaults.
+/// Use [`SubWhiteLabelingOptions::builder`] to have colors validated before the
+/// options are sent.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::api_app::*;
+///
+/// # fn example() -> Result<(), WhiteLabelingOptionsError> {
+/// let options = SubWhiteLabelingOptions::builder()
+///     .primary_button_color("#00B3E6")
+///     .primary_button_text_color("#FFFFFF")
+///     .legal_version(LegalVersion::Terms1)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubWhiteLabelingOptions {
+    /// Color of the page header
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub header_background_color: Option<String>,
+    /// Version of the legal terms shown to signers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub legal_version: Option<LegalVersion>,
+    /// Color of links
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link_color: Option<String>,
+    /// Background color of the page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_background_color: Option<String>,
+    /// Background color of primary buttons
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary_button_color: Option<String>,
+    /// Background color of primary buttons on hover
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary_button_color_hover: Option<String>,
+    /// Text color of primary buttons
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary_button_text_color: Option<String>,
+    /// Text color of primary buttons on hover
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary_button_text_color_hover: Option<String>,
+    /// Background color of secondary buttons
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secondary_button_color: Option<String>,
+    /// Background color of secondary buttons on hover
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secondary_button_color_hover: Option<String>,
+    /// Text color of secondary buttons
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secondary_button_text_color: Option<String>,
+    /// Text color of secondary buttons on hover
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secondary_button_text_color_hover: Option<String>,
+    /// Primary text color
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text_color1: Option<String>,
+    /// Secondary text color
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text_color2: Option<String>,
+    /// Whether white labeling is reset to the default branding
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reset_to_default: Option<bool>,
+}
+
+/// Versions of the legal terms that can be shown to signers.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LegalVersion {
+    /// The standard Dropbox Sign terms
+    Terms1,
+    /// The alternative Dropbox Sign terms
+    Terms2,
+}
+
+/// Builder for [`SubWhiteLabelingOptions`] that validates colors on `build`.
+#[derive(Debug, Default)]
+pub struct SubWhiteLabelingOptionsBuilder {
+    options: SubWhiteLabelingOptions,
+}
+
+/// Errors that can occur when building white-labeling options.
+#[derive(Error, Debug)]
+pub enum WhiteLabelingOptionsError {
+    #[error("invalid hex color for {field}: {value:?}")]
+    InvalidColor { field: &'static str, value: String },
+}
+
+/// Complete response data for an API app.
+///
+/// Contains the app's callback configuration, OAuth settings, white labeling and
+/// the account that owns it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApiAppResponse {
+    /// Client ID identifying this API app
+    pub client_id: ClientId,
+    /// Name of the API app
+    pub name: String,
+    /// URL that receives app callback events
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub callback_url: Option<String>,
+    /// Unix timestamp when the API app was created
+    pub created_at: u64,
+    /// Domains the API app is allowed to be embedded on
+    #[serde(default)]
+    pub domains: Vec<String>,
+    /// Whether the API app has been approved for production use
+    pub is_approved: bool,
+    /// OAuth configuration, if OAuth is enabled for the app
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oauth: Option<ApiAppResponseOAuth>,
+    /// Additional options for the API app
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options: Option<ApiAppResponseOptions>,
+    /// Account that owns the API app
+    pub owner_account: ApiAppResponseOwnerAccount,
+    /// Branding applied to the embedded pages of the API app
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub white_labeling_options: Option<ApiAppResponseWhiteLabelingOptions>,
+    /// Fields returned by the API that this crate does not model yet
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "crate::strict::extra",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    pub extra: HashMap<String, Value>,
+}
+
+/// OAuth configuration of an API app.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApiAppResponseOAuth {
+    /// URL users are redirected to after authorizing the app
+    pub callback_url: String,
+    /// Secret used when exchanging OAuth codes for tokens
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    /// Scopes the app requests from users
+    #[serde(default)]
+    pub scopes: Vec<String>,
+    /// Whether the app is billed for signature requests sent on behalf of users
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub charges_users: Option<bool>,
+}
+
+/// Additional options of an API app.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApiAppResponseOptions {
+    /// Whether signers can "Insert Everywhere" when signing
+    pub can_insert_everywhere: bool,
+}
+
+/// Account that owns an API app.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApiAppResponseOwnerAccount {
+    /// Unique identifier of the owner account
+    pub account_id: AccountId,
+    /// Email address of the owner account
+    pub email_address: String,
+}
+
+/// Branding applied to the embedded pages of an API app.
+///
+/// Colors are returned as hex strings such as `#1A1A1A`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApiAppResponseWhiteLabelingOptions {
+    /// Color of the page header
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub header_background_color: Option<String>,
+    /// Version of the legal terms shown to signers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub legal_version: Option<String>,
+    /// Color of links
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link_color: Option<String>,
+    /// Background color of the page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_background_color: Option<String>,
+    /// Background color of primary buttons
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary_button_color: Option<String>,
+    /// Background color of primary buttons on hover
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary_button_color_hover: Option<String>,
+    /// Text color of primary buttons
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary_button_text_color: Option<String>,
+    /// Text color of primary buttons on hover
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary_button_text_color_hover: Option<String>,
+    /// Background color of secondary buttons
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secondary_button_color: Option<String>,
+    /// Background color of secondary buttons on hover
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secondary_button_color_hover: Option<String>,
+    /// Text color of secondary buttons
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secondary_button_text_color: Option<String>,
+    /// Text color of secondary buttons on hover
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secondary_button_text_color_hover: Option<String>,
+    /// Primary text color
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text_color1: Option<String>,
+    /// Secondary text color
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text_color2: Option<String>,
+}
+
+/// A page of API apps.
+pub type ApiAppsResponse = Page<ApiAppResponse>;
+
+impl ListItem for ApiAppResponse {
+    const LIST_KEY: &'static str = "api_apps";
+}
+
+impl CreateApiAppRequest {
+    /// Creates a new API app request with the required fields.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the API app
+    /// * `domains` - Domains the API app is allowed to be embedded on
+    pub fn new(name: String, domains: Vec<String>) -> Self {
+        Self {
+            name,
+            domains,
+            callback_url: None,
+            custom_logo_file: None,
+            oauth: None,
+            options: None,
+            white_labeling_options: None,
+        }
+    }
+
+    /// Sets the URL that receives app callback events.
+    ///
+    /// # Arguments
+    ///
+    /// * `callback_url` - Publicly reachable callback URL
+    pub fn callback_url(mut self, callback_url: String) -> Self {
+        self.callback_url = Some(callback_url);
+        self
+    }
+
+    /// Sets the logo image shown on the embedded pages.
+    ///
+    /// # Arguments
+    ///
+    /// * `custom_logo_file` - Image file contents, path or reader
+    pub fn custom_logo_file(mut self, custom_logo_file: impl Into<UploadFile>) -> Self {
+        self.custom_logo_file = Some(custom_logo_file.into());
+        self
+    }
+
+    /// Sets the OAuth configuration for acting on behalf of users.
+    ///
+    /// # Arguments
+    ///
+    /// * `oauth` - OAuth callback URL and scopes
+    pub fn oauth(mut self, oauth: SubOAuth) -> Self {
+        self.oauth = Some(oauth);
+        self
+    }
+
+    /// Sets additional options for the API app.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Additional API app options
+    pub fn options(mut self, options: SubOptions) -> Self {
+        self.options = Some(options);
+        self
+    }
+
+    /// Sets the branding applied to the embedded pages.
+    ///
+    /// # Arguments
+    ///
+    /// * `white_labeling_options` - Branding colors and legal version
+    pub fn white_labeling_options(
+        mut self,
+        white_labeling_options: SubWhiteLabelingOptions,
+    ) -> Self {
+        self.white_labeling_options = Some(white_labeling_options);
+        self
+    }
+}
+
+impl UpdateApiAppRequest {
+    /// Creates an empty update request that leaves the API app unchanged.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the name of the API app.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - New name of the API app
+    pub fn name(mut self, name: String) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Sets the domains the API app is allowed to be embedded on.
+    ///
+    /// # Arguments
+    ///
+    /// * `domains` - Replacement list of domains
+    pub fn domains(mut self, domains: Vec<String>) -> Self {
+        self.domains = Some(domains);
+        self
+    }
+
+    /// Sets the URL that receives app callback events.
+    ///
+    /// # Arguments
+    ///
+    /// * `callback_url` - Publicly reachable callback URL
+    pub fn callback_url(mut self, callback_url: String) -> Self {
+        self.callback_url = Some(callback_url);
+        self
+    }
+
+    /// Sets the logo image shown on the embedded pages.
+    ///
+    /// # Arguments
+    ///
+    /// * `custom_logo_file` - Image file contents, path or reader
+    pub fn custom_logo_file(mut self, custom_logo_file: impl Into<UploadFile>) -> Self {
+        self.custom_logo_file = Some(custom_logo_file.into());
+        self
+    }
+
+    /// Sets the OAuth configuration for acting on behalf of users.
+    ///
+    /// # Arguments
+    ///
+    /// * `oauth` - OAuth callback URL and scopes
+    pub fn oauth(mut self, oauth: SubOAuth) -> Self {
+        self.oauth = Some(oauth);
+        self
+    }
+
+    /// Sets additional options for the API app.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Additional API app options
+    pub fn options(mut self, options: SubOptions) -> Self {
+        self.options = Some(options);
+        self
+    }
+
+    /// Sets the branding applied to the embedded pages.
+    ///
+    /// # Arguments
+    ///
+    /// * `white_labeling_options` - Branding colors and legal version
+    pub fn white_labeling_options(
+        mut self,
+        white_labeling_options: SubWhiteLabelingOptions,
+    ) -> Self {
+        self.white_labeling_options = Some(white_labeling_options);
+        self
+    }
+}
+
+impl SubOAuth {
+    /// Creates a new OAuth configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `callback_url` - URL users are redirected to after authorizing the app
+    /// * `scopes` - Scopes the app requests from users
+    pub fn new(callback_url: String, scopes: Vec<OAuthScope>) -> Self {
+        Self {
+            callback_url,
+            scopes,
+        }
+    }
+}
+
+impl SubOptions {
+    /// Creates new API app options.
+    ///
+    /// # Arguments
+    ///
+    /// * `can_insert_everywhere` - Whether signers can "Insert Everywhere"
+    pub fn new(can_insert_everywhere: bool) -> Self {
+        Self {
+            can_insert_everywhere,
+        }
+    }
+}
+
+impl SubWhiteLabelingOptions {
+    /// Creates a builder that validates the configured colors.
+    pub fn builder() -> SubWhiteLabelingOptionsBuilder {
+        SubWhiteLabelingOptionsBuilder::default()
+    }
+
+    /// Checks that every configured color is a hex color (`#RGB` or `#RRGGBB`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `WhiteLabelingOptionsError::InvalidColor` naming the first field
+    /// with a malformed value.
+    pub fn validate(&self) -> Result<(), WhiteLabelingOptionsError> {
+        let colors = [
+            ("header_background_color", &self.header_background_color),
+            ("link_color", &self.link_color),
+            ("page_background_color", &self.page_background_color),
+            ("primary_button_color", &self.primary_button_color),
+            (
+                "primary_button_color_hover",
+                &self.primary_button_color_hover,
+            ),
+            ("primary_button_text_color", &self.primary_button_text_color),
+            (
+                "primary_button_text_color_hover",
+                &self.primary_button_text_color_hover,
+            ),
+            ("secondary_button_color", &self.secondary_button_color),
+            (
+                "secondary_button_color_hover",
+                &self.secondary_button_color_hover,
+            ),
+            (
+                "secondary_button_text_color",
+                &self.secondary_button_text_color,
+            ),
+            (
+                "secondary_button_text_color_hover",
+                &self.secondary_button_text_color_hover,
+            ),
+            ("text_color1", &self.text_color1),
+            ("text_color2", &self.text_color2),
+        ];
+
+        for (field, value) in colors {
+            if let Some(value) = value
+                && !is_hex_color(value)
+            {
+                return Err(WhiteLabelingOptionsError::InvalidColor {
+                    field,
+                    value: value.clone(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl SubWhiteLabelingOptionsBuilder {
+    /// Sets the color of the page header.
+    pub fn header_background_color(mut self, color: impl Into<String>) -> Self {
+        self.options.header_background_color = Some(color.into());
+        self
+    }
+
+    /// Sets the version of the legal terms shown to signers.
+    pub fn legal_version(mut self, legal_version: LegalVersion) -> Self {
+        self.options.legal_version = Some(legal_version);
+        self
+    }
+
+    /// Sets the color of links.
+    pub fn link_color(mut self, color: impl Into<String>) -> Self {
+        self.options.link_color = Some(color.into());
+        self
+    }
+
+    /// Sets the background color of the page.
+    pub fn page_background_color(mut self, color: impl Into<String>) -> Self {
+        self.options.page_background_color = Some(color.into());
+        self
+    }
+
+    /// Sets the background color of primary buttons.
+    pub fn primary_button_color(mut self, color: impl Into<String>) -> Self {
+        self.options.primary_button_color = Some(color.into());
+        self
+    }
+
+    /// Sets the background color of primary buttons on hover.
+    pub fn primary_button_color_hover(mut self, color: impl Into<String>) -> Self {
+        self.options.primary_button_color_hover = Some(color.into());
+        self
+    }
+
+    /// Sets the text color of primary buttons.
+    pub fn primary_button_text_color(mut self, color: impl Into<String>) -> Self {
+        self.options.primary_button_text_color = Some(color.into());
+        self
+    }
+
+    /// Sets the text color of primary buttons on hover.
+    pub fn primary_button_text_color_hover(mut self, color: impl Into<String>) -> Self {
+        self.options.primary_button_text_color_hover = Some(color.into());
+        self
+    }
+
+    /// Sets the background color of secondary buttons.
+    pub fn secondary_button_color(mut self, color: impl Into<String>) -> Self {
+        self.options.secondary_button_color = Some(color.into());
+        self
+    }
+
+    /// Sets the background color of secondary buttons on hover.
+    pub fn secondary_button_color_hover(mut self, color: impl Into<String>) -> Self {
+        self.options.secondary_button_color_hover = Some(color.into());
+        self
+    }
+
+    /// Sets the text color of secondary buttons.
+    pub fn secondary_button_text_color(mut self, color: impl Into<String>) -> Self {
+        self.options.secondary_button_text_color = Some(color.into());
+        self
+    }
+
+    /// Sets the text color of secondary buttons on hover.
+    pub fn secondary_button_text_color_hover(mut self, color: impl Into<String>) -> Self {
+        self.options.secondary_button_text_color_hover = Some(color.into());
+        self
+    }
+
+    /// Sets the primary text color.
+    pub fn text_color1(mut self, color: impl Into<String>) -> Self {
+        self.options.text_color1 = Some(color.into());
+        self
+    }
+
+    /// Sets the secondary text color.
+    pub fn text_color2(mut self, color: impl Into<String>) -> Self {
+        self.options.text_color2 = Some(color.into());
+        self
+    }
+
+    /// Sets whether white labeling is reset to the default branding.
+    pub fn reset_to_default(mut self, reset_to_default: bool) -> Self {
+        self.options.reset_to_default = Some(reset_to_default);
+        self
+    }
+
+    /// Validates the configured colors and returns the options.
+    ///
+    /// # Errors
+    ///
+    /// Returns `WhiteLabelingOptionsError::InvalidColor` if any color is not a
+    /// hex color such as `#1A1A1A`.
+    pub fn build(self) -> Result<SubWhiteLabelingOptions, WhiteLabelingOptionsError> {
+        self.options.validate()?;
+        Ok(self.options)
+    }
+}
+
+impl Validate for CreateApiAppRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut checks = Checks::new();
+
+        checks.not_blank(&self.name, "name");
+        checks.check(!self.domains.is_empty(), "domains", "must not be empty");
+        validate_settings(
+            &mut checks,
+            &self.domains,
+            self.oauth.as_ref(),
+            self.white_labeling_options.as_ref(),
+        );
+
+        checks.finish()
+    }
+}
+
+impl Validate for UpdateApiAppRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut checks = Checks::new();
+
+        if let Some(name) = &self.name {
+            checks.not_blank(name, "name");
+        }
+        if let Some(domains) = &self.domains {
+            checks.check(!domains.is_empty(), "domains", "must not be empty");
+        }
+        validate_settings(
+            &mut checks,
+            self.domains.as_deref().unwrap_or_default(),
+            self.oauth.as_ref(),
+            self.white_labeling_options.as_ref(),
+        );
+
+        checks.finish()
+    }
+}
+
+/// Checks the domains, OAuth and white-labeling settings shared by API app
+/// requests.
+fn validate_settings(
+    checks: &mut Checks,
+    domains: &[String],
+    oauth: Option<&SubOAuth>,
+    white_labeling_options: Option<&SubWhiteLabelingOptions>,
+) {
+    for (index, domain) in domains.iter().enumerate() {
+        checks.not_blank(domain, format!("domains[{index}]"));
+    }
+    if let Some(oauth) = oauth {
+        checks.not_blank(&oauth.callback_url, "oauth[callback_url]");
+        checks.check(
+            !oauth.scopes.is_empty(),
+            "oauth[scopes]",
+            "must not be empty",
+        );
+    }
+    if let Some(Err(WhiteLabelingOptionsError::InvalidColor { field, .. })) =
+        white_labeling_options.map(SubWhiteLabelingOptions::validate)
+    {
+        checks.check(
+            false,
+            format!("white_labeling_options[{field}]"),
+            "must be a hex color",
+        );
+    }
+}
+
+/// Returns whether `value` is a `#RGB` or `#RRGGBB` hex color.
+fn is_hex_color(value: &str) -> bool {
+    value
+        .strip_prefix('#')
+        .is_some_and(|hex| matches!(hex.len(), 3 | 6) && hex.chars().all(|c| c.is_ascii_hexdigit()))
+}
@@ -0,0 +1,104 @@
+//! Concurrent batch operations.
+//!
+//! These helpers run many calls at once with bounded parallelism. Every call goes
+//! through the client as usual, so a configured rate limit still applies across
+//! the whole batch. A failed call does not stop the rest of the batch: results
+//! are returned in input order, one per input.
+
+use crate::client::DropboxSignClientError;
+use crate::id::SignatureRequestId;
+use crate::signature_request::{SendWithTemplateRequest, SignatureRequestResponse};
+use crate::{DropboxSignClient, WarningResponse};
+use futures_util::stream::{self, StreamExt};
+
+/// Result of a single call in a batch.
+pub type BatchResult<T> = Result<(T, Option<Vec<WarningResponse>>), DropboxSignClientError>;
+
+/// Sends many template-based signature requests concurrently.
+///
+/// # Arguments
+///
+/// * `client` - Client used to send the requests
+/// * `requests` - Signature requests to send
+/// * `max_concurrency` - Maximum number of requests in flight at once (at least 1)
+///
+/// # Returns
+///
+/// One result per request, in the same order as `requests`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::{DropboxSignClient, batch};
+/// use dropboxsign_client::signature_request::SendWithTemplateRequest;
+///
+/// # async fn example(
+/// #     requests: Vec<SendWithTemplateRequest>,
+/// # ) -> Result<(), Box<dyn std::error::Error>> {
+/// let client = DropboxSignClient::builder()
+///     .api_key("your-api-key")
+///     .rate_limit(100)
+///     .build()?;
+///
+/// let results = batch::send_many(&client, requests, 8).await;
+/// let failed = results.iter().filter(|result| result.is_err()).count();
+/// println!("{failed} signature requests failed");
+/// # Ok(())
+/// # }
+/// ```
+pub async fn send_many(
+    client: &DropboxSignClient,
+    requests: impl IntoIterator<Item = SendWithTemplateRequest>,
+    max_concurrency: usize,
+) -> Vec<BatchResult<SignatureRequestResponse>> {
+    stream::iter(requests)
+        .map(|request| client.send_with_template(request))
+        .buffered(max_concurrency.max(1))
+        .collect()
+        .await
+}
+
+/// Fetches many signature requests concurrently.
+///
+/// # Arguments
+///
+/// * `client` - Client used to fetch the signature requests
+/// * `ids` - Identifiers of the signature requests to fetch
+/// * `max_concurrency` - Maximum number of requests in flight at once (at least 1)
+///
+/// # Returns
+///
+/// One result per identifier, in the same order as `ids`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::{DropboxSignClient, batch};
+///
+/// # async fn example() {
+/// let client = DropboxSignClient::new("your-api-key");
+/// let ids = ["first-id", "second-id", "third-id"];
+///
+/// for (id, result) in ids.iter().zip(batch::get_many(&client, ids, 4).await) {
+///     match result {
+///         Ok((request, _)) => println!("{id}: complete = {:?}", request.is_complete),
+///         Err(error) => eprintln!("{id}: {error}"),
+///     }
+/// }
+/// # }
+/// ```
+pub async fn get_many<I>(
+    client: &DropboxSignClient,
+    ids: I,
+    max_concurrency: usize,
+) -> Vec<BatchResult<SignatureRequestResponse>>
+where
+    I: IntoIterator,
+    I::Item: Into<SignatureRequestId>,
+{
+    stream::iter(ids)
+        .map(|id| async move { client.get_signature_request(&id.into()).await })
+        .buffered(max_concurrency.max(1))
+        .collect()
+        .await
+}
@@ -0,0 +1,69 @@
+//! Data models and types for bulk send jobs.
+//!
+//! A bulk send job sends one template to many signers at once, creating a
+//! separate signature request for each of them. This module contains the data
+//! structures returned when reading bulk send jobs and the signature requests
+//! they created.
+
+use crate::id::BulkSendJobId;
+use crate::signature_request::SignatureRequestResponse;
+use crate::{ListInfoResponse, ListItem, Page};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Summary of a bulk send job.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BulkSendJobResponse {
+    /// Unique identifier for this bulk send job
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bulk_send_job_id: Option<BulkSendJobId>,
+    /// Number of signature requests in the job
+    pub total: u64,
+    /// Whether the caller created the job
+    pub is_creator: bool,
+    /// Unix timestamp when the job was created
+    pub created_at: u64,
+    /// Fields returned by the API that this crate does not model yet
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "crate::strict::extra",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    pub extra: HashMap<String, Value>,
+}
+
+/// A bulk send job along with a page of the signature requests it created.
+///
+/// The signature requests are paginated like any list endpoint; use
+/// [`next_page`](Self::next_page) to find the page to request next.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BulkSendJobGetResponse {
+    /// Summary of the bulk send job
+    pub bulk_send_job: BulkSendJobResponse,
+    /// Pagination information for the signature requests
+    pub list_info: ListInfoResponse,
+    /// Signature requests created by the job on this page
+    #[serde(default)]
+    pub signature_requests: Vec<SignatureRequestResponse>,
+}
+
+impl BulkSendJobGetResponse {
+    /// Returns the number of the page after this one, or `None` on the last page.
+    pub fn next_page(&self) -> Option<u64> {
+        (self.list_info.page < self.list_info.num_pages).then_some(self.list_info.page + 1)
+    }
+
+    /// Returns whether this is the last page of signature requests.
+    pub fn is_last_page(&self) -> bool {
+        self.next_page().is_none()
+    }
+}
+
+/// A page of bulk send jobs.
+pub type BulkSendJobsResponse = Page<BulkSendJobResponse>;
+
+impl ListItem for BulkSendJobResponse {
+    const LIST_KEY: &'static str = "bulk_send_jobs";
+}
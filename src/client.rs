@@ -3,12 +3,87 @@
 //! This module provides the main client struct and associated functionality
 //! for making authenticated requests to the Dropbox Sign API.
 
-use crate::signature_request::{SendSignatureRequest, SignatureRequestResponse};
-use crate::{ErrorResponse, ErrorResponseError, WarningResponse};
-use reqwest::{Client, StatusCode};
+use crate::account::{
+    AccountCreateResponse, AccountResponse, AccountVerifyResponse, CreateAccountRequest,
+    UpdateAccountRequest, VerifyAccountRequest,
+};
+use crate::api_app::{ApiAppResponse, ApiAppsResponse, CreateApiAppRequest, UpdateApiAppRequest};
+use crate::bulk_send_job::{BulkSendJobResponse, BulkSendJobsResponse};
+use crate::embedded::{EmbeddedResponse, TemplateEditResponse};
+use crate::events::{EventCallbackRequest, EventType, HydratedEvent};
+use crate::fax::{FaxResponse, FaxesResponse};
+use crate::fax_line::{
+    CreateFaxLineRequest, DeleteFaxLineRequest, FaxLineAreaCodesResponse, FaxLineCountry,
+    FaxLineResponse, FaxLineUserRequest, FaxLinesResponse,
+};
+use crate::file::Progress;
+use crate::id::{ClientId, SignatureId, SignatureRequestId, TemplateId};
+use crate::oauth::{OAuthClient, OAuthTokenRefresher, TokenStore};
+use crate::report::{CreateReportRequest, ReportResponse};
+use crate::signature_request::{
+    SendWithFilesRequest, SendWithTemplateRequest, SignatureRequestFileType,
+    SignatureRequestResponse, SignatureRequestSummariesResponse, SignatureRequestSummary,
+    SignatureRequestsResponse,
+};
+use crate::strict;
+use crate::team::{
+    AddTeamMemberRequest, CreateTeamRequest, RemoveTeamMemberRequest, SubTeamResponse,
+    SubTeamsResponse, TeamInvitesResponse, TeamMemberIdentifier, TeamMemberResponse,
+    TeamMemberRole, TeamMembersResponse, TeamResponse, UpdateTeamRequest,
+};
+use crate::template::{TemplateResponse, TemplatesResponse};
+use crate::unclaimed_draft::{
+    CreateEmbeddedUnclaimedDraftRequest, CreateUnclaimedDraftRequest, UnclaimedDraftResponse,
+};
+use crate::validate::{Validate, ValidationError};
+use crate::{
+    DropboxSignErrorKind, ErrorResponse, ErrorResponseError, ListItem, Page, PageWithWarnings,
+    ResponseWithWarnings, WarningResponse,
+};
+use futures_util::{Stream, StreamExt};
+use reqwest::{Client, Method, RequestBuilder, StatusCode};
+use serde::Serialize;
 use serde::de::DeserializeOwned;
 use serde_json::Value;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use thiserror::Error;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+mod builder;
+mod cache;
+mod circuit;
+mod defaults;
+mod meter;
+mod multipart;
+mod options;
+mod paginate;
+mod rate_limit;
+mod redact;
+mod response;
+mod retry;
+mod telemetry;
+#[cfg(feature = "vcr")]
+mod vcr;
+
+pub use builder::{DropboxSignClientBuildError, DropboxSignClientBuilder};
+pub use cache::{CacheConfig, CachedEndpoint};
+pub use circuit::CircuitBreakerConfig;
+pub use options::RequestOptions;
+pub use redact::RedactionPolicy;
+pub use response::{RateLimitStatus, Response, ResponseMetadata};
+pub use retry::RetryPolicy;
+pub use tokio_util::sync::CancellationToken;
+#[cfg(feature = "vcr")]
+pub use vcr::{Cassette, CassetteError};
+
+use builder::HttpConfig;
+use cache::{CacheKey, CachedResponse, ResponseCache};
+use circuit::CircuitBreaker;
+use defaults::RequestDefaults;
+use multipart::FilePart;
+use rate_limit::RateLimiter;
 
 /// Base URL for the Dropbox Sign API (v3)
 const API_URL: &str = "https://api.hellosign.com/v3";
@@ -41,50 +116,137 @@ pub async fn parse_response<T: DeserializeOwned>(
     key: &str,
 ) -> Result<(T, Option<Vec<WarningResponse>>), Box<dyn std::error::Error + Send + Sync>> {
     let body = response.text().await?;
-    let json: Value = serde_json::from_str(&body)?;
+    Ok(parse_body(&body, key, false)?)
+}
+
+/// Parses the payload stored under `key` and any warnings from a response body.
+///
+/// With `strict` set, fields the models do not know about are rejected.
+fn parse_body<T: DeserializeOwned>(
+    body: &str,
+    key: &str,
+    strict: bool,
+) -> Result<(T, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+    let json: Value = serde_json::from_str(body)?;
 
     // Extract main payload by key
-    let payload = json
-        .get(key)
-        .ok_or_else(|| format!("Missing key `{}` in response", key))?;
+    let payload = json.get(key).ok_or_else(|| {
+        DropboxSignClientError::Other(format!("Missing key `{key}` in response").into())
+    })?;
 
     // Deserialize the payload into T
-    let inner: T = serde_json::from_value(payload.clone())?;
+    let inner: T = strict::from_value(payload, key, strict)?;
 
     // Extract warnings if present
     let warnings = json
         .get("warnings")
-        .map(|w| serde_json::from_value(w.clone()))
+        .map(|w| strict::from_value(w, "warnings", strict))
         .transpose()?;
 
     Ok((inner, warnings))
 }
 
+/// Query parameters shared by the paginated list endpoints.
+#[derive(Serialize)]
+struct PageQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page_size: Option<u64>,
+}
+
+/// Query parameters for filtering by email address.
+#[derive(Serialize)]
+struct EmailAddressQuery<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email_address: Option<&'a str>,
+}
+
+/// Query parameters identifying a fax line.
+#[derive(Serialize)]
+struct FaxLineQuery<'a> {
+    number: &'a str,
+}
+
+/// Query parameters for looking up purchasable fax line area codes.
+#[derive(Serialize)]
+struct FaxLineAreaCodesQuery<'a> {
+    country: FaxLineCountry,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    province: Option<&'a str>,
+}
+
 /// HTTP client for interacting with the Dropbox Sign API.
 ///
 /// This client handles authentication, request/response processing, and error handling
-/// for Dropbox Sign API operations. It supports configuration of connection pooling
-/// and request timeouts.
+/// for Dropbox Sign API operations. Use [`DropboxSignClient::builder`] to configure
+/// timeouts, connection pooling, proxies and headers.
 ///
 /// # Examples
 ///
 /// ```no_run
-/// use dropboxsign_rs::DropboxSignClient;
+/// use dropboxsign_client::DropboxSignClient;
 ///
-/// let client = DropboxSignClient::new("your-api-key")
-///     .with_pool(10)
-///     .with_timeout(60);
+/// let client = DropboxSignClient::new("your-api-key");
 /// ```
 #[derive(Clone)]
 pub struct DropboxSignClient {
-    /// API key for authentication
-    api_key: String,
+    /// Credentials used to authenticate requests
+    auth: Auth,
     /// HTTP client for making requests
     client: Client,
-    /// Connection pool size (currently unused, reserved for future use)
-    pool: usize,
-    /// Request timeout in seconds (currently unused, reserved for future use)
-    timeout: usize,
+    /// Error building the HTTP client from `http`, returned by every call
+    client_error: Option<Arc<DropboxSignClientBuildError>>,
+    /// Base URL that endpoint paths are appended to
+    base_url: String,
+    /// Settings the HTTP client was built from, or `None` for a client supplied
+    /// through [`DropboxSignClientBuilder::http_client`]
+    http: Option<HttpConfig>,
+    /// Limiter pacing requests, shared by all clones of the client
+    rate_limiter: Option<Arc<RateLimiter>>,
+    /// Personal data removed from logged payloads
+    redaction: RedactionPolicy,
+    /// How failed requests are retried
+    retry: RetryPolicy,
+    /// Time allowed for each attempt, overriding the HTTP client's timeout
+    timeout: Option<Duration>,
+    /// Token cancelling in-flight calls
+    cancellation: Option<CancellationToken>,
+    /// Total time allowed for a call, including retries and reading the response
+    deadline: Option<Duration>,
+    /// Values merged into outgoing signature requests and drafts
+    defaults: RequestDefaults,
+    /// Cached GET responses, shared by all clones of the client
+    cache: Option<Arc<ResponseCache>>,
+    /// Breaker failing calls fast during outages, shared by all clones of the client
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    /// Whether responses with fields the models do not know about are rejected
+    strict: bool,
+    /// Cassette recording or replaying the client's requests
+    #[cfg(feature = "vcr")]
+    cassette: Option<Arc<Cassette>>,
+}
+
+/// Credentials a client authenticates with.
+#[derive(Clone)]
+enum Auth {
+    /// API key sent as the basic auth username
+    ApiKey(String),
+    /// OAuth bearer token refreshed on demand
+    OAuth(Arc<OAuthTokenRefresher>),
+}
+
+impl Auth {
+    /// Identifies the credentials, so cached responses are never shared between
+    /// accounts.
+    fn cache_scope(&self) -> String {
+        match self {
+            Self::ApiKey(api_key) => format!("api_key:{api_key}"),
+            Self::OAuth(refresher) => format!("oauth:{:p}", Arc::as_ptr(refresher)),
+        }
+    }
 }
 
 /// Errors that can occur when using the Dropbox Sign client.
@@ -103,12 +265,147 @@ pub enum DropboxSignClientError {
     Serde(#[from] serde_json::Error),
 
     #[error("DropboxSign error: {0}")]
-    ResponseError(ErrorResponseError),
+    ResponseError(Box<ErrorResponseError>),
+
+    #[error("unexpected {status} response: {body}")]
+    UnexpectedResponse { status: StatusCode, body: String },
+
+    #[error("no OAuth token available in the token store")]
+    MissingOAuthToken,
+
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("request was cancelled")]
+    Cancelled,
+
+    #[error("test mode is enforced but the request disables it")]
+    TestModeRequired,
+
+    #[error("call did not complete within its {0:?} deadline")]
+    DeadlineExceeded(Duration),
+
+    #[error("circuit breaker is open; next attempt allowed in {0:?}")]
+    CircuitOpen(Duration),
+
+    #[error("HTTP client could not be built: {0}")]
+    HttpClient(Arc<DropboxSignClientBuildError>),
+
+    #[error(
+        "access token was rejected and the file upload cannot be resent; send the request again"
+    )]
+    UploadNotResent,
+
+    #[error(transparent)]
+    Validation(#[from] ValidationError),
+
+    #[error("response does not match the model at {path}: {message}")]
+    ModelMismatch { path: String, message: String },
+
+    #[error("response has fields the models do not know about: {}", .0.join(", "))]
+    UnknownFields(Vec<String>),
 
     #[error("Other error: {0}")]
     Other(#[from] Box<dyn std::error::Error + Send + Sync>),
 }
 
+impl DropboxSignClientError {
+    /// Returns the HTTP status of the response that caused the error, if any.
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Self::ResponseError(error) => Some(error.status),
+            Self::UnexpectedResponse { status, .. } => Some(*status),
+            Self::Reqwest(error) => error.status(),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the API rejected the call for exceeding the rate limit.
+    pub fn is_rate_limited(&self) -> bool {
+        self.status() == Some(StatusCode::TOO_MANY_REQUESTS)
+            || self.kind() == Some(DropboxSignErrorKind::ExceededRate)
+    }
+
+    /// Returns whether the call failed because of missing, invalid or
+    /// insufficient credentials.
+    pub fn is_auth_error(&self) -> bool {
+        matches!(self, Self::MissingOAuthToken)
+            || matches!(
+                self.status(),
+                Some(StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN)
+            )
+            || matches!(
+                self.kind(),
+                Some(DropboxSignErrorKind::Unauthorized | DropboxSignErrorKind::Forbidden)
+            )
+    }
+
+    /// Returns whether the call may succeed if it is sent again later.
+    ///
+    /// This covers connection failures, timeouts, rate limiting, maintenance and
+    /// the gateway errors that [`RetryPolicy`] retries automatically, as well as
+    /// [`UploadNotResent`](Self::UploadNotResent).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::DropboxSignClient;
+    ///
+    /// # async fn example() {
+    /// let client = DropboxSignClient::new("your-api-key");
+    ///
+    /// if let Err(error) = client.get_account().await {
+    ///     if error.is_retryable() {
+    ///         // queue the job again
+    ///     } else if error.is_auth_error() {
+    ///         // alert: the API key was revoked
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Reqwest(error) if error.is_connect() || error.is_timeout() => true,
+            Self::UploadNotResent => true,
+            _ => {
+                self.is_rate_limited()
+                    || self.status().is_some_and(retry::is_retryable_status)
+                    || self.kind() == Some(DropboxSignErrorKind::Maintenance)
+            }
+        }
+    }
+
+    /// Returns how long the API asked callers to wait before retrying, if it did.
+    ///
+    /// Taken from the `Retry-After` header of an API error response, which is
+    /// usually only sent when the call was rate limited.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::ResponseError(error) => error.retry_after,
+            _ => None,
+        }
+    }
+
+    /// Returns the rate limit state reported by the API error response, if any.
+    pub fn rate_limit(&self) -> Option<RateLimitStatus> {
+        match self {
+            Self::ResponseError(error) => error.rate_limit,
+            _ => None,
+        }
+    }
+
+    /// Returns the category of the error reported by the API.
+    ///
+    /// Returns `None` for errors that did not come from an API error response,
+    /// such as connection failures.
+    pub fn kind(&self) -> Option<DropboxSignErrorKind> {
+        match self {
+            Self::ResponseError(error) => Some(error.kind()),
+            _ => None,
+        }
+    }
+}
+
 impl DropboxSignClient {
     /// Creates a new Dropbox Sign client with the specified API key.
     ///
@@ -119,17 +416,268 @@ impl DropboxSignClient {
     /// # Examples
     ///
     /// ```no_run
-    /// use dropboxsign_rs::DropboxSignClient;
+    /// use dropboxsign_client::DropboxSignClient;
     ///
     /// let client = DropboxSignClient::new("your-api-key");
     /// ```
     pub fn new(api_key: impl Into<String>) -> Self {
-        let client = Client::new();
+        Self::with_auth(Auth::ApiKey(api_key.into()))
+    }
+
+    /// Creates a new Dropbox Sign client that acts on behalf of a user through OAuth.
+    ///
+    /// Requests carry the bearer token held in `store`. When the API rejects the
+    /// token with `401 Unauthorized`, the client refreshes it using the API app
+    /// credentials, saves the new token to `store` and retries the request once.
+    /// Clones of the client share one refresh lock, so share the client rather
+    /// than building several on the same store; use
+    /// [`spawn_refresh_task`](crate::oauth::spawn_refresh_task) to refresh ahead
+    /// of expiry.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - Client ID of the API app
+    /// * `client_secret` - Client secret of the API app
+    /// * `store` - Storage holding the user's current OAuth token
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::{DropboxSignClient, oauth::*};
+    ///
+    /// # fn example(token: OAuthTokenResponse) {
+    /// let client = DropboxSignClient::new_with_oauth(
+    ///     "client-id",
+    ///     "client-secret",
+    ///     InMemoryTokenStore::new(token),
+    /// );
+    /// # }
+    /// ```
+    pub fn new_with_oauth(
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        store: impl TokenStore + 'static,
+    ) -> Self {
+        let mut client = Self::with_auth(Auth::ApiKey(String::new()));
+        let refresher = OAuthTokenRefresher::new(
+            client_id.into(),
+            client_secret.into(),
+            Box::new(store),
+            OAuthClient::from_client(client.client.clone()),
+        );
+        client.auth = Auth::OAuth(Arc::new(refresher));
+        client
+    }
+
+    /// Creates a builder for configuring a client.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::DropboxSignClient;
+    /// use std::time::Duration;
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::builder()
+    ///     .api_key("your-api-key")
+    ///     .timeout(Duration::from_secs(60))
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn builder() -> DropboxSignClientBuilder {
+        DropboxSignClientBuilder::new()
+    }
+
+    /// Returns a copy of the client whose calls use the given options.
+    ///
+    /// The copy shares the connection pool, credentials and rate limiter with this
+    /// client, so it is cheap to create for a single call.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Settings overriding the client's configuration
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::DropboxSignClient;
+    /// use dropboxsign_client::client::{RequestOptions, RetryPolicy};
+    /// use dropboxsign_client::signature_request::SignatureRequestFileType;
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::new("your-api-key");
+    /// let mut file = tokio::fs::File::create("signed.pdf").await?;
+    ///
+    /// client
+    ///     .with_options(
+    ///         RequestOptions::new()
+    ///             .timeout(Duration::from_secs(600))
+    ///             .retry(RetryPolicy::new(5)),
+    ///     )
+    ///     .download_files_to(&"signature-request-id".into(), SignatureRequestFileType::Pdf, &mut file)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_options(&self, options: RequestOptions) -> Self {
+        let mut client = self.clone();
+        if let Some(timeout) = options.timeout {
+            client.timeout = Some(timeout);
+        }
+        if let Some(retry) = options.retry {
+            client.retry = retry;
+        }
+        if let Some(cancellation) = options.cancellation {
+            client.cancellation = Some(cancellation);
+        }
+        if let Some(deadline) = options.deadline {
+            client.deadline = Some(deadline);
+        }
+        client
+    }
+
+    /// Returns a copy of the client that authenticates with another API key.
+    ///
+    /// The copy shares the connection pool and every other setting with this
+    /// client, so multi-tenant apps can act for many accounts without building a
+    /// client per account. The rate limiter is shared as well and paces the
+    /// requests of all accounts together.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - API key of the account to act for
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::DropboxSignClient;
+    ///
+    /// # async fn example(customer_api_key: &str) -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::new("your-api-key");
+    ///
+    /// let (account, _) = client.with_api_key(customer_api_key).get_account().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_api_key(&self, api_key: impl Into<String>) -> Self {
+        let mut client = self.clone();
+        client.auth = Auth::ApiKey(api_key.into());
+        client
+    }
+
+    /// Removes cached responses of an endpoint.
+    ///
+    /// Does nothing unless the client was built with a
+    /// [`cache`](DropboxSignClientBuilder::cache). Entries are removed for every
+    /// credential.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - Endpoint whose responses are removed
+    /// * `id` - Only remove the response for this resource (e.g. a template ID)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::DropboxSignClient;
+    /// use dropboxsign_client::client::CachedEndpoint;
+    ///
+    /// # fn example(client: &DropboxSignClient) {
+    /// // The template was edited in the Dropbox Sign web app.
+    /// client.invalidate_cache(CachedEndpoint::Template, Some("template-id"));
+    /// # }
+    /// ```
+    pub fn invalidate_cache(&self, endpoint: CachedEndpoint, id: Option<&str>) {
+        if let Some(cache) = &self.cache {
+            cache.invalidate(endpoint, id);
+        }
+    }
+
+    /// Removes every cached response.
+    pub fn clear_cache(&self) {
+        if let Some(cache) = &self.cache {
+            cache.clear();
+        }
+    }
+
+    /// Runs a call and returns its result together with the HTTP response it came from.
+    ///
+    /// The status, headers, request ID and rate limit state of the last response
+    /// received while running `call` are captured. Wrap a single call to correlate
+    /// it with Dropbox Sign support, or to watch the remaining rate limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `call` - The call to run, e.g. `client.get_account()`
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::DropboxSignClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::new("your-api-key");
+    ///
+    /// let response = DropboxSignClient::with_metadata(client.get_account()).await;
+    /// if let Some(request_id) = response.request_id() {
+    ///     println!("request ID: {request_id}");
+    /// }
+    /// if let Some(remaining) = response.rate_limit().and_then(|limit| limit.remaining) {
+    ///     println!("{remaining} requests left this window");
+    /// }
+    ///
+    /// let (account, _warnings) = response.into_inner()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn with_metadata<T>(call: impl Future<Output = T>) -> Response<T> {
+        response::capture(call).await
+    }
+
+    /// Returns the OAuth token refresher shared by this client and its clones.
+    pub(crate) fn oauth_refresher(&self) -> Option<Arc<OAuthTokenRefresher>> {
+        match &self.auth {
+            Auth::ApiKey(_) => None,
+            Auth::OAuth(refresher) => Some(Arc::clone(refresher)),
+        }
+    }
+
+    /// Returns the token cancelling this client's calls, if one was set.
+    pub(crate) fn cancellation(&self) -> Option<&CancellationToken> {
+        self.cancellation.as_ref()
+    }
+
+    /// Creates a client with the default settings.
+    ///
+    /// If the HTTP client cannot be built, every call fails with
+    /// [`DropboxSignClientError::HttpClient`].
+    fn with_auth(auth: Auth) -> Self {
+        let http = HttpConfig::default();
+        let (client, client_error) = match http.build() {
+            Ok(client) => (client, None),
+            // Only a placeholder: calls fail with the build error before using it.
+            Err(error) => (Client::default(), Some(Arc::new(error))),
+        };
         Self {
-            api_key: api_key.into(),
+            auth,
             client,
-            pool: 5,
-            timeout: 30,
+            client_error,
+            base_url: API_URL.to_string(),
+            http: Some(http),
+            rate_limiter: None,
+            redaction: RedactionPolicy::default(),
+            retry: RetryPolicy::default(),
+            timeout: None,
+            cancellation: None,
+            deadline: None,
+            defaults: RequestDefaults::default(),
+            cache: None,
+            circuit_breaker: None,
+            strict: false,
+            #[cfg(feature = "vcr")]
+            cassette: None,
         }
     }
 
@@ -137,18 +685,32 @@ impl DropboxSignClient {
     ///
     /// # Arguments
     ///
-    /// * `pool` - Maximum number of connections in the pool
+    /// * `pool` - Maximum number of idle connections in the pool
     ///
     /// # Returns
     ///
-    /// The client instance for method chaining
+    /// The client instance for method chaining; a client built with a custom HTTP
+    /// client is returned unchanged
+    #[deprecated(note = "use `DropboxSignClient::builder` and `pool_max_idle_per_host` instead")]
+    pub fn with_pool(self, pool: usize) -> Self {
+        self.rebuild_client(|http| http.pool_max_idle_per_host = pool)
+    }
+
+    /// Sets how long idle pooled connections are kept alive.
     ///
-    /// # Note
+    /// # Arguments
     ///
-    /// This setting is currently reserved for future use and does not affect behavior.
-    pub fn with_pool(mut self, pool: usize) -> Self {
-        self.pool = pool;
-        self
+    /// * `pool_idle_timeout` - Idle timeout in seconds
+    ///
+    /// # Returns
+    ///
+    /// The client instance for method chaining; a client built with a custom HTTP
+    /// client is returned unchanged
+    #[deprecated(note = "use `DropboxSignClient::builder` and `pool_idle_timeout` instead")]
+    pub fn with_pool_idle_timeout(self, pool_idle_timeout: usize) -> Self {
+        self.rebuild_client(|http| {
+            http.pool_idle_timeout = Some(Duration::from_secs(pool_idle_timeout as u64));
+        })
     }
 
     /// Sets the request timeout for the client.
@@ -159,13 +721,48 @@ impl DropboxSignClient {
     ///
     /// # Returns
     ///
-    /// The client instance for method chaining
+    /// The client instance for method chaining; a client built with a custom HTTP
+    /// client is returned unchanged
+    #[deprecated(note = "use `DropboxSignClient::builder` and `timeout` instead")]
+    pub fn with_timeout(self, timeout: usize) -> Self {
+        self.rebuild_client(|http| http.timeout = Some(Duration::from_secs(timeout as u64)))
+    }
+
+    /// Sets the connect timeout for the client.
+    ///
+    /// # Arguments
+    ///
+    /// * `connect_timeout` - Connect timeout in seconds
     ///
-    /// # Note
+    /// # Returns
+    ///
+    /// The client instance for method chaining; a client built with a custom HTTP
+    /// client is returned unchanged
+    #[deprecated(note = "use `DropboxSignClient::builder` and `connect_timeout` instead")]
+    pub fn with_connect_timeout(self, connect_timeout: usize) -> Self {
+        self.rebuild_client(|http| {
+            http.connect_timeout = Some(Duration::from_secs(connect_timeout as u64));
+        })
+    }
+
+    /// Changes the HTTP settings with `configure` and rebuilds the HTTP client.
     ///
-    /// This setting is currently reserved for future use and does not affect behavior.
-    pub fn with_timeout(mut self, timeout: usize) -> Self {
-        self.timeout = timeout;
+    /// A client supplied through [`DropboxSignClientBuilder::http_client`] is left
+    /// untouched. If the new client cannot be built, every call fails with
+    /// [`DropboxSignClientError::HttpClient`].
+    fn rebuild_client(mut self, configure: impl FnOnce(&mut HttpConfig)) -> Self {
+        let Some(http) = &mut self.http else {
+            return self;
+        };
+
+        configure(http);
+        match http.build() {
+            Ok(client) => {
+                self.client = client;
+                self.client_error = None;
+            }
+            Err(error) => self.client_error = Some(Arc::new(error)),
+        }
         self
     }
 
@@ -190,12 +787,12 @@ impl DropboxSignClient {
     /// # Examples
     ///
     /// ```no_run
-    /// use dropboxsign_rs::DropboxSignClient;
+    /// use dropboxsign_client::DropboxSignClient;
     ///
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = DropboxSignClient::new("your-api-key");
     /// let (signature_request, warnings) = client
-    ///     .get_signature_request("signature_request_id")
+    ///     .get_signature_request(&"signature_request_id".into())
     ///     .await?;
     ///
     /// println!("Title: {}", signature_request.title);
@@ -204,138 +801,2697 @@ impl DropboxSignClient {
     /// ```
     pub async fn get_signature_request(
         &self,
-        signature_request_id: &str,
+        signature_request_id: &SignatureRequestId,
     ) -> Result<(SignatureRequestResponse, Option<Vec<WarningResponse>>), DropboxSignClientError>
     {
-        let url = format!("{}/signature_request/{signature_request_id}", API_URL);
-
-        let response = self
-            .client
-            .get(&url)
-            .basic_auth(&self.api_key, Some(""))
-            .send()
-            .await?;
-
-        let status = response.status();
+        let request = self.request(
+            Method::GET,
+            &format!("/signature_request/{signature_request_id}"),
+        );
 
-        if status == StatusCode::OK {
-            let (sig_req, warnings) =
-                parse_response::<SignatureRequestResponse>(response, "signature_request") // Add the key parameter
-                    .await
-                    .map_err(DropboxSignClientError::Other)?;
-            Ok((sig_req, warnings))
-        } else {
-            let body = response.text().await?;
-            let parsed: ErrorResponse = serde_json::from_str(&body)?;
-            Err(DropboxSignClientError::ResponseError(parsed.error))
-        }
+        self.send(request, "signature_request").await
     }
 
-    /// Sends a signature request using a template.
+    /// Lists the signature requests the account can access.
     ///
-    /// This method creates and sends a signature request based on a pre-existing
-    /// template. The template defines the document layout and form fields, while
-    /// the request specifies the signers and other dynamic parameters.
+    /// Signature requests are returned newest first.
     ///
     /// # Arguments
     ///
-    /// * `send_signature_request` - The signature request configuration including
-    ///   signers, template IDs, and optional parameters
+    /// * `page` - Optional page number to fetch (1-based, defaults to 1)
+    /// * `page_size` - Optional number of signature requests per page (defaults to 20)
     ///
     /// # Returns
     ///
-    /// A tuple containing the created signature request data and any warnings,
-    /// or an error if the request fails.
+    /// A tuple containing the signature requests on the requested page along with
+    /// the pagination information, and any warnings, or an error if the request fails.
     ///
     /// # Errors
     ///
     /// Returns `DropboxSignClientError` if:
     /// - The HTTP request fails
-    /// - The API returns an error response (e.g., invalid template ID, missing signers)
+    /// - The API returns an error response
     /// - The response cannot be parsed
+    pub async fn list_signature_requests(
+        &self,
+        page: Option<u64>,
+        page_size: Option<u64>,
+    ) -> Result<(SignatureRequestsResponse, Option<Vec<WarningResponse>>), DropboxSignClientError>
+    {
+        let request = self
+            .request(Method::GET, "/signature_request/list")
+            .query(&PageQuery { page, page_size });
+
+        self.send_flattened(request).await
+    }
+
+    /// Streams every signature request, fetching further pages as the stream is consumed.
+    ///
+    /// Warnings returned alongside the pages are dropped. The stream ends after the
+    /// first error.
+    ///
+    /// # Arguments
+    ///
+    /// * `page_size` - Optional number of signature requests fetched per page (defaults to 20)
     ///
     /// # Examples
     ///
     /// ```no_run
-    /// use dropboxsign_rs::{DropboxSignClient, signature_request::*};
+    /// use dropboxsign_client::DropboxSignClient;
+    /// use futures_util::{Stream, StreamExt};
     ///
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = DropboxSignClient::new("your-api-key");
+    /// let mut signature_requests = Box::pin(client.list_signature_requests_stream(Some(100)));
     ///
-    /// let signer = SubSignatureRequestTemplateSigner::new(
-    ///     "Signer".to_string(),
-    ///     "John Doe".to_string(),
-    ///     "john@example.com".to_string()
-    /// );
-    ///
-    /// let request = SendSignatureRequest::new(
-    ///     vec![signer],
-    ///     vec!["template-id".to_string()]
-    /// )
-    /// .title("Contract Signature".to_string())
-    /// .test_mode(true);
-    ///
-    /// let (signature_request, warnings) = client
-    ///     .send_with_template(request)
-    ///     .await?;
-    ///
-    /// println!("Sent signature request: {}", signature_request.signature_request_id);
+    /// while let Some(signature_request) = signature_requests.next().await {
+    ///     println!("{}", signature_request?.title);
+    /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn send_with_template(
+    pub fn list_signature_requests_stream(
         &self,
-        send_signature_request: SendSignatureRequest,
-    ) -> Result<(SignatureRequestResponse, Option<Vec<WarningResponse>>), DropboxSignClientError>
-    {
-        let url = format!("{}/signature_request/send_with_template", API_URL);
-
-        let response = self
-            .client
-            .post(&url)
-            .basic_auth(&self.api_key, Some(""))
-            .json(&send_signature_request)
-            .send()
-            .await?;
-
-        let status = response.status();
+        page_size: Option<u64>,
+    ) -> impl Stream<Item = Result<SignatureRequestResponse, DropboxSignClientError>> + use<> {
+        let client = self.clone();
 
-        if status == StatusCode::OK {
-            let (sig_req, warnings) =
-                parse_response::<SignatureRequestResponse>(response, "signature_request").await?;
-            println!("Dropbox send_with_template response: {sig_req:?}");
-            Ok((sig_req, warnings))
-        } else {
-            let body = response.text().await?;
-            let parsed: ErrorResponse = serde_json::from_str(&body)?;
-            Err(DropboxSignClientError::ResponseError(parsed.error))
-        }
+        paginate::paginate(move |page| {
+            let client = client.clone();
+            async move {
+                let (response, _) = client
+                    .list_signature_requests(Some(page), page_size)
+                    .await?;
+                Ok(response)
+            }
+        })
     }
 
-    pub async fn cancel_incomplete_signature_request(
+    /// Lists summaries of signature requests, one page at a time.
+    ///
+    /// Summaries skip the heavy fields of each request, such as `response_data`,
+    /// while the page is parsed, which keeps parse time and the size of the
+    /// results down when syncing large numbers of requests. The response body is
+    /// still read in full before parsing. Strict parsing does not apply, since
+    /// fields are skipped on purpose.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - Optional page number to fetch (1-based, defaults to 1)
+    /// * `page_size` - Optional number of signature requests per page (defaults to 20)
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the summaries on the requested page along with the
+    /// pagination information, and any warnings, or an error if the request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    pub async fn list_signature_request_summaries(
         &self,
-        signature_request_id: &str,
-    ) -> Result<StatusCode, DropboxSignClientError> {
-        let url = format!(
-            "{}/signature_request/cancel/{}",
-            API_URL, signature_request_id
+        page: Option<u64>,
+        page_size: Option<u64>,
+    ) -> Result<
+        (
+            SignatureRequestSummariesResponse,
+            Option<Vec<WarningResponse>>,
+        ),
+        DropboxSignClientError,
+    > {
+        let request = self
+            .request(Method::GET, "/signature_request/list")
+            .query(&PageQuery { page, page_size });
+
+        self.send_page(request).await
+    }
+
+    /// Streams summaries of every signature request, fetching further pages as the
+    /// stream is consumed.
+    ///
+    /// Warnings returned alongside the pages are dropped. The stream ends after the
+    /// first error.
+    ///
+    /// # Arguments
+    ///
+    /// * `page_size` - Optional number of signature requests fetched per page (defaults to 20)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::DropboxSignClient;
+    /// use futures_util::{Stream, StreamExt};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::new("your-api-key");
+    /// let mut summaries = Box::pin(client.list_signature_request_summaries_stream(Some(100)));
+    ///
+    /// while let Some(summary) = summaries.next().await {
+    ///     let summary = summary?;
+    ///     println!("{}: complete = {}", summary.signature_request_id, summary.is_complete);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_signature_request_summaries_stream(
+        &self,
+        page_size: Option<u64>,
+    ) -> impl Stream<Item = Result<SignatureRequestSummary, DropboxSignClientError>> + use<> {
+        let client = self.clone();
+
+        paginate::paginate(move |page| {
+            let client = client.clone();
+            async move {
+                let (response, _) = client
+                    .list_signature_request_summaries(Some(page), page_size)
+                    .await?;
+                Ok(response)
+            }
+        })
+    }
+
+    /// Sends a signature request using a template, unless a request with the same
+    /// idempotency key was already sent.
+    ///
+    /// The key is stamped into the request's metadata. Before sending, the most
+    /// recent signature requests are searched for that key, and a match is returned
+    /// instead of sending a duplicate. Retry with the same key after a crash or an
+    /// ambiguous network failure to send the request at most once.
+    ///
+    /// Retryable failures of the send are retried according to the client's
+    /// [`RetryPolicy`], searching for the key again before every attempt, so the
+    /// `POST` is retried even though the policy skips non-idempotent requests.
+    ///
+    /// Only the newest signature requests are searched (see
+    /// [`find_by_idempotency_key`](Self::find_by_idempotency_key)), so retries should
+    /// happen soon after the original attempt.
+    ///
+    /// # Arguments
+    ///
+    /// * `send_signature_request` - The signature request configuration
+    /// * `idempotency_key` - Key identifying this send, e.g. from
+    ///   [`generate_idempotency_key`](crate::signature_request::generate_idempotency_key)
+    ///
+    /// # Returns
+    ///
+    /// The sent or previously sent signature request, with warnings from the send
+    /// (none when an existing request is returned).
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if listing or sending signature requests fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::DropboxSignClient;
+    /// use dropboxsign_client::signature_request::*;
+    ///
+    /// # async fn example(request: SendWithTemplateRequest) -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::new("your-api-key");
+    ///
+    /// // Store the key with the job so a retried job reuses it.
+    /// let key = generate_idempotency_key();
+    /// let (sent, _) = client.send_with_template_idempotent(request, &key).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_with_template_idempotent(
+        &self,
+        send_signature_request: SendWithTemplateRequest,
+        idempotency_key: &str,
+    ) -> Result<(SignatureRequestResponse, Option<Vec<WarningResponse>>), DropboxSignClientError>
+    {
+        send_signature_request.validate()?;
+        let send_signature_request = send_signature_request.idempotency_key(idempotency_key);
+
+        let mut retries = 0;
+        loop {
+            if let Some(existing) = self.find_by_idempotency_key(idempotency_key).await? {
+                return Ok((existing, None));
+            }
+
+            match self
+                .send_with_template(send_signature_request.clone())
+                .await
+            {
+                Err(error) if error.is_retryable() && self.retry.allows(retries) => {
+                    let delay = self
+                        .retry
+                        .backoff(retries)
+                        .max(error.retry_after().unwrap_or_default());
+                    tokio::time::sleep(delay).await;
+                    retries += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Searches the most recent signature requests for one stamped with
+    /// `idempotency_key`.
+    ///
+    /// Up to the 500 newest signature requests are searched.
+    ///
+    /// # Arguments
+    ///
+    /// * `idempotency_key` - Key the signature request was sent with
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if listing signature requests fails.
+    pub async fn find_by_idempotency_key(
+        &self,
+        idempotency_key: &str,
+    ) -> Result<Option<SignatureRequestResponse>, DropboxSignClientError> {
+        for page in 1..=IDEMPOTENCY_SCAN_PAGES {
+            let (response, _) = self
+                .list_signature_requests(Some(page), Some(IDEMPOTENCY_SCAN_PAGE_SIZE))
+                .await?;
+            let is_last_page = response.is_last_page();
+
+            if let Some(found) = response
+                .into_iter()
+                .find(|request| request.idempotency_key() == Some(idempotency_key))
+            {
+                return Ok(Some(found));
+            }
+            if is_last_page {
+                break;
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Downloads the documents of a signature request into `writer`.
+    ///
+    /// The response body is streamed chunk by chunk, so large merged packets are
+    /// never held in memory as a whole. The writer is flushed once the download
+    /// completes.
+    ///
+    /// # Arguments
+    ///
+    /// * `signature_request_id` - The unique identifier of the signature request
+    /// * `file_type` - Whether to download a merged PDF or a zip of the documents
+    /// * `writer` - Destination the document contents are written to
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes written, or an error if the request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails or the connection drops mid-download
+    /// - The API returns an error response (e.g., the files are still being processed)
+    /// - Writing to `writer` fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::DropboxSignClient;
+    /// use dropboxsign_client::signature_request::SignatureRequestFileType;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::new("your-api-key");
+    /// let mut file = tokio::fs::File::create("signed.pdf").await?;
+    ///
+    /// client
+    ///     .download_files_to(&"signature-request-id".into(), SignatureRequestFileType::Pdf, &mut file)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_files_to<W>(
+        &self,
+        signature_request_id: &SignatureRequestId,
+        file_type: SignatureRequestFileType,
+        writer: &mut W,
+    ) -> Result<u64, DropboxSignClientError>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        self.download_files_with_progress(signature_request_id, file_type, writer, |_| {})
+            .await
+    }
+
+    /// Downloads the documents of a signature request into `writer`, reporting
+    /// progress as chunks arrive.
+    ///
+    /// The total in each [`Progress`] is taken from the response's
+    /// `Content-Length` and is `None` when the API streams the body without one.
+    ///
+    /// # Arguments
+    ///
+    /// * `signature_request_id` - The unique identifier of the signature request
+    /// * `file_type` - Whether to download a merged PDF or a zip of the documents
+    /// * `writer` - Destination the document contents are written to
+    /// * `on_progress` - Called with the download progress after each chunk is written
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes written, or an error if the request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails or the connection drops mid-download
+    /// - The API returns an error response (e.g., the files are still being processed)
+    /// - Writing to `writer` fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::DropboxSignClient;
+    /// use dropboxsign_client::signature_request::SignatureRequestFileType;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::new("your-api-key");
+    /// let mut file = tokio::fs::File::create("signed.zip").await?;
+    ///
+    /// client
+    ///     .download_files_with_progress(
+    ///         &"signature-request-id".into(),
+    ///         SignatureRequestFileType::Zip,
+    ///         &mut file,
+    ///         |progress| println!("downloaded {} bytes", progress.transferred),
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_files_with_progress<W>(
+        &self,
+        signature_request_id: &SignatureRequestId,
+        file_type: SignatureRequestFileType,
+        writer: &mut W,
+        on_progress: impl Fn(Progress),
+    ) -> Result<u64, DropboxSignClientError>
+    where
+        W: AsyncWrite + Unpin + ?Sized,
+    {
+        let request = self
+            .request(
+                Method::GET,
+                &format!("/signature_request/files/{signature_request_id}"),
+            )
+            .query(&[("file_type", file_type)]);
+
+        let call = async {
+            let response = check_status(self.execute(request).await?).await?;
+            let total = response.content_length();
+            let mut chunks = response.bytes_stream();
+            let mut transferred = 0;
+
+            while let Some(chunk) = chunks.next().await {
+                let chunk = chunk?;
+                writer.write_all(&chunk).await?;
+                transferred += chunk.len() as u64;
+                on_progress(Progress { transferred, total });
+            }
+            writer.flush().await?;
+
+            Ok(transferred)
+        };
+
+        self.run(call, |_| 0).await
+    }
+
+    /// Sends a signature request using a template.
+    ///
+    /// This method creates and sends a signature request based on a pre-existing
+    /// template. The template defines the document layout and form fields, while
+    /// the request specifies the signers and other dynamic parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `send_signature_request` - The signature request configuration including
+    ///   signers, template IDs, and optional parameters
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the created signature request data and any warnings,
+    /// or an error if the request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response (e.g., invalid template ID, missing signers)
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::{DropboxSignClient, signature_request::*};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::new("your-api-key");
+    ///
+    /// let signer = SubSignatureRequestTemplateSigner::new(
+    ///     "Signer".to_string(),
+    ///     "John Doe".to_string(),
+    ///     "john@example.com".to_string()
+    /// );
+    ///
+    /// let request = SendWithTemplateRequest::new(
+    ///     vec![signer],
+    ///     vec!["template-id".to_string()]
+    /// )
+    /// .title("Contract Signature".to_string())
+    /// .test_mode(true);
+    ///
+    /// let (signature_request, warnings) = client
+    ///     .send_with_template(request)
+    ///     .await?;
+    ///
+    /// println!("Sent signature request: {}", signature_request.signature_request_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_with_template(
+        &self,
+        mut send_signature_request: SendWithTemplateRequest,
+    ) -> Result<(SignatureRequestResponse, Option<Vec<WarningResponse>>), DropboxSignClientError>
+    {
+        self.defaults.apply(
+            Some(&mut send_signature_request.client_id),
+            &mut send_signature_request.test_mode,
+            &mut send_signature_request.metadata,
+        )?;
+        send_signature_request.validate()?;
+        let request = self
+            .request(Method::POST, "/signature_request/send_with_template")
+            .json(&send_signature_request);
+
+        let (sig_req, warnings) = self
+            .send::<SignatureRequestResponse>(request, "signature_request")
+            .await?;
+        telemetry::debug_payload("signature request sent", &sig_req, &self.redaction);
+        Ok((sig_req, warnings))
+    }
+
+    /// Sends a new signature request for uploaded documents.
+    ///
+    /// Unlike [`send_with_template`](Self::send_with_template), the documents are
+    /// not based on a template: they are uploaded with the request or downloaded
+    /// by the API from URLs, and signers are identified by name and email address.
+    ///
+    /// # Arguments
+    ///
+    /// * `send_with_files_request` - The signature request configuration including
+    ///   signers, documents, and optional parameters
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the created signature request data and any warnings,
+    /// or an error if the request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - A file cannot be read
+    /// - The HTTP request fails
+    /// - The API returns an error response (e.g., unreadable documents)
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::{DropboxSignClient, signature_request::*};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::new("your-api-key");
+    ///
+    /// let request = SendWithFilesRequest::new(
+    ///     vec![SubSignatureRequestSigner::new(
+    ///         "John Doe".to_string(),
+    ///         "john@example.com".to_string(),
+    ///     )],
+    ///     SignatureRequestDocuments::file_urls(["https://example.com/contract.pdf"]),
+    /// )
+    /// .test_mode(true);
+    ///
+    /// let (signature_request, _) = client.send_with_files(request).await?;
+    ///
+    /// println!("Sent signature request: {}", signature_request.signature_request_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_with_files(
+        &self,
+        mut send_with_files_request: SendWithFilesRequest,
+    ) -> Result<(SignatureRequestResponse, Option<Vec<WarningResponse>>), DropboxSignClientError>
+    {
+        self.defaults.apply(
+            Some(&mut send_with_files_request.client_id),
+            &mut send_with_files_request.test_mode,
+            &mut send_with_files_request.metadata,
+        )?;
+        send_with_files_request.validate()?;
+        let request = self.request(Method::POST, "/signature_request/send");
+        let files = FilePart::indexed("files", send_with_files_request.documents.take_files());
+        let request = multipart::with_body(request, &send_with_files_request, files).await?;
+
+        let (sig_req, warnings) = self
+            .send::<SignatureRequestResponse>(request, "signature_request")
+            .await?;
+        telemetry::debug_payload("signature request sent", &sig_req, &self.redaction);
+        Ok((sig_req, warnings))
+    }
+
+    pub async fn cancel_incomplete_signature_request(
+        &self,
+        signature_request_id: &SignatureRequestId,
+    ) -> Result<StatusCode, DropboxSignClientError> {
+        let request = self.request(
+            Method::POST,
+            &format!("/signature_request/cancel/{signature_request_id}"),
+        );
+
+        self.send_empty(request).await
+    }
+
+    /// Creates an unclaimed draft that the end user can claim and finish preparing.
+    ///
+    /// The returned `claim_url` opens the draft in Dropbox Sign, where the user can
+    /// review the documents, adjust signers and send the request themselves.
+    ///
+    /// # Arguments
+    ///
+    /// * `create_unclaimed_draft_request` - The draft configuration including the draft
+    ///   type, documents and optional signers
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the created unclaimed draft and any warnings, or an error
+    /// if the request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response (e.g., missing files)
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::{DropboxSignClient, unclaimed_draft::*};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::new("your-api-key");
+    ///
+    /// let request = CreateUnclaimedDraftRequest::new(UnclaimedDraftType::RequestSignature)
+    ///     .file_urls(vec!["https://example.com/contract.pdf".to_string()])
+    ///     .test_mode(true);
+    ///
+    /// let (draft, warnings) = client.create_unclaimed_draft(request).await?;
+    ///
+    /// println!("Claim URL: {}", draft.claim_url);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_unclaimed_draft(
+        &self,
+        mut create_unclaimed_draft_request: CreateUnclaimedDraftRequest,
+    ) -> Result<(UnclaimedDraftResponse, Option<Vec<WarningResponse>>), DropboxSignClientError>
+    {
+        self.defaults.apply(
+            Some(&mut create_unclaimed_draft_request.client_id),
+            &mut create_unclaimed_draft_request.test_mode,
+            &mut create_unclaimed_draft_request.metadata,
+        )?;
+        create_unclaimed_draft_request.validate()?;
+        let request = self.request(Method::POST, "/unclaimed_draft/create");
+        let files = FilePart::indexed("files", create_unclaimed_draft_request.files.take());
+        let request = multipart::with_body(request, &create_unclaimed_draft_request, files).await?;
+
+        self.send(request, "unclaimed_draft").await
+    }
+
+    /// Creates an unclaimed draft for the embedded requesting flow.
+    ///
+    /// The returned `claim_url` is opened inside your application with the
+    /// Dropbox Sign embedded client, where the requester finishes preparing and
+    /// sends the signature request.
+    ///
+    /// # Arguments
+    ///
+    /// * `create_embedded_unclaimed_draft_request` - The draft configuration including
+    ///   the API app client ID and the requester email address
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the created unclaimed draft and any warnings, or an error
+    /// if the request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response (e.g., unknown client ID)
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::{DropboxSignClient, unclaimed_draft::*};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::new("your-api-key");
+    ///
+    /// let request = CreateEmbeddedUnclaimedDraftRequest::new(
+    ///     "client-id".to_string(),
+    ///     "requester@example.com".to_string()
+    /// )
+    /// .file_urls(vec!["https://example.com/contract.pdf".to_string()])
+    /// .requesting_redirect_url("https://example.com/sent".to_string());
+    ///
+    /// let (draft, warnings) = client.create_embedded_unclaimed_draft(request).await?;
+    ///
+    /// println!("Claim URL: {}", draft.claim_url);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_embedded_unclaimed_draft(
+        &self,
+        mut create_embedded_unclaimed_draft_request: CreateEmbeddedUnclaimedDraftRequest,
+    ) -> Result<(UnclaimedDraftResponse, Option<Vec<WarningResponse>>), DropboxSignClientError>
+    {
+        self.defaults.apply(
+            None,
+            &mut create_embedded_unclaimed_draft_request.test_mode,
+            &mut create_embedded_unclaimed_draft_request.metadata,
+        )?;
+        create_embedded_unclaimed_draft_request.validate()?;
+        let request = self.request(Method::POST, "/unclaimed_draft/create_embedded");
+        let files = FilePart::indexed(
+            "files",
+            create_embedded_unclaimed_draft_request.files.take(),
+        );
+        let request =
+            multipart::with_body(request, &create_embedded_unclaimed_draft_request, files).await?;
+
+        self.send(request, "unclaimed_draft").await
+    }
+
+    /// Retrieves a URL for signing in an embedded iframe.
+    ///
+    /// The URL expires shortly after it is issued; request a new one each time the
+    /// signing iframe is opened.
+    ///
+    /// # Arguments
+    ///
+    /// * `signature_id` - Identifier of the signer's signature within the request
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the sign URL and its expiry, and any warnings, or an error
+    /// if the request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response (e.g., the request was not created for
+    ///   embedded signing)
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::DropboxSignClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::new("your-api-key");
+    /// let (embedded, _) = client.get_embedded_sign_url(&"signature-id".into()).await?;
+    ///
+    /// println!("Sign URL: {}", embedded.sign_url);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_embedded_sign_url(
+        &self,
+        signature_id: &SignatureId,
+    ) -> Result<(EmbeddedResponse, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        let request = self.request(Method::GET, &format!("/embedded/sign_url/{signature_id}"));
+
+        self.send(request, "embedded").await
+    }
+
+    /// Retrieves a URL for editing a template in an embedded iframe.
+    ///
+    /// The URL expires shortly after it is issued; request a new one each time the
+    /// editor iframe is opened.
+    ///
+    /// # Arguments
+    ///
+    /// * `template_id` - The unique identifier of the template
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the edit URL and its expiry, and any warnings, or an error
+    /// if the request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    pub async fn get_embedded_template_edit_url(
+        &self,
+        template_id: &TemplateId,
+    ) -> Result<(TemplateEditResponse, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        let request = self.request(Method::POST, &format!("/embedded/edit_url/{template_id}"));
+
+        self.send(request, "embedded").await
+    }
+
+    /// Retrieves the account associated with the API key.
+    ///
+    /// The response includes the remaining quotas, which can be checked before
+    /// sending to avoid failed calls once the API signature request quota is used up.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the account data and any warnings, or an error if the
+    /// request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::DropboxSignClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::new("your-api-key");
+    /// let (account, warnings) = client.get_account().await?;
+    ///
+    /// println!("Account: {}", account.account_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_account(
+        &self,
+    ) -> Result<(AccountResponse, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        let request = self.request(Method::GET, "/account");
+
+        self.send_cached(request, "account", CachedEndpoint::Account, None)
+            .await
+    }
+
+    /// Updates the settings of the account associated with the API key.
+    ///
+    /// # Arguments
+    ///
+    /// * `update_account_request` - The settings to change
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the updated account data and any warnings, or an error if
+    /// the request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response (e.g., unreachable callback URL)
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::{DropboxSignClient, account::UpdateAccountRequest};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::new("your-api-key");
+    ///
+    /// let request = UpdateAccountRequest::new()
+    ///     .callback_url("https://example.com/dropboxsign/callback".to_string());
+    ///
+    /// let (account, warnings) = client.update_account(request).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_account(
+        &self,
+        update_account_request: UpdateAccountRequest,
+    ) -> Result<(AccountResponse, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        update_account_request.validate()?;
+        let request = self
+            .request(Method::POST, "/account")
+            .json(&update_account_request);
+
+        let result = self.send(request, "account").await;
+        self.invalidate_cache(CachedEndpoint::Account, None);
+        result
+    }
+
+    /// Creates a new Dropbox Sign account for the given email address.
+    ///
+    /// When `client_id` and `client_secret` of an API app are supplied, the account
+    /// is created through that app and the response includes OAuth credentials for
+    /// acting on behalf of the new account.
+    ///
+    /// # Arguments
+    ///
+    /// * `email_address` - Email address of the account to create
+    /// * `client_id` - Optional client ID of the API app creating the account
+    /// * `client_secret` - Optional client secret of the API app creating the account
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the created account (and OAuth data, if any) and any
+    /// warnings, or an error if the request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response (e.g., the account already exists)
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::DropboxSignClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::new("your-api-key");
+    ///
+    /// let (created, warnings) = client
+    ///     .create_account("new-user@example.com", Some(&"client-id".into()), Some("client-secret"))
+    ///     .await?;
+    ///
+    /// println!("Created account: {}", created.account.account_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_account(
+        &self,
+        email_address: &str,
+        client_id: Option<&ClientId>,
+        client_secret: Option<&str>,
+    ) -> Result<(AccountCreateResponse, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        let mut create_account_request = CreateAccountRequest::new(email_address.to_string());
+        if let Some(client_id) = client_id {
+            create_account_request = create_account_request.client_id(client_id.clone());
+        }
+        if let Some(client_secret) = client_secret {
+            create_account_request =
+                create_account_request.client_secret(client_secret.to_string());
+        }
+        create_account_request.validate()?;
+
+        let request = self
+            .request(Method::POST, "/account/create")
+            .json(&create_account_request);
+
+        self.send_flattened(request).await
+    }
+
+    /// Checks whether an email address belongs to an existing paid Dropbox Sign account.
+    ///
+    /// This is only available to paid API apps and is useful before calling
+    /// [`create_account`](Self::create_account) for a user who may already have an account.
+    ///
+    /// # Arguments
+    ///
+    /// * `email_address` - Email address to look up
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the verification result and any warnings, or an error if the
+    /// request fails. The result's `account` is `None` when no paid account exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::DropboxSignClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::new("your-api-key");
+    /// let (verified, warnings) = client.verify_account("user@example.com").await?;
+    ///
+    /// if verified.account.is_none() {
+    ///     println!("No existing account");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn verify_account(
+        &self,
+        email_address: &str,
+    ) -> Result<(AccountVerifyResponse, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        let verify_account_request = VerifyAccountRequest::new(email_address.to_string());
+        verify_account_request.validate()?;
+
+        let request = self
+            .request(Method::POST, "/account/verify")
+            .json(&verify_account_request);
+
+        self.send_flattened(request).await
+    }
+
+    /// Retrieves the team the account associated with the API key belongs to.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the team data, including members and pending invitations,
+    /// and any warnings, or an error if the request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response (e.g., the account is not on a team)
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::DropboxSignClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::new("your-api-key");
+    /// let (team, warnings) = client.get_team().await?;
+    ///
+    /// for account in &team.accounts {
+    ///     println!("Member: {}", account.account_id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_team(
+        &self,
+    ) -> Result<(TeamResponse, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        let request = self.request(Method::GET, "/team");
+
+        self.send(request, "team").await
+    }
+
+    /// Creates a new team and makes the account associated with the API key its admin.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the new team
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the created team and any warnings, or an error if the
+    /// request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response (e.g., the account is already on a team)
+    /// - The response cannot be parsed
+    pub async fn create_team(
+        &self,
+        name: &str,
+    ) -> Result<(TeamResponse, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        let create_team_request = CreateTeamRequest::new(name.to_string());
+        create_team_request.validate()?;
+
+        let request = self
+            .request(Method::POST, "/team/create")
+            .json(&create_team_request);
+
+        self.send(request, "team").await
+    }
+
+    /// Renames the team the account associated with the API key belongs to.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - New name for the team
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the updated team and any warnings, or an error if the
+    /// request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response (e.g., the caller is not a team admin)
+    /// - The response cannot be parsed
+    pub async fn update_team(
+        &self,
+        name: &str,
+    ) -> Result<(TeamResponse, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        let update_team_request = UpdateTeamRequest::new(name.to_string());
+        update_team_request.validate()?;
+
+        let request = self
+            .request(Method::PUT, "/team")
+            .json(&update_team_request);
+
+        self.send(request, "team").await
+    }
+
+    /// Deletes the team the account associated with the API key belongs to.
+    ///
+    /// All members are removed from the team; their accounts are kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response (e.g., the caller is not a team admin)
+    pub async fn delete_team(&self) -> Result<StatusCode, DropboxSignClientError> {
+        let request = self.request(Method::DELETE, "/team/destroy");
+
+        self.send_empty(request).await
+    }
+
+    /// Adds an existing account or invites an email address to the team.
+    ///
+    /// # Arguments
+    ///
+    /// * `member` - Account ID or email address of the member to add
+    /// * `role` - Optional role for the member (defaults to `Member`)
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the updated team and any warnings, or an error if the
+    /// request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response (e.g., the account is on another team)
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::{DropboxSignClient, team::*};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::new("your-api-key");
+    ///
+    /// let (team, warnings) = client
+    ///     .add_team_member(
+    ///         TeamMemberIdentifier::EmailAddress("new-hire@example.com".to_string()),
+    ///         Some(TeamMemberRole::Developer),
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_team_member(
+        &self,
+        member: TeamMemberIdentifier,
+        role: Option<TeamMemberRole>,
+    ) -> Result<(TeamResponse, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        let mut add_team_member_request = AddTeamMemberRequest::new(member);
+        if let Some(role) = role {
+            add_team_member_request = add_team_member_request.role(role);
+        }
+        add_team_member_request.validate()?;
+
+        let request = self
+            .request(Method::POST, "/team/add_member")
+            .json(&add_team_member_request);
+
+        self.send(request, "team").await
+    }
+
+    /// Removes a member from the team, optionally reassigning their documents.
+    ///
+    /// # Arguments
+    ///
+    /// * `remove_team_member_request` - The member to remove and where their
+    ///   documents and membership should go
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the updated team and any warnings, or an error if the
+    /// request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response (e.g., the new owner is not on the team)
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::{DropboxSignClient, team::*};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::new("your-api-key");
+    ///
+    /// let request = RemoveTeamMemberRequest::new(
+    ///     TeamMemberIdentifier::EmailAddress("leaver@example.com".to_string())
+    /// )
+    /// .new_owner_email_address("manager@example.com".to_string());
+    ///
+    /// let (team, warnings) = client.remove_team_member(request).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_team_member(
+        &self,
+        remove_team_member_request: RemoveTeamMemberRequest,
+    ) -> Result<(TeamResponse, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        remove_team_member_request.validate()?;
+        let request = self
+            .request(Method::POST, "/team/remove_member")
+            .json(&remove_team_member_request);
+
+        self.send(request, "team").await
+    }
+
+    /// Lists the members of a team, one page at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `team_id` - Identifier of the team to list
+    /// * `page` - Optional page number to fetch (1-based, defaults to 1)
+    /// * `page_size` - Optional number of members per page (defaults to 20)
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the members on the requested page along with the
+    /// pagination information, and any warnings, or an error if the request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response (e.g., unknown team ID)
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::DropboxSignClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::new("your-api-key");
+    /// let (members, warnings) = client
+    ///     .list_team_members("team-id", Some(1), Some(100))
+    ///     .await?;
+    ///
+    /// println!("Pages: {}", members.list_info.num_pages);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_team_members(
+        &self,
+        team_id: &str,
+        page: Option<u64>,
+        page_size: Option<u64>,
+    ) -> Result<(TeamMembersResponse, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        let request = self
+            .request(Method::GET, &format!("/team/members/{team_id}"))
+            .query(&PageQuery { page, page_size });
+
+        self.send_flattened(request).await
+    }
+
+    /// Streams every team member, fetching further pages as the stream is consumed.
+    ///
+    /// Warnings returned alongside the pages are dropped. The stream ends after the
+    /// first error.
+    ///
+    /// # Arguments
+    ///
+    /// * `team_id` - Identifier of the team
+    /// * `page_size` - Optional number of team members fetched per page (defaults to 20)
+    pub fn list_team_members_stream(
+        &self,
+        team_id: &str,
+        page_size: Option<u64>,
+    ) -> impl Stream<Item = Result<TeamMemberResponse, DropboxSignClientError>> + use<> {
+        let client = self.clone();
+        let team_id = team_id.to_string();
+
+        paginate::paginate(move |page| {
+            let client = client.clone();
+            let team_id = team_id.clone();
+            async move {
+                let (response, _) = client
+                    .list_team_members(&team_id, Some(page), page_size)
+                    .await?;
+                Ok(response)
+            }
+        })
+    }
+
+    /// Lists the sub-teams directly below a team, one page at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `team_id` - Identifier of the parent team
+    /// * `page` - Optional page number to fetch (1-based, defaults to 1)
+    /// * `page_size` - Optional number of sub-teams per page (defaults to 20)
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the sub-teams on the requested page along with the
+    /// pagination information, and any warnings, or an error if the request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response (e.g., unknown team ID)
+    /// - The response cannot be parsed
+    pub async fn list_sub_teams(
+        &self,
+        team_id: &str,
+        page: Option<u64>,
+        page_size: Option<u64>,
+    ) -> Result<(SubTeamsResponse, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        let request = self
+            .request(Method::GET, &format!("/team/sub_teams/{team_id}"))
+            .query(&PageQuery { page, page_size });
+
+        self.send_flattened(request).await
+    }
+
+    /// Streams every sub-team, fetching further pages as the stream is consumed.
+    ///
+    /// Warnings returned alongside the pages are dropped. The stream ends after the
+    /// first error.
+    ///
+    /// # Arguments
+    ///
+    /// * `team_id` - Identifier of the parent team
+    /// * `page_size` - Optional number of sub-teams fetched per page (defaults to 20)
+    pub fn list_sub_teams_stream(
+        &self,
+        team_id: &str,
+        page_size: Option<u64>,
+    ) -> impl Stream<Item = Result<SubTeamResponse, DropboxSignClientError>> + use<> {
+        let client = self.clone();
+        let team_id = team_id.to_string();
+
+        paginate::paginate(move |page| {
+            let client = client.clone();
+            let team_id = team_id.clone();
+            async move {
+                let (response, _) = client
+                    .list_sub_teams(&team_id, Some(page), page_size)
+                    .await?;
+                Ok(response)
+            }
+        })
+    }
+
+    /// Lists the pending invitations for the team.
+    ///
+    /// # Arguments
+    ///
+    /// * `email_address` - Optional email address to only return invitations for
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the invitations and any warnings, or an error if the
+    /// request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    pub async fn list_team_invites(
+        &self,
+        email_address: Option<&str>,
+    ) -> Result<(TeamInvitesResponse, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        let request = self
+            .request(Method::GET, "/team/invites")
+            .query(&EmailAddressQuery { email_address });
+
+        self.send_flattened(request).await
+    }
+
+    /// Revokes pending team invitations.
+    ///
+    /// The API has no dedicated revoke endpoint; an invitation is revoked by removing
+    /// the invited email address through `POST /team/remove_member`. When
+    /// `email_address` is `None`, every pending invitation is revoked.
+    ///
+    /// # Arguments
+    ///
+    /// * `email_address` - Optional email address whose invitation should be revoked
+    ///
+    /// # Returns
+    ///
+    /// The email addresses whose invitations were revoked, or an error if a request
+    /// fails. Invitations revoked before the failure stay revoked.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::DropboxSignClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::new("your-api-key");
+    /// let revoked = client.delete_team_invites(None).await?;
+    ///
+    /// println!("Revoked {} invitations", revoked.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_team_invites(
+        &self,
+        email_address: Option<&str>,
+    ) -> Result<Vec<String>, DropboxSignClientError> {
+        let (invites, _) = self.list_team_invites(email_address).await?;
+
+        let mut revoked = Vec::new();
+        for invite in invites
+            .team_invites
+            .into_iter()
+            .filter(|invite| invite.redeemed_at.is_none())
+        {
+            let remove_team_member_request = RemoveTeamMemberRequest::new(
+                TeamMemberIdentifier::EmailAddress(invite.email_address.clone()),
+            );
+            self.remove_team_member(remove_team_member_request).await?;
+            revoked.push(invite.email_address);
+        }
+
+        Ok(revoked)
+    }
+
+    /// Retrieves an API app by its client ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - Client ID of the API app
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the API app data and any warnings, or an error if the
+    /// request fails or the API app is not found.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::DropboxSignClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::new("your-api-key");
+    /// let (api_app, warnings) = client.get_api_app(&"client-id".into()).await?;
+    ///
+    /// println!("Domains: {:?}", api_app.domains);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_api_app(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<(ApiAppResponse, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        let request = self.request(Method::GET, &format!("/api_app/{client_id}"));
+
+        self.send_cached(
+            request,
+            "api_app",
+            CachedEndpoint::ApiApp,
+            Some(client_id.as_str()),
+        )
+        .await
+    }
+
+    /// Lists the API apps owned by the account, one page at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - Optional page number to fetch (1-based, defaults to 1)
+    /// * `page_size` - Optional number of API apps per page (defaults to 20)
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the API apps on the requested page along with the
+    /// pagination information, and any warnings, or an error if the request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    pub async fn list_api_apps(
+        &self,
+        page: Option<u64>,
+        page_size: Option<u64>,
+    ) -> Result<(ApiAppsResponse, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        let request = self
+            .request(Method::GET, "/api_app/list")
+            .query(&PageQuery { page, page_size });
+
+        self.send_flattened(request).await
+    }
+
+    /// Streams every API app, fetching further pages as the stream is consumed.
+    ///
+    /// Warnings returned alongside the pages are dropped. The stream ends after the
+    /// first error.
+    ///
+    /// # Arguments
+    ///
+    /// * `page_size` - Optional number of API apps fetched per page (defaults to 20)
+    pub fn list_api_apps_stream(
+        &self,
+        page_size: Option<u64>,
+    ) -> impl Stream<Item = Result<ApiAppResponse, DropboxSignClientError>> + use<> {
+        let client = self.clone();
+
+        paginate::paginate(move |page| {
+            let client = client.clone();
+            async move {
+                let (response, _) = client.list_api_apps(Some(page), page_size).await?;
+                Ok(response)
+            }
+        })
+    }
+
+    /// Creates a new API app.
+    ///
+    /// # Arguments
+    ///
+    /// * `create_api_app_request` - The API app configuration including name,
+    ///   domains and optional callback, OAuth and branding settings
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the created API app and any warnings, or an error if the
+    /// request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response (e.g., invalid domain)
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::{DropboxSignClient, api_app::*};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::new("your-api-key");
+    ///
+    /// let request = CreateApiAppRequest::new(
+    ///     "Staging".to_string(),
+    ///     vec!["staging.example.com".to_string()]
+    /// )
+    /// .callback_url("https://staging.example.com/dropboxsign/callback".to_string());
+    ///
+    /// let (api_app, warnings) = client.create_api_app(request).await?;
+    ///
+    /// println!("Client ID: {}", api_app.client_id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_api_app(
+        &self,
+        mut create_api_app_request: CreateApiAppRequest,
+    ) -> Result<(ApiAppResponse, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        create_api_app_request.validate()?;
+        let request = self.request(Method::POST, "/api_app");
+        let files = FilePart::single(
+            "custom_logo_file",
+            create_api_app_request.custom_logo_file.take(),
+        );
+        let request = multipart::with_body(request, &create_api_app_request, files).await?;
+
+        self.send(request, "api_app").await
+    }
+
+    /// Updates an existing API app.
+    ///
+    /// Only the fields set on `update_api_app_request` are sent, so settings that
+    /// are not mentioned keep their current values.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - Client ID of the API app to update
+    /// * `update_api_app_request` - The settings to change
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the updated API app and any warnings, or an error if the
+    /// request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    pub async fn update_api_app(
+        &self,
+        client_id: &ClientId,
+        mut update_api_app_request: UpdateApiAppRequest,
+    ) -> Result<(ApiAppResponse, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        update_api_app_request.validate()?;
+        let request = self.request(Method::PUT, &format!("/api_app/{client_id}"));
+        let files = FilePart::single(
+            "custom_logo_file",
+            update_api_app_request.custom_logo_file.take(),
         );
+        let request = multipart::with_body(request, &update_api_app_request, files).await?;
+
+        let result = self.send(request, "api_app").await;
+        self.invalidate_cache(CachedEndpoint::ApiApp, Some(client_id.as_str()));
+        result
+    }
+
+    /// Deletes an API app.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - Client ID of the API app to delete
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response (e.g., unknown client ID)
+    pub async fn delete_api_app(
+        &self,
+        client_id: &ClientId,
+    ) -> Result<StatusCode, DropboxSignClientError> {
+        let request = self.request(Method::DELETE, &format!("/api_app/{client_id}"));
+
+        let result = self.send_empty(request).await;
+        self.invalidate_cache(CachedEndpoint::ApiApp, Some(client_id.as_str()));
+        result
+    }
+
+    /// Lists the faxes sent by the account, one page at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - Optional page number to fetch (1-based, defaults to 1)
+    /// * `page_size` - Optional number of faxes per page (defaults to 20)
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the faxes on the requested page along with the
+    /// pagination information, and any warnings, or an error if the request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    pub async fn list_faxes(
+        &self,
+        page: Option<u64>,
+        page_size: Option<u64>,
+    ) -> Result<(FaxesResponse, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        let request = self
+            .request(Method::GET, "/fax/list")
+            .query(&PageQuery { page, page_size });
+
+        self.send_flattened(request).await
+    }
+
+    /// Streams every fax, fetching further pages as the stream is consumed.
+    ///
+    /// Warnings returned alongside the pages are dropped. The stream ends after the
+    /// first error.
+    ///
+    /// # Arguments
+    ///
+    /// * `page_size` - Optional number of faxes fetched per page (defaults to 20)
+    pub fn list_faxes_stream(
+        &self,
+        page_size: Option<u64>,
+    ) -> impl Stream<Item = Result<FaxResponse, DropboxSignClientError>> + use<> {
+        let client = self.clone();
+
+        paginate::paginate(move |page| {
+            let client = client.clone();
+            async move {
+                let (response, _) = client.list_faxes(Some(page), page_size).await?;
+                Ok(response)
+            }
+        })
+    }
+
+    /// Retrieves a fax by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `fax_id` - The unique identifier of the fax
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the fax data, including the status of each transmission,
+    /// and any warnings, or an error if the request fails or the fax is not found.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::DropboxSignClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::new("your-api-key");
+    /// let (fax, warnings) = client.get_fax("fax-id").await?;
+    ///
+    /// for transmission in &fax.transmissions {
+    ///     println!("{}: {:?}", transmission.recipient, transmission.status_code);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_fax(
+        &self,
+        fax_id: &str,
+    ) -> Result<(FaxResponse, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        let request = self.request(Method::GET, &format!("/fax/{fax_id}"));
+
+        self.send(request, "fax").await
+    }
+
+    /// Downloads the document of a fax as a PDF.
+    ///
+    /// # Arguments
+    ///
+    /// * `fax_id` - The unique identifier of the fax
+    ///
+    /// # Returns
+    ///
+    /// The PDF file contents, or an error if the request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response (e.g., the fax is still processing)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::DropboxSignClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::new("your-api-key");
+    /// let pdf = client.get_fax_files("fax-id").await?;
+    ///
+    /// std::fs::write("fax.pdf", pdf)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_fax_files(&self, fax_id: &str) -> Result<Vec<u8>, DropboxSignClientError> {
+        let request = self.request(Method::GET, &format!("/fax/files/{fax_id}"));
+
+        self.send_bytes(request).await
+    }
+
+    /// Deletes a fax.
+    ///
+    /// # Arguments
+    ///
+    /// * `fax_id` - The unique identifier of the fax
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response (e.g., unknown fax ID)
+    pub async fn delete_fax(&self, fax_id: &str) -> Result<StatusCode, DropboxSignClientError> {
+        let request = self.request(Method::DELETE, &format!("/fax/{fax_id}"));
+
+        self.send_empty(request).await
+    }
+
+    /// Purchases a new fax line.
+    ///
+    /// # Arguments
+    ///
+    /// * `area_code` - Area code of the new number
+    /// * `country` - Country of the new number
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the new fax line and any warnings, or an error if the
+    /// request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response (e.g., no numbers left in the area code)
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::{DropboxSignClient, fax_line::FaxLineCountry};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::new("your-api-key");
+    /// let (fax_line, warnings) = client
+    ///     .create_fax_line(415, FaxLineCountry::UnitedStates)
+    ///     .await?;
+    ///
+    /// println!("New number: {}", fax_line.number);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_fax_line(
+        &self,
+        area_code: u32,
+        country: FaxLineCountry,
+    ) -> Result<(FaxLineResponse, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        let create_fax_line_request = CreateFaxLineRequest {
+            area_code,
+            country,
+            city: None,
+            account_id: None,
+        };
+        create_fax_line_request.validate()?;
+
+        let request = self
+            .request(Method::POST, "/fax_line/create")
+            .json(&create_fax_line_request);
+
+        self.send(request, "fax_line").await
+    }
+
+    /// Lists the fax lines of the account, one page at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - Optional page number to fetch (1-based, defaults to 1)
+    /// * `page_size` - Optional number of fax lines per page (defaults to 20)
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the fax lines on the requested page along with the
+    /// pagination information, and any warnings, or an error if the request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    pub async fn list_fax_lines(
+        &self,
+        page: Option<u64>,
+        page_size: Option<u64>,
+    ) -> Result<(FaxLinesResponse, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        let request = self
+            .request(Method::GET, "/fax_line/list")
+            .query(&PageQuery { page, page_size });
+
+        self.send_flattened(request).await
+    }
+
+    /// Streams every fax line, fetching further pages as the stream is consumed.
+    ///
+    /// Warnings returned alongside the pages are dropped. The stream ends after the
+    /// first error.
+    ///
+    /// # Arguments
+    ///
+    /// * `page_size` - Optional number of fax lines fetched per page (defaults to 20)
+    pub fn list_fax_lines_stream(
+        &self,
+        page_size: Option<u64>,
+    ) -> impl Stream<Item = Result<FaxLineResponse, DropboxSignClientError>> + use<> {
+        let client = self.clone();
+
+        paginate::paginate(move |page| {
+            let client = client.clone();
+            async move {
+                let (response, _) = client.list_fax_lines(Some(page), page_size).await?;
+                Ok(response)
+            }
+        })
+    }
+
+    /// Retrieves a fax line by its number.
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - The fax number
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the fax line and any warnings, or an error if the request
+    /// fails or the fax line is not found.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    pub async fn get_fax_line(
+        &self,
+        number: &str,
+    ) -> Result<(FaxLineResponse, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        let request = self
+            .request(Method::GET, "/fax_line")
+            .query(&FaxLineQuery { number });
+
+        self.send(request, "fax_line").await
+    }
+
+    /// Deletes a fax line, releasing the number.
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - The fax number to delete
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response (e.g., unknown number)
+    pub async fn delete_fax_line(
+        &self,
+        number: &str,
+    ) -> Result<StatusCode, DropboxSignClientError> {
+        let delete_fax_line_request = DeleteFaxLineRequest {
+            number: number.to_string(),
+        };
+        delete_fax_line_request.validate()?;
+
+        let request = self
+            .request(Method::DELETE, "/fax_line")
+            .json(&delete_fax_line_request);
+
+        self.send_empty(request).await
+    }
+
+    /// Grants an account access to a fax line.
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - The fax number
+    /// * `user` - Account ID or email address of the account to add
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the updated fax line and any warnings, or an error if the
+    /// request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response (e.g., the account is not on the team)
+    /// - The response cannot be parsed
+    pub async fn add_fax_line_user(
+        &self,
+        number: &str,
+        user: TeamMemberIdentifier,
+    ) -> Result<(FaxLineResponse, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        let fax_line_user_request = FaxLineUserRequest {
+            number: number.to_string(),
+            user,
+        };
+        fax_line_user_request.validate()?;
+
+        let request = self
+            .request(Method::PUT, "/fax_line/add_user")
+            .json(&fax_line_user_request);
+
+        self.send(request, "fax_line").await
+    }
+
+    /// Revokes an account's access to a fax line.
+    ///
+    /// # Arguments
+    ///
+    /// * `number` - The fax number
+    /// * `user` - Account ID or email address of the account to remove
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the updated fax line and any warnings, or an error if the
+    /// request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    pub async fn remove_fax_line_user(
+        &self,
+        number: &str,
+        user: TeamMemberIdentifier,
+    ) -> Result<(FaxLineResponse, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        let fax_line_user_request = FaxLineUserRequest {
+            number: number.to_string(),
+            user,
+        };
+        fax_line_user_request.validate()?;
+
+        let request = self
+            .request(Method::PUT, "/fax_line/remove_user")
+            .json(&fax_line_user_request);
+
+        self.send(request, "fax_line").await
+    }
 
-        let response = self
-            .client
-            .post(&url)
-            .basic_auth(&self.api_key, Some(""))
-            .send()
-            .await?;
+    /// Lists the area codes in which fax lines can currently be purchased.
+    ///
+    /// # Arguments
+    ///
+    /// * `country` - Country to look up
+    /// * `state` - Optional US state abbreviation to narrow the results
+    /// * `province` - Optional Canadian province abbreviation to narrow the results
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the available area codes and any warnings, or an error if
+    /// the request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response (e.g., unknown state)
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::{DropboxSignClient, fax_line::FaxLineCountry};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::new("your-api-key");
+    /// let (area_codes, warnings) = client
+    ///     .get_fax_line_area_codes(FaxLineCountry::UnitedStates, Some("CA"), None)
+    ///     .await?;
+    ///
+    /// println!("Available: {:?}", area_codes.area_codes);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_fax_line_area_codes(
+        &self,
+        country: FaxLineCountry,
+        state: Option<&str>,
+        province: Option<&str>,
+    ) -> Result<(FaxLineAreaCodesResponse, Option<Vec<WarningResponse>>), DropboxSignClientError>
+    {
+        let request =
+            self.request(Method::GET, "/fax_line/area_codes")
+                .query(&FaxLineAreaCodesQuery {
+                    country,
+                    state,
+                    province,
+                });
+
+        self.send_flattened(request).await
+    }
+
+    /// Lists the bulk send jobs created by the account, one page at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - Optional page number to fetch (1-based, defaults to 1)
+    /// * `page_size` - Optional number of bulk send jobs per page (defaults to 20)
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the bulk send jobs on the requested page along with the
+    /// pagination information, and any warnings, or an error if the request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    pub async fn list_bulk_send_jobs(
+        &self,
+        page: Option<u64>,
+        page_size: Option<u64>,
+    ) -> Result<(BulkSendJobsResponse, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        let request = self
+            .request(Method::GET, "/bulk_send_job/list")
+            .query(&PageQuery { page, page_size });
+
+        self.send_flattened(request).await
+    }
+
+    /// Streams every bulk send job, fetching further pages as the stream is consumed.
+    ///
+    /// Warnings returned alongside the pages are dropped. The stream ends after the
+    /// first error.
+    ///
+    /// # Arguments
+    ///
+    /// * `page_size` - Optional number of bulk send jobs fetched per page (defaults to 20)
+    pub fn list_bulk_send_jobs_stream(
+        &self,
+        page_size: Option<u64>,
+    ) -> impl Stream<Item = Result<BulkSendJobResponse, DropboxSignClientError>> + use<> {
+        let client = self.clone();
+
+        paginate::paginate(move |page| {
+            let client = client.clone();
+            async move {
+                let (response, _) = client.list_bulk_send_jobs(Some(page), page_size).await?;
+                Ok(response)
+            }
+        })
+    }
+
+    /// Requests activity reports for a range of days.
+    ///
+    /// Reports are generated asynchronously and emailed to the account associated
+    /// with the API key once they are ready.
+    ///
+    /// # Arguments
+    ///
+    /// * `create_report_request` - The reports and the days they cover
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the accepted report request and any warnings, or an
+    /// error if the request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The request fails validation (e.g., `end_date` before `start_date`)
+    /// - The HTTP request fails
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::{DropboxSignClient, report::*};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::new("your-api-key");
+    ///
+    /// let request = CreateReportRequest::new(
+    ///     ReportDate::new(2024, 1, 1)?,
+    ///     ReportDate::new(2024, 3, 31)?,
+    ///     [ReportType::DocumentStatus],
+    /// );
+    /// let (report, _) = client.create_report(request).await?;
+    ///
+    /// println!("Report from {} to {}", report.start_date, report.end_date);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_report(
+        &self,
+        create_report_request: CreateReportRequest,
+    ) -> Result<(ReportResponse, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        create_report_request.validate()?;
+
+        let request = self
+            .request(Method::POST, "/report/create")
+            .json(&create_report_request);
+
+        self.send(request, "report").await
+    }
+
+    /// Retrieves a template by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `template_id` - The unique identifier of the template
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the template data and any warnings.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::DropboxSignClient;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::new("your-api-key");
+    /// let (template, _) = client.get_template(&"template_id".into()).await?;
+    ///
+    /// println!("Template: {:?}", template.title);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_template(
+        &self,
+        template_id: &TemplateId,
+    ) -> Result<(TemplateResponse, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        let request = self.request(Method::GET, &format!("/template/{template_id}"));
+
+        self.send_cached(
+            request,
+            "template",
+            CachedEndpoint::Template,
+            Some(template_id.as_str()),
+        )
+        .await
+    }
+
+    /// Lists the templates the account can use, one page at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - Optional page number to fetch (1-based, defaults to 1)
+    /// * `page_size` - Optional number of templates per page (defaults to 20)
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the templates on the requested page along with the
+    /// pagination information, and any warnings, or an error if the request fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    pub async fn list_templates(
+        &self,
+        page: Option<u64>,
+        page_size: Option<u64>,
+    ) -> Result<(TemplatesResponse, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        let request = self
+            .request(Method::GET, "/template/list")
+            .query(&PageQuery { page, page_size });
+
+        self.send_flattened(request).await
+    }
+
+    /// Streams every template, fetching further pages as the stream is consumed.
+    ///
+    /// Warnings returned alongside the pages are dropped. The stream ends after the
+    /// first error.
+    ///
+    /// # Arguments
+    ///
+    /// * `page_size` - Optional number of templates fetched per page (defaults to 20)
+    pub fn list_templates_stream(
+        &self,
+        page_size: Option<u64>,
+    ) -> impl Stream<Item = Result<TemplateResponse, DropboxSignClientError>> + use<> {
+        let client = self.clone();
+
+        paginate::paginate(move |page| {
+            let client = client.clone();
+            async move {
+                let (response, _) = client.list_templates(Some(page), page_size).await?;
+                Ok(response)
+            }
+        })
+    }
+
+    /// Fetches the current state of the object a callback is about.
+    ///
+    /// Callback payloads are a snapshot taken when the event was sent, so they can
+    /// be stale by the time the callback is processed. Template events fetch the
+    /// template and signature request events fetch the signature request. The
+    /// template is always fetched from the API, replacing any cached copy, since
+    /// the event usually means it has changed.
+    ///
+    /// The callback is taken as a whole because the object IDs are carried in the
+    /// payload rather than in the event itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `callback` - Parsed callback payload
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the fetched object and any warnings. The object is `None`
+    /// for account events, test callbacks, and callbacks without an object payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::DropboxSignClient;
+    /// use dropboxsign_client::events::{EventCallbackRequest, HydratedEvent};
+    ///
+    /// # async fn example(callback: EventCallbackRequest) -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::new("your-api-key");
+    ///
+    /// if let (Some(HydratedEvent::SignatureRequest(signature_request)), _) =
+    ///     client.hydrate_event(&callback).await?
+    /// {
+    ///     println!("Complete: {}", signature_request.is_complete);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn hydrate_event(
+        &self,
+        callback: &EventCallbackRequest,
+    ) -> Result<(Option<HydratedEvent>, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        match callback.event.event_type {
+            EventType::AccountConfirmed | EventType::CallbackTest => Ok((None, None)),
+            EventType::TemplateCreated | EventType::TemplateError => match &callback.template {
+                Some(template) => {
+                    self.invalidate_cache(
+                        CachedEndpoint::Template,
+                        Some(template.template_id.as_str()),
+                    );
+                    let (template, warnings) = self.get_template(&template.template_id).await?;
+                    Ok((Some(HydratedEvent::Template(Box::new(template))), warnings))
+                }
+                None => Ok((None, None)),
+            },
+            _ => match &callback.signature_request {
+                Some(signature_request) => {
+                    let (signature_request, warnings) = self
+                        .get_signature_request(&signature_request.signature_request_id)
+                        .await?;
+                    Ok((
+                        Some(HydratedEvent::SignatureRequest(Box::new(signature_request))),
+                        warnings,
+                    ))
+                }
+                None => Ok((None, None)),
+            },
+        }
+    }
+
+    /// Builds a request for the given API path.
+    ///
+    /// Credentials are attached when the request is executed.
+    fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        self.client
+            .request(method, format!("{}{path}", self.base_url))
+    }
+
+    /// Authenticates and sends a request.
+    ///
+    /// With OAuth credentials, a `401 Unauthorized` response triggers a token
+    /// refresh and a single retry of the request. Requests uploading files stream
+    /// their body and cannot be resent, so they fail with
+    /// [`DropboxSignClientError::UploadNotResent`] after the refresh instead.
+    async fn execute(
+        &self,
+        request: RequestBuilder,
+    ) -> Result<reqwest::Response, DropboxSignClientError> {
+        if let Some(error) = &self.client_error {
+            return Err(DropboxSignClientError::HttpClient(Arc::clone(error)));
+        }
+
+        match &self.auth {
+            Auth::ApiKey(api_key) => {
+                Ok(self.transmit(request.basic_auth(api_key, Some(""))).await?)
+            }
+            Auth::OAuth(refresher) => {
+                let retry = request.try_clone();
+                let access_token = refresher.access_token().await?;
+                let response = self.transmit(request.bearer_auth(&access_token)).await?;
+
+                if response.status() != StatusCode::UNAUTHORIZED {
+                    return Ok(response);
+                }
+
+                let access_token = refresher.refresh(&access_token).await?;
+                match retry {
+                    Some(retry) => Ok(self.transmit(retry.bearer_auth(access_token)).await?),
+                    None => Err(DropboxSignClientError::UploadNotResent),
+                }
+            }
+        }
+    }
+
+    /// Sends an authenticated request.
+    async fn transmit(
+        &self,
+        request: RequestBuilder,
+    ) -> Result<reqwest::Response, DropboxSignClientError> {
+        let mut request = request.build()?;
+        if let Some(timeout) = self.timeout {
+            *request.timeout_mut() = Some(timeout);
+        }
+        telemetry::record_request(&request);
+
+        let response = self.send_with_retries(request).await?;
+        response::record(&response);
+        Ok(response)
+    }
+
+    /// Sends a request, retrying failed attempts according to the retry policy.
+    async fn send_with_retries(
+        &self,
+        mut request: reqwest::Request,
+    ) -> Result<reqwest::Response, DropboxSignClientError> {
+        let endpoint = meter::Endpoint::new(&request);
+        let mut retries = 0;
+        loop {
+            let retry = (self.retry.allows(retries) && self.retry.allows_method(request.method()))
+                .then(|| request.try_clone())
+                .flatten();
+
+            let permit = self
+                .circuit_breaker
+                .as_ref()
+                .map(|circuit_breaker| circuit_breaker.acquire())
+                .transpose()
+                .map_err(DropboxSignClientError::CircuitOpen)?;
+            if let Some(rate_limiter) = &self.rate_limiter {
+                rate_limiter.acquire().await;
+            }
+
+            let started = Instant::now();
+            let result = self.dispatch(request).await;
+            if let (Some(circuit_breaker), Some(permit)) = (&self.circuit_breaker, permit) {
+                circuit_breaker.record(
+                    permit,
+                    result
+                        .as_ref()
+                        .map_or(true, |response| response.status().is_server_error()),
+                );
+            }
+            let delay = match &result {
+                Ok(response) => {
+                    telemetry::record_status(response.status());
+                    endpoint.record_response(response, started.elapsed());
+                    self.retry.retry_response(response, retries)
+                }
+                Err(error) => {
+                    endpoint.record_failure(started.elapsed());
+                    match error {
+                        DropboxSignClientError::Reqwest(error) => {
+                            self.retry.retry_error(error, retries)
+                        }
+                        _ => None,
+                    }
+                }
+            };
+
+            match (retry, delay) {
+                (Some(next), Some(delay)) => {
+                    endpoint.record_retry();
+                    tokio::time::sleep(delay).await;
+                    request = next;
+                }
+                _ => return result,
+            }
+            retries += 1;
+        }
+    }
+
+    /// Sends a single attempt of a request, through the cassette when one is set.
+    async fn dispatch(
+        &self,
+        request: reqwest::Request,
+    ) -> Result<reqwest::Response, DropboxSignClientError> {
+        #[cfg(feature = "vcr")]
+        if let Some(cassette) = &self.cassette {
+            return cassette.execute(&self.client, request).await;
+        }
+
+        Ok(self.client.execute(request).await?)
+    }
+
+    /// Runs an API call within the client's deadline, recording it in telemetry.
+    ///
+    /// The whole call, including reading the response body, stops early if the
+    /// client's calls are cancelled.
+    async fn run<R>(
+        &self,
+        call: impl Future<Output = Result<R, DropboxSignClientError>>,
+        warning_count: impl FnOnce(&R) -> usize,
+    ) -> Result<R, DropboxSignClientError> {
+        let call = async {
+            match self.deadline {
+                Some(deadline) => tokio::time::timeout(deadline, call)
+                    .await
+                    .unwrap_or(Err(DropboxSignClientError::DeadlineExceeded(deadline))),
+                None => call.await,
+            }
+        };
+        let call = async {
+            match &self.cancellation {
+                Some(cancellation) => cancellation
+                    .run_until_cancelled(call)
+                    .await
+                    .unwrap_or(Err(DropboxSignClientError::Cancelled)),
+                None => call.await,
+            }
+        };
+
+        telemetry::traced(call, warning_count).await
+    }
+
+    /// Sends a request and parses the payload stored under `key`.
+    async fn send<T: DeserializeOwned>(
+        &self,
+        request: RequestBuilder,
+        key: &str,
+    ) -> Result<(T, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        let call = async {
+            let response = check_status(self.execute(request).await?).await?;
+            let body = response.text().await?;
+
+            parse_body::<T>(&body, key, self.strict)
+        };
+
+        self.run(call, warning_count).await
+    }
+
+    /// Sends a GET request, reusing a cached response body when one is fresh.
+    ///
+    /// Cache hits run as a call too, so they are traced, counted and report the
+    /// metadata of the response they were cached from.
+    async fn send_cached<T: DeserializeOwned>(
+        &self,
+        request: RequestBuilder,
+        key: &str,
+        endpoint: CachedEndpoint,
+        id: Option<&str>,
+    ) -> Result<(T, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        let Some(cache) = self.cache.as_ref().filter(|cache| cache.caches(endpoint)) else {
+            return self.send(request, key).await;
+        };
+        let cache_key = CacheKey {
+            scope: self.auth.cache_scope(),
+            endpoint,
+            id: id.map(str::to_string),
+        };
+
+        let call = async {
+            if let Some(cached) = cache.get(&cache_key) {
+                if let Some(Ok(request)) = request.try_clone().map(RequestBuilder::build) {
+                    telemetry::record_request(&request);
+                    meter::Endpoint::new(&request).record_cache_hit();
+                }
+                telemetry::record_status(cached.metadata.status());
+                response::record_metadata(cached.metadata);
+
+                return parse_body::<T>(&cached.body, key, self.strict);
+            }
+
+            let response = check_status(self.execute(request).await?).await?;
+            let metadata = ResponseMetadata::from_response(&response);
+            let body = response.text().await?;
+            let parsed = parse_body::<T>(&body, key, self.strict)?;
+            cache.insert(cache_key, CachedResponse { body, metadata });
+
+            Ok(parsed)
+        };
+
+        self.run(call, warning_count).await
+    }
+
+    /// Sends a request whose payload spans several top-level keys of the response.
+    async fn send_flattened<T: DeserializeOwned>(
+        &self,
+        request: RequestBuilder,
+    ) -> Result<(T, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        let call = async {
+            let response = check_status(self.execute(request).await?).await?;
+            let body = response.text().await?;
+            if !self.strict {
+                let parsed: ResponseWithWarnings<T> = serde_json::from_str(&body)?;
+                return Ok((parsed.inner, parsed.warnings));
+            }
 
-        let status = response.status();
+            // Flattening hides unknown fields from strict parsing, so the payload
+            // and the warnings are parsed separately.
+            let mut json: Value = serde_json::from_str(&body)?;
+            let warnings = json
+                .as_object_mut()
+                .and_then(|fields| fields.remove("warnings"))
+                .map(|warnings| strict::from_value(&warnings, "warnings", true))
+                .transpose()?;
 
-        if status != StatusCode::OK {
+            Ok((strict::from_value(&json, "", true)?, warnings))
+        };
+
+        self.run(call, warning_count).await
+    }
+
+    /// Sends a request for a page of items whose type skips fields on purpose.
+    ///
+    /// The whole body is read as text, then the page is parsed straight from it,
+    /// so skipped fields are never turned into values. It is parsed leniently
+    /// even on a strict client.
+    async fn send_page<T: ListItem + DeserializeOwned>(
+        &self,
+        request: RequestBuilder,
+    ) -> Result<(Page<T>, Option<Vec<WarningResponse>>), DropboxSignClientError> {
+        let call = async {
+            let response = check_status(self.execute(request).await?).await?;
             let body = response.text().await?;
-            let parsed: ErrorResponse = serde_json::from_str(&body)?;
-            return Err(DropboxSignClientError::ResponseError(parsed.error));
+            let PageWithWarnings(page, warnings) = serde_json::from_str(&body)?;
+
+            Ok((page, warnings))
+        };
+
+        self.run(call, warning_count).await
+    }
+
+    /// Sends a request whose successful response is a file download.
+    async fn send_bytes(&self, request: RequestBuilder) -> Result<Vec<u8>, DropboxSignClientError> {
+        let call = async {
+            let response = check_status(self.execute(request).await?).await?;
+
+            Ok(response.bytes().await?.to_vec())
+        };
+
+        self.run(call, |_| 0).await
+    }
+
+    /// Sends a request whose successful response carries no payload.
+    async fn send_empty(
+        &self,
+        request: RequestBuilder,
+    ) -> Result<StatusCode, DropboxSignClientError> {
+        let call = async {
+            let response = check_status(self.execute(request).await?).await?;
+
+            Ok(response.status())
+        };
+
+        self.run(call, |_| 0).await
+    }
+}
+
+/// Returns the number of warnings attached to a parsed response.
+fn warning_count<T>((_, warnings): &(T, Option<Vec<WarningResponse>>)) -> usize {
+    warnings.as_ref().map_or(0, Vec::len)
+}
+
+/// Passes successful (2xx) responses through and converts error responses into
+/// `DropboxSignClientError::ResponseError`, or `UnexpectedResponse` when the
+/// body is not a Dropbox Sign error.
+pub(crate) async fn check_status(
+    response: reqwest::Response,
+) -> Result<reqwest::Response, DropboxSignClientError> {
+    if response.status().is_success() {
+        return Ok(response);
+    }
+
+    let status = response.status();
+    let endpoint = response.url().path().to_string();
+    let rate_limit = RateLimitStatus::from_headers(response.headers());
+    let retry_after = response::retry_after(response.headers());
+    let body = response.text().await?;
+    match serde_json::from_str::<ErrorResponse>(&body) {
+        Ok(mut parsed) => {
+            parsed.error.status = status;
+            parsed.error.endpoint = Some(endpoint);
+            parsed.error.rate_limit = rate_limit;
+            parsed.error.retry_after = retry_after;
+            Err(DropboxSignClientError::ResponseError(Box::new(
+                parsed.error,
+            )))
         }
+        Err(_) => Err(DropboxSignClientError::UnexpectedResponse {
+            status,
+            body: snippet(&body),
+        }),
+    }
+}
 
-        Ok(status)
+/// Number of signature request pages searched for an idempotency key.
+const IDEMPOTENCY_SCAN_PAGES: u64 = 5;
+
+/// Page size used when searching for an idempotency key.
+const IDEMPOTENCY_SCAN_PAGE_SIZE: u64 = 100;
+
+/// Longest error body kept in `DropboxSignClientError::UnexpectedResponse`.
+const ERROR_SNIPPET_LEN: usize = 512;
+
+/// Shortens an unparseable error body, e.g. a load balancer's HTML error page.
+fn snippet(body: &str) -> String {
+    let body = body.trim();
+    if body.is_empty() {
+        return "<empty body>".to_string();
+    }
+    match body.char_indices().nth(ERROR_SNIPPET_LEN) {
+        Some((end, _)) => format!("{}...", &body[..end]),
+        None => body.to_string(),
     }
 }
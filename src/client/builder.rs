@@ -0,0 +1,753 @@
+//! Builder for configuring a Dropbox Sign client.
+
+use super::{
+    API_URL, Auth, CacheConfig, CircuitBreaker, CircuitBreakerConfig, DropboxSignClient,
+    RateLimiter, RedactionPolicy, RequestDefaults, ResponseCache, RetryPolicy,
+};
+use crate::id::ClientId;
+use crate::metadata::MetadataValue;
+use crate::oauth::{OAuthClient, OAuthTokenRefresher, TokenStore};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Client, NoProxy, Proxy};
+use std::sync::Arc;
+use std::time::Duration;
+use thiserror::Error;
+
+/// Errors that can occur when building a Dropbox Sign client.
+#[derive(Error, Debug)]
+pub enum DropboxSignClientBuildError {
+    #[error("no API key or OAuth credentials configured")]
+    MissingCredentials,
+
+    #[error("invalid default header: {0}")]
+    InvalidHeader(String),
+
+    #[error("HTTP client error: {0}")]
+    Reqwest(#[from] reqwest::Error),
+}
+
+/// `User-Agent` sent by clients unless it is replaced.
+const DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// Settings used to build the underlying HTTP client.
+#[derive(Clone, Debug)]
+pub(super) struct HttpConfig {
+    /// Timeout for whole requests
+    pub(super) timeout: Option<Duration>,
+    /// Timeout for establishing connections
+    pub(super) connect_timeout: Option<Duration>,
+    /// Timeout for each read from the connection
+    pub(super) read_timeout: Option<Duration>,
+    /// Maximum number of idle connections kept per host
+    pub(super) pool_max_idle_per_host: usize,
+    /// How long idle pooled connections are kept alive
+    pub(super) pool_idle_timeout: Option<Duration>,
+    /// Value of the `User-Agent` header, replacing the crate's default
+    user_agent: Option<String>,
+    /// Products appended to the `User-Agent` header, e.g. `my-app/1.2.0`
+    user_agent_products: Vec<String>,
+    /// URL of the proxy all requests are sent through
+    proxy: Option<String>,
+    /// Username and password for the proxy
+    proxy_auth: Option<(String, String)>,
+    /// Hosts reached directly instead of through the proxy
+    no_proxy: Option<String>,
+    /// Headers sent with every request
+    default_headers: HeaderMap,
+    /// Whether gzip-compressed responses are requested and decompressed
+    gzip: bool,
+    /// Whether brotli-compressed responses are requested and decompressed
+    brotli: bool,
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        Self {
+            timeout: None,
+            connect_timeout: Some(Duration::from_secs(10)),
+            read_timeout: Some(Duration::from_secs(30)),
+            pool_max_idle_per_host: 5,
+            pool_idle_timeout: None,
+            user_agent: None,
+            user_agent_products: Vec::new(),
+            proxy: None,
+            proxy_auth: None,
+            no_proxy: None,
+            default_headers: HeaderMap::new(),
+            gzip: true,
+            brotli: true,
+        }
+    }
+}
+
+impl HttpConfig {
+    /// Builds an HTTP client from the settings.
+    pub(super) fn build(&self) -> Result<Client, DropboxSignClientBuildError> {
+        let mut builder = Client::builder()
+            .pool_max_idle_per_host(self.pool_max_idle_per_host)
+            .default_headers(self.default_headers.clone())
+            .gzip(self.gzip)
+            .brotli(self.brotli);
+
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(connect_timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        if let Some(read_timeout) = self.read_timeout {
+            builder = builder.read_timeout(read_timeout);
+        }
+        if let Some(pool_idle_timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(pool_idle_timeout);
+        }
+        let mut user_agent = self
+            .user_agent
+            .clone()
+            .unwrap_or_else(|| DEFAULT_USER_AGENT.to_string());
+        for product in &self.user_agent_products {
+            user_agent.push(' ');
+            user_agent.push_str(product);
+        }
+        builder = builder.user_agent(user_agent);
+        if let Some(proxy) = &self.proxy {
+            let mut proxy = Proxy::all(proxy)?;
+            if let Some((username, password)) = &self.proxy_auth {
+                proxy = proxy.basic_auth(username, password);
+            }
+            if let Some(no_proxy) = &self.no_proxy {
+                proxy = proxy.no_proxy(NoProxy::from_string(no_proxy));
+            }
+            builder = builder.proxy(proxy);
+        }
+
+        Ok(builder.build()?)
+    }
+}
+
+/// Builder for a [`DropboxSignClient`].
+///
+/// Obtained from [`DropboxSignClient::builder`]. Credentials are required; every
+/// other setting has a default.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::DropboxSignClient;
+/// use std::time::Duration;
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = DropboxSignClient::builder()
+///     .api_key("your-api-key")
+///     .timeout(Duration::from_secs(60))
+///     .connect_timeout(Duration::from_secs(10))
+///     .pool_max_idle_per_host(10)
+///     .user_agent("my-app/1.0")
+///     .rate_limit(100)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct DropboxSignClientBuilder {
+    credentials: Option<Credentials>,
+    base_url: Option<String>,
+    http: HttpConfig,
+    http_client: Option<Client>,
+    invalid_header: Option<String>,
+    requests_per_minute: Option<u32>,
+    redaction: RedactionPolicy,
+    retry: RetryPolicy,
+    deadline: Option<Duration>,
+    defaults: RequestDefaults,
+    cache: Option<CacheConfig>,
+    circuit_breaker: Option<CircuitBreakerConfig>,
+    strict: bool,
+    #[cfg(feature = "vcr")]
+    cassette: Option<super::Cassette>,
+}
+
+/// Credentials configured on the builder.
+///
+/// OAuth refreshers are only created in [`DropboxSignClientBuilder::build`], so
+/// token refreshes go through the same HTTP client as API requests.
+enum Credentials {
+    /// API key sent as the basic auth username
+    ApiKey(String),
+    /// API app credentials and the store holding the user's token
+    OAuth {
+        client_id: String,
+        client_secret: String,
+        store: Box<dyn TokenStore>,
+    },
+}
+
+impl DropboxSignClientBuilder {
+    /// Creates a builder with the default settings and no credentials.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Authenticates requests with an API key.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - Your Dropbox Sign API key
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.credentials = Some(Credentials::ApiKey(api_key.into()));
+        self
+    }
+
+    /// Authenticates requests with an OAuth token refreshed on demand.
+    ///
+    /// See [`DropboxSignClient::new_with_oauth`] for how tokens are refreshed.
+    /// Refreshes are sent through the same HTTP client as API requests, so they
+    /// use its proxy, timeouts and headers.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - Client ID of the API app
+    /// * `client_secret` - Client secret of the API app
+    /// * `store` - Storage holding the user's current OAuth token
+    pub fn oauth(
+        mut self,
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+        store: impl TokenStore + 'static,
+    ) -> Self {
+        self.credentials = Some(Credentials::OAuth {
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+            store: Box::new(store),
+        });
+        self
+    }
+
+    /// Sets the timeout for whole requests.
+    ///
+    /// There is no whole-request timeout by default, so large file downloads and
+    /// uploads are not cut off; stalled connections are caught by the
+    /// [`connect_timeout`](Self::connect_timeout) and
+    /// [`read_timeout`](Self::read_timeout) instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - Time allowed from connecting until the response body is read
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.http.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout for establishing connections (defaults to 10 seconds).
+    ///
+    /// # Arguments
+    ///
+    /// * `connect_timeout` - Time allowed to establish a connection
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.http.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// Sets the timeout for each read from the connection (defaults to 30
+    /// seconds).
+    ///
+    /// The timer restarts whenever data arrives, so long downloads keep going as
+    /// long as the API keeps sending.
+    ///
+    /// # Arguments
+    ///
+    /// * `read_timeout` - Time allowed between two reads
+    pub fn read_timeout(mut self, read_timeout: Duration) -> Self {
+        self.http.read_timeout = Some(read_timeout);
+        self
+    }
+
+    /// Sets the maximum number of idle connections kept per host (defaults to 5).
+    ///
+    /// # Arguments
+    ///
+    /// * `max_idle` - Maximum number of idle connections
+    pub fn pool_max_idle_per_host(mut self, max_idle: usize) -> Self {
+        self.http.pool_max_idle_per_host = max_idle;
+        self
+    }
+
+    /// Sets how long idle pooled connections are kept alive.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool_idle_timeout` - Idle time after which a connection is closed
+    pub fn pool_idle_timeout(mut self, pool_idle_timeout: Duration) -> Self {
+        self.http.pool_idle_timeout = Some(pool_idle_timeout);
+        self
+    }
+
+    /// Replaces the `User-Agent` header sent with every request.
+    ///
+    /// By default the header identifies this crate, e.g. `dropboxsign-client/0.1.0`.
+    /// Prefer [`app_user_agent`](Self::app_user_agent) to identify your application
+    /// while keeping the crate's identifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_agent` - User agent string
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.http.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Appends an application's name and version to the `User-Agent` header.
+    ///
+    /// Can be called several times to append several products.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Application name
+    /// * `version` - Application version
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::DropboxSignClient;
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// // Sends `User-Agent: dropboxsign-client/0.1.0 contracts-service/2.4.1`
+    /// let client = DropboxSignClient::builder()
+    ///     .api_key("your-api-key")
+    ///     .app_user_agent("contracts-service", "2.4.1")
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn app_user_agent(mut self, name: impl AsRef<str>, version: impl AsRef<str>) -> Self {
+        self.http
+            .user_agent_products
+            .push(format!("{}/{}", name.as_ref(), version.as_ref()));
+        self
+    }
+
+    /// Sets whether gzip-compressed responses are accepted.
+    ///
+    /// Enabled by default. Compressed responses are decompressed transparently,
+    /// which considerably shrinks large list pages on the wire.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to request and decompress gzip responses
+    pub fn gzip(mut self, enabled: bool) -> Self {
+        self.http.gzip = enabled;
+        self
+    }
+
+    /// Sets whether brotli-compressed responses are accepted.
+    ///
+    /// Enabled by default. Compressed responses are decompressed transparently.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to request and decompress brotli responses
+    pub fn brotli(mut self, enabled: bool) -> Self {
+        self.http.brotli = enabled;
+        self
+    }
+
+    /// Sends all requests through a proxy.
+    ///
+    /// HTTP, HTTPS and SOCKS5 proxies are supported. Use a `socks5h://` URL to have
+    /// the proxy resolve host names.
+    ///
+    /// # Arguments
+    ///
+    /// * `proxy` - URL of the proxy, e.g. `http://proxy.internal:8080` or
+    ///   `socks5://proxy.internal:1080`
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::DropboxSignClient;
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::builder()
+    ///     .api_key("your-api-key")
+    ///     .proxy("http://proxy.internal:8080")
+    ///     .proxy_auth("svc-dropboxsign", "proxy-password")
+    ///     .no_proxy("localhost,127.0.0.1,.internal")
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn proxy(mut self, proxy: impl Into<String>) -> Self {
+        self.http.proxy = Some(proxy.into());
+        self
+    }
+
+    /// Authenticates with the proxy using basic auth.
+    ///
+    /// Only applies when a proxy is set with [`proxy`](Self::proxy).
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - Proxy username
+    /// * `password` - Proxy password
+    pub fn proxy_auth(mut self, username: impl Into<String>, password: impl Into<String>) -> Self {
+        self.http.proxy_auth = Some((username.into(), password.into()));
+        self
+    }
+
+    /// Sets hosts that are reached directly instead of through the proxy.
+    ///
+    /// Only applies when a proxy is set with [`proxy`](Self::proxy).
+    ///
+    /// # Arguments
+    ///
+    /// * `no_proxy` - Comma-separated hosts, domains (`.example.com`) or IP ranges
+    ///   (`10.0.0.0/8`), in the same format as the `NO_PROXY` environment variable
+    pub fn no_proxy(mut self, no_proxy: impl Into<String>) -> Self {
+        self.http.no_proxy = Some(no_proxy.into());
+        self
+    }
+
+    /// Sets the base URL of the API (defaults to `https://api.hellosign.com/v3`).
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - Base URL that endpoint paths are appended to
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into().trim_end_matches('/').to_string());
+        self
+    }
+
+    /// Adds a header sent with every request.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Header name
+    /// * `value` - Header value
+    pub fn default_header(mut self, name: &str, value: &str) -> Self {
+        match (
+            HeaderName::from_bytes(name.as_bytes()),
+            HeaderValue::from_str(value),
+        ) {
+            (Ok(name), Ok(value)) => {
+                self.http.default_headers.insert(name, value);
+            }
+            _ => self.invalid_header = Some(name.to_string()),
+        }
+        self
+    }
+
+    /// Adds headers sent with every request.
+    ///
+    /// Headers with the same name as an earlier default header replace it.
+    ///
+    /// # Arguments
+    ///
+    /// * `headers` - Headers to send with every request
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::DropboxSignClient;
+    /// use reqwest::header::{HeaderMap, HeaderValue};
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut headers = HeaderMap::new();
+    /// headers.insert("x-egress-team", HeaderValue::from_static("contracts"));
+    /// headers.insert("x-egress-cost-center", HeaderValue::from_static("cc-1042"));
+    ///
+    /// let client = DropboxSignClient::builder()
+    ///     .api_key("your-api-key")
+    ///     .default_headers(headers)
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn default_headers(mut self, headers: HeaderMap) -> Self {
+        for name in headers.keys() {
+            self.http.default_headers.remove(name);
+            for value in headers.get_all(name) {
+                self.http.default_headers.append(name, value.clone());
+            }
+        }
+        self
+    }
+
+    /// Limits how many requests the client sends per minute.
+    ///
+    /// Requests over the limit wait until the limit allows them instead of failing
+    /// with `429 Too Many Requests`. Clones of the client share the same limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `requests_per_minute` - Maximum number of requests in any 60 seconds
+    pub fn rate_limit(mut self, requests_per_minute: u32) -> Self {
+        self.requests_per_minute = Some(requests_per_minute);
+        self
+    }
+
+    /// Sets which personal data is redacted from logged payloads.
+    ///
+    /// Payloads are only logged at debug level with the `tracing` feature enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `redaction` - Redaction policy for logged payloads
+    pub fn redaction(mut self, redaction: RedactionPolicy) -> Self {
+        self.redaction = redaction;
+        self
+    }
+
+    /// Sets how failed requests are retried.
+    ///
+    /// Requests are not retried unless a policy is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `retry` - Retry policy for all calls made by the client
+    pub fn retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Sets the total time allowed for each call.
+    ///
+    /// Unlike [`timeout`](Self::timeout), which bounds a single attempt, the
+    /// deadline covers every attempt, the backoff between retries and reading the
+    /// response. Calls that run out of time fail with
+    /// [`DropboxSignClientError::DeadlineExceeded`](super::DropboxSignClientError::DeadlineExceeded).
+    ///
+    /// # Arguments
+    ///
+    /// * `deadline` - Total time allowed for each call
+    pub fn deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Forces every signature request and unclaimed draft into test mode.
+    ///
+    /// Requests that do not set `test_mode` are sent with `test_mode` enabled, and
+    /// requests that explicitly disable it fail with
+    /// [`DropboxSignClientError::TestModeRequired`](super::DropboxSignClientError::TestModeRequired)
+    /// before anything is sent. Use this in staging so real, billable signature
+    /// requests can never go out.
+    ///
+    /// # Arguments
+    ///
+    /// * `enforce` - Whether to enforce test mode
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::DropboxSignClient;
+    ///
+    /// # fn example(is_production: bool) -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::builder()
+    ///     .api_key("your-api-key")
+    ///     .enforce_test_mode(!is_production)
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn enforce_test_mode(mut self, enforce: bool) -> Self {
+        self.defaults.enforce_test_mode = enforce;
+        self
+    }
+
+    /// Sets the API app client ID used by signature requests and unclaimed drafts
+    /// that do not set one.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - Client ID of the API app
+    pub fn default_client_id(mut self, client_id: impl Into<ClientId>) -> Self {
+        self.defaults.client_id = Some(client_id.into());
+        self
+    }
+
+    /// Sets the test mode used by signature requests and unclaimed drafts that do
+    /// not set it.
+    ///
+    /// # Arguments
+    ///
+    /// * `test_mode` - Whether requests are sent in test mode by default
+    pub fn default_test_mode(mut self, test_mode: bool) -> Self {
+        self.defaults.test_mode = Some(test_mode);
+        self
+    }
+
+    /// Adds a metadata entry to every signature request and unclaimed draft.
+    ///
+    /// Entries set on a request take precedence over defaults with the same key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Metadata key
+    /// * `value` - Metadata value, a string, integer or boolean
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::DropboxSignClient;
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::builder()
+    ///     .api_key("your-api-key")
+    ///     .default_client_id("your-client-id")
+    ///     .default_metadata("environment", "staging")
+    ///     .default_metadata("app_version", env!("CARGO_PKG_VERSION"))
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn default_metadata(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<MetadataValue>,
+    ) -> Self {
+        self.defaults.metadata.insert(key.into(), value.into());
+        self
+    }
+
+    /// Caches the responses of frequently read GET endpoints.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache` - Which endpoints are cached and for how long
+    pub fn cache(mut self, cache: CacheConfig) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Fails calls fast while the API keeps failing.
+    ///
+    /// See [`CircuitBreakerConfig`] for when the circuit opens and closes.
+    ///
+    /// # Arguments
+    ///
+    /// * `circuit_breaker` - Failure rate, window and cool-down of the breaker
+    pub fn circuit_breaker(mut self, circuit_breaker: CircuitBreakerConfig) -> Self {
+        self.circuit_breaker = Some(circuit_breaker);
+        self
+    }
+
+    /// Rejects responses with fields the models of this crate do not know about.
+    ///
+    /// Responses are parsed leniently by default, so new API fields never break a
+    /// call. With strict parsing, such responses fail with
+    /// [`DropboxSignClientError::UnknownFields`](super::DropboxSignClientError::UnknownFields),
+    /// and responses that do not match the models fail with
+    /// [`DropboxSignClientError::ModelMismatch`](super::DropboxSignClientError::ModelMismatch)
+    /// naming the offending field. Enable it in staging or tests to catch drift
+    /// between the API and this crate early.
+    ///
+    /// # Arguments
+    ///
+    /// * `strict` - Whether unknown response fields are rejected
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::DropboxSignClient;
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::builder()
+    ///     .api_key("your-api-key")
+    ///     .strict_parsing(cfg!(debug_assertions))
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn strict_parsing(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Records requests to, or replays them from, a cassette.
+    ///
+    /// The cassette takes the place of the network, so requests still go through
+    /// the client's retries, rate limit, circuit breaker and metrics.
+    ///
+    /// # Arguments
+    ///
+    /// * `cassette` - Cassette to record to or replay from
+    #[cfg(feature = "vcr")]
+    pub fn cassette(mut self, cassette: super::Cassette) -> Self {
+        self.cassette = Some(cassette);
+        self
+    }
+
+    /// Uses a pre-built HTTP client.
+    ///
+    /// The timeout, pool, user agent, proxy and default header settings only apply
+    /// to clients built by this builder and are ignored when a client is supplied.
+    ///
+    /// # Arguments
+    ///
+    /// * `http_client` - HTTP client used to send requests
+    pub fn http_client(mut self, http_client: Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
+    /// Builds the client.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientBuildError` if:
+    /// - No credentials were configured
+    /// - A default header has an invalid name or value
+    /// - The proxy URL is invalid or the HTTP client cannot be built
+    pub fn build(self) -> Result<DropboxSignClient, DropboxSignClientBuildError> {
+        let credentials = self
+            .credentials
+            .ok_or(DropboxSignClientBuildError::MissingCredentials)?;
+        if let Some(name) = self.invalid_header {
+            return Err(DropboxSignClientBuildError::InvalidHeader(name));
+        }
+
+        let (client, http) = match self.http_client {
+            Some(client) => (client, None),
+            None => (self.http.build()?, Some(self.http)),
+        };
+        let auth = match credentials {
+            Credentials::ApiKey(api_key) => Auth::ApiKey(api_key),
+            Credentials::OAuth {
+                client_id,
+                client_secret,
+                store,
+            } => Auth::OAuth(Arc::new(OAuthTokenRefresher::new(
+                client_id,
+                client_secret,
+                store,
+                OAuthClient::from_client(client.clone()),
+            ))),
+        };
+
+        Ok(DropboxSignClient {
+            auth,
+            client,
+            client_error: None,
+            base_url: self.base_url.unwrap_or_else(|| API_URL.to_string()),
+            http,
+            rate_limiter: self
+                .requests_per_minute
+                .map(|requests_per_minute| Arc::new(RateLimiter::new(requests_per_minute))),
+            redaction: self.redaction,
+            retry: self.retry,
+            timeout: None,
+            cancellation: None,
+            deadline: self.deadline,
+            defaults: self.defaults,
+            cache: self
+                .cache
+                .map(|config| Arc::new(ResponseCache::new(config))),
+            circuit_breaker: self
+                .circuit_breaker
+                .map(|config| Arc::new(CircuitBreaker::new(config))),
+            strict: self.strict,
+            #[cfg(feature = "vcr")]
+            cassette: self.cassette.map(Arc::new),
+        })
+    }
+}
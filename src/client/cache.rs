@@ -0,0 +1,148 @@
+//! Caching of GET responses for frequently read resources.
+
+use super::ResponseMetadata;
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+/// Endpoints whose responses can be cached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CachedEndpoint {
+    /// [`get_account`](super::DropboxSignClient::get_account)
+    Account,
+    /// [`get_api_app`](super::DropboxSignClient::get_api_app)
+    ApiApp,
+    /// [`get_template`](super::DropboxSignClient::get_template)
+    Template,
+}
+
+/// Which responses are cached and for how long.
+///
+/// Only endpoints with a TTL are cached. Entries are kept per credential, so
+/// clients created with
+/// [`with_api_key`](super::DropboxSignClient::with_api_key) never see each
+/// other's responses. Updates and deletes made through the client invalidate the
+/// affected entries.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::DropboxSignClient;
+/// use dropboxsign_client::client::{CacheConfig, CachedEndpoint};
+/// use std::time::Duration;
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = DropboxSignClient::builder()
+///     .api_key("your-api-key")
+///     .cache(
+///         CacheConfig::new()
+///             .ttl(CachedEndpoint::Template, Duration::from_secs(600))
+///             .ttl(CachedEndpoint::Account, Duration::from_secs(60)),
+///     )
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CacheConfig {
+    ttls: HashMap<CachedEndpoint, Duration>,
+}
+
+impl CacheConfig {
+    /// Creates a configuration that caches nothing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Caches responses of `endpoint` for `ttl`.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - Endpoint to cache
+    /// * `ttl` - How long a cached response is reused
+    pub fn ttl(mut self, endpoint: CachedEndpoint, ttl: Duration) -> Self {
+        self.ttls.insert(endpoint, ttl);
+        self
+    }
+}
+
+/// Identifies a cached response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(super) struct CacheKey {
+    /// Identifies the credentials the response was fetched with
+    pub(super) scope: String,
+    /// Endpoint the response came from
+    pub(super) endpoint: CachedEndpoint,
+    /// Identifier of the resource, if the endpoint takes one
+    pub(super) id: Option<String>,
+}
+
+/// A cached response body and the status and headers it was received with.
+#[derive(Debug, Clone)]
+pub(super) struct CachedResponse {
+    pub(super) body: String,
+    pub(super) metadata: ResponseMetadata,
+}
+
+/// Cached responses, shared by all clones of a client.
+#[derive(Debug)]
+pub(super) struct ResponseCache {
+    config: CacheConfig,
+    entries: Mutex<HashMap<CacheKey, (Instant, CachedResponse)>>,
+}
+
+impl ResponseCache {
+    /// Creates an empty cache.
+    pub(super) fn new(config: CacheConfig) -> Self {
+        Self {
+            config,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns whether responses of `endpoint` are cached.
+    pub(super) fn caches(&self, endpoint: CachedEndpoint) -> bool {
+        self.config.ttls.contains_key(&endpoint)
+    }
+
+    /// Returns the cached response for `key` if it has not expired.
+    pub(super) fn get(&self, key: &CacheKey) -> Option<CachedResponse> {
+        let ttl = self.config.ttls.get(&key.endpoint)?;
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+
+        match entries.get(key) {
+            Some((stored, response)) if stored.elapsed() < *ttl => Some(response.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Stores a response.
+    pub(super) fn insert(&self, key: CacheKey, response: CachedResponse) {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key, (Instant::now(), response));
+    }
+
+    /// Removes the entries of `endpoint`, limited to resource `id` if given.
+    pub(super) fn invalidate(&self, endpoint: CachedEndpoint, id: Option<&str>) {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .retain(|key, _| {
+                key.endpoint != endpoint || id.is_some_and(|id| key.id.as_deref() != Some(id))
+            });
+    }
+
+    /// Removes every entry.
+    pub(super) fn clear(&self) {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+}
@@ -0,0 +1,229 @@
+//! Circuit breaking that fails fast while the API is unavailable.
+
+use std::collections::VecDeque;
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+/// When the circuit breaker opens and how long it stays open.
+///
+/// The outcomes of the most recent requests are tracked in a sliding window. A
+/// request fails when the connection fails or times out, or when the API
+/// responds with a `5xx` status. Once the window holds at least the minimum
+/// number of requests and the share of failures reaches the failure rate, the
+/// circuit opens: calls fail immediately with
+/// [`DropboxSignClientError::CircuitOpen`](super::DropboxSignClientError::CircuitOpen)
+/// without contacting the API.
+///
+/// After the cool-down a single trial request is let through. The circuit closes
+/// again if it succeeds and stays open for another cool-down if it fails. Only
+/// the trial decides: requests that were still in flight when the circuit
+/// opened are not counted once they complete.
+///
+/// The breaker is shared by all clones of a client.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::DropboxSignClient;
+/// use dropboxsign_client::client::CircuitBreakerConfig;
+/// use std::time::Duration;
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = DropboxSignClient::builder()
+///     .api_key("your-api-key")
+///     .circuit_breaker(
+///         CircuitBreakerConfig::new()
+///             .failure_rate(0.5)
+///             .cool_down(Duration::from_secs(60)),
+///     )
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct CircuitBreakerConfig {
+    failure_rate: f64,
+    window: usize,
+    min_requests: usize,
+    cool_down: Duration,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CircuitBreakerConfig {
+    /// Creates a configuration that opens when half of the last 20 requests failed,
+    /// once at least 10 were sent, and stays open for 30 seconds.
+    pub fn new() -> Self {
+        Self {
+            failure_rate: 0.5,
+            window: 20,
+            min_requests: 10,
+            cool_down: Duration::from_secs(30),
+        }
+    }
+
+    /// Sets the share of failed requests that opens the circuit.
+    ///
+    /// # Arguments
+    ///
+    /// The circuit never opens without at least one failure, so `0.0` opens it on
+    /// the first failure once enough requests were tracked. A NaN rate is ignored
+    /// and keeps the current setting.
+    ///
+    /// # Arguments
+    ///
+    /// * `failure_rate` - Share of failures between `0.0` and `1.0`
+    pub fn failure_rate(mut self, failure_rate: f64) -> Self {
+        if !failure_rate.is_nan() {
+            self.failure_rate = failure_rate.clamp(0.0, 1.0);
+        }
+        self
+    }
+
+    /// Sets how many of the most recent requests the failure rate is computed over.
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - Number of requests tracked
+    pub fn window(mut self, window: usize) -> Self {
+        self.window = window.max(1);
+        self
+    }
+
+    /// Sets how many requests must be tracked before the circuit can open.
+    ///
+    /// Values larger than the [`window`](Self::window) are capped at the window
+    /// size, since no more requests than that are ever tracked.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_requests` - Minimum number of tracked requests
+    pub fn min_requests(mut self, min_requests: usize) -> Self {
+        self.min_requests = min_requests.max(1);
+        self
+    }
+
+    /// Sets how long the circuit stays open before a trial request is let through.
+    ///
+    /// # Arguments
+    ///
+    /// * `cool_down` - Time calls fail fast for
+    pub fn cool_down(mut self, cool_down: Duration) -> Self {
+        self.cool_down = cool_down;
+        self
+    }
+}
+
+/// Whether requests are let through.
+#[derive(Debug)]
+enum State {
+    /// Requests are sent; outcomes of the most recent ones, `true` for failures
+    Closed(VecDeque<bool>),
+    /// Requests fail fast until the given time
+    Open(Instant),
+    /// A trial request started at the given time is in flight
+    HalfOpen(Instant),
+}
+
+/// State of the circuit breaker and the number of transitions so far.
+#[derive(Debug)]
+struct Inner {
+    state: State,
+    /// Incremented on every transition, so outcomes of requests admitted in an
+    /// earlier state are ignored
+    epoch: u64,
+}
+
+impl Inner {
+    /// Moves to `state`, invalidating every outstanding permit.
+    fn transition(&mut self, state: State) {
+        self.state = state;
+        self.epoch += 1;
+    }
+}
+
+/// Admission of a single request, passed back when its outcome is recorded.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Permit {
+    epoch: u64,
+}
+
+/// Circuit breaker shared by all clones of a client.
+#[derive(Debug)]
+pub(super) struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    inner: Mutex<Inner>,
+}
+
+impl CircuitBreaker {
+    /// Creates a closed circuit breaker.
+    pub(super) fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            inner: Mutex::new(Inner {
+                state: State::Closed(VecDeque::new()),
+                epoch: 0,
+            }),
+        }
+    }
+
+    /// Checks whether a request may be sent.
+    ///
+    /// Returns the time until the next trial request while the circuit is open.
+    pub(super) fn acquire(&self) -> Result<Permit, Duration> {
+        let mut inner = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+        let now = Instant::now();
+
+        match inner.state {
+            State::Closed(_) => {}
+            State::Open(until) if now < until => return Err(until - now),
+            // A trial whose outcome was never recorded (e.g. a cancelled call) is
+            // replaced after a cool-down.
+            State::HalfOpen(started) if now < started + self.config.cool_down => {
+                return Err(started + self.config.cool_down - now);
+            }
+            State::Open(_) | State::HalfOpen(_) => inner.transition(State::HalfOpen(now)),
+        }
+
+        Ok(Permit { epoch: inner.epoch })
+    }
+
+    /// Records the outcome of a request admitted by [`acquire`](Self::acquire).
+    ///
+    /// Only requests admitted in the current state count: while half-open that is
+    /// the trial request alone, so requests that were already in flight when the
+    /// circuit opened cannot close or reopen it.
+    pub(super) fn record(&self, permit: Permit, failed: bool) {
+        let mut inner = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+        if permit.epoch != inner.epoch {
+            return;
+        }
+
+        match &mut inner.state {
+            State::Closed(outcomes) => {
+                outcomes.push_back(failed);
+                if outcomes.len() > self.config.window {
+                    outcomes.pop_front();
+                }
+
+                let failures = outcomes.iter().filter(|failed| **failed).count();
+                if outcomes.len() >= self.config.min_requests.min(self.config.window)
+                    && failures > 0
+                    && failures as f64 >= self.config.failure_rate * outcomes.len() as f64
+                {
+                    inner.transition(State::Open(Instant::now() + self.config.cool_down));
+                }
+            }
+            State::HalfOpen(_) if failed => {
+                inner.transition(State::Open(Instant::now() + self.config.cool_down));
+            }
+            State::HalfOpen(_) => inner.transition(State::Closed(VecDeque::new())),
+            // No permits are issued while the circuit is open.
+            State::Open(_) => {}
+        }
+    }
+}
@@ -0,0 +1,56 @@
+//! Defaults merged into outgoing signature requests and drafts.
+
+use super::DropboxSignClientError;
+use crate::id::ClientId;
+use crate::metadata::Metadata;
+
+/// Values filled into send and create requests that leave them unset.
+#[derive(Clone, Debug, Default)]
+pub(super) struct RequestDefaults {
+    /// API app client ID used when a request has none
+    pub(super) client_id: Option<ClientId>,
+    /// Test mode used when a request does not set it
+    pub(super) test_mode: Option<bool>,
+    /// Metadata entries added unless the request sets the same key
+    pub(super) metadata: Metadata,
+    /// Whether every request is forced into test mode
+    pub(super) enforce_test_mode: bool,
+}
+
+impl RequestDefaults {
+    /// Merges the defaults into the fields of an outgoing request.
+    ///
+    /// Values set on the request take precedence. Fails if test mode is enforced
+    /// and the request explicitly disables it.
+    pub(super) fn apply(
+        &self,
+        client_id: Option<&mut Option<ClientId>>,
+        test_mode: &mut Option<bool>,
+        metadata: &mut Option<Metadata>,
+    ) -> Result<(), DropboxSignClientError> {
+        if let Some(client_id) = client_id
+            && client_id.is_none()
+        {
+            client_id.clone_from(&self.client_id);
+        }
+
+        if test_mode.is_none() {
+            *test_mode = self.test_mode;
+        }
+        if self.enforce_test_mode {
+            if *test_mode == Some(false) {
+                return Err(DropboxSignClientError::TestModeRequired);
+            }
+            *test_mode = Some(true);
+        }
+
+        if !self.metadata.is_empty() {
+            let metadata = metadata.get_or_insert_with(Metadata::new);
+            for (key, value) in &self.metadata {
+                metadata.entry(key.clone()).or_insert_with(|| value.clone());
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,119 @@
+//! Metrics for API requests.
+//!
+//! With the `metrics` feature every HTTP attempt is reported through the
+//! [`metrics`](https://docs.rs/metrics) facade, so any installed recorder (e.g.
+//! a Prometheus exporter) receives:
+//!
+//! - `dropboxsign_requests_total` (counter): attempts by `method`, `endpoint`
+//!   and `status` (`error` when no response was received)
+//! - `dropboxsign_request_duration_seconds` (histogram): attempt latency by
+//!   `method` and `endpoint`
+//! - `dropboxsign_retries_total` (counter): retries by `method` and `endpoint`
+//! - `dropboxsign_cache_hits_total` (counter): calls answered from the response
+//!   cache by `method` and `endpoint`
+//! - `dropboxsign_rate_limit_remaining` (gauge): requests left in the current
+//!   rate limit window
+//!
+//! Identifiers in paths are replaced with `{id}` so the `endpoint` label stays
+//! bounded. Without the feature these helpers compile to nothing.
+
+use reqwest::{Request, Response};
+use std::time::Duration;
+
+/// Labels identifying the endpoint a request is sent to.
+pub(super) struct Endpoint {
+    #[cfg(feature = "metrics")]
+    method: String,
+    #[cfg(feature = "metrics")]
+    path: String,
+}
+
+impl Endpoint {
+    /// Derives the labels of a request.
+    pub(super) fn new(_request: &Request) -> Self {
+        Self {
+            #[cfg(feature = "metrics")]
+            method: _request.method().to_string(),
+            #[cfg(feature = "metrics")]
+            path: normalize_path(_request.url().path()),
+        }
+    }
+
+    /// Records an attempt that received a response.
+    pub(super) fn record_response(&self, _response: &Response, _elapsed: Duration) {
+        #[cfg(feature = "metrics")]
+        {
+            self.record_attempt(_response.status().as_str().to_string(), _elapsed);
+            if let Some(remaining) = super::RateLimitStatus::from_headers(_response.headers())
+                .and_then(|rate_limit| rate_limit.remaining)
+            {
+                metrics::gauge!("dropboxsign_rate_limit_remaining").set(remaining as f64);
+            }
+        }
+    }
+
+    /// Records an attempt that failed without a response.
+    pub(super) fn record_failure(&self, _elapsed: Duration) {
+        #[cfg(feature = "metrics")]
+        self.record_attempt("error".to_string(), _elapsed);
+    }
+
+    /// Records that a request is being retried.
+    pub(super) fn record_retry(&self) {
+        #[cfg(feature = "metrics")]
+        metrics::counter!(
+            "dropboxsign_retries_total",
+            "method" => self.method.clone(),
+            "endpoint" => self.path.clone(),
+        )
+        .increment(1);
+    }
+
+    /// Records a call answered from the response cache.
+    pub(super) fn record_cache_hit(&self) {
+        #[cfg(feature = "metrics")]
+        metrics::counter!(
+            "dropboxsign_cache_hits_total",
+            "method" => self.method.clone(),
+            "endpoint" => self.path.clone(),
+        )
+        .increment(1);
+    }
+
+    /// Records the outcome and latency of an attempt.
+    #[cfg(feature = "metrics")]
+    fn record_attempt(&self, status: String, elapsed: Duration) {
+        metrics::counter!(
+            "dropboxsign_requests_total",
+            "method" => self.method.clone(),
+            "endpoint" => self.path.clone(),
+            "status" => status,
+        )
+        .increment(1);
+        metrics::histogram!(
+            "dropboxsign_request_duration_seconds",
+            "method" => self.method.clone(),
+            "endpoint" => self.path.clone(),
+        )
+        .record(elapsed.as_secs_f64());
+    }
+}
+
+/// Replaces identifiers in a path with `{id}`.
+///
+/// Segments containing a digit are treated as identifiers, except for the API
+/// version prefix (`v3`).
+#[cfg(feature = "metrics")]
+fn normalize_path(path: &str) -> String {
+    path.split('/')
+        .map(|segment| {
+            let is_version = segment.len() <= 3 && segment.starts_with('v');
+            if !is_version && segment.chars().any(|c| c.is_ascii_digit()) {
+                "{id}"
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
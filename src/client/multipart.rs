@@ -0,0 +1,236 @@
+//! Encoding of request bodies that upload files.
+//!
+//! The API only accepts file uploads as `multipart/form-data`. Nested request
+//! fields are flattened into bracketed keys such as `signers[0][name]`, and files
+//! are attached as `files[0]`, `files[1]`, and so on.
+
+use super::DropboxSignClientError;
+use crate::file::{FileSource, Progress, ProgressCallback, UploadFile};
+use bytes::Bytes;
+use futures_util::stream::{self, BoxStream, StreamExt};
+use reqwest::multipart::{Form, Part};
+use reqwest::{Body, RequestBuilder};
+use serde::Serialize;
+use serde_json::Value;
+use std::io;
+use std::path::Path;
+use tokio_util::io::ReaderStream;
+
+/// A file attached to a multipart request.
+pub(super) struct FilePart {
+    /// Form field the file is sent under
+    field: String,
+    /// File to upload
+    file: UploadFile,
+}
+
+impl FilePart {
+    /// Creates the parts for a list of files sent as `name[0]`, `name[1]`, ...
+    pub(super) fn indexed(name: &str, files: Option<Vec<UploadFile>>) -> Vec<Self> {
+        files
+            .unwrap_or_default()
+            .into_iter()
+            .enumerate()
+            .map(|(index, file)| Self {
+                field: format!("{name}[{index}]"),
+                file,
+            })
+            .collect()
+    }
+
+    /// Creates the part for a single file sent as `name`.
+    pub(super) fn single(name: &str, file: Option<UploadFile>) -> Vec<Self> {
+        file.map(|file| Self {
+            field: name.to_string(),
+            file,
+        })
+        .into_iter()
+        .collect()
+    }
+
+    /// Converts the file into a form part, opening files on disk for streaming.
+    async fn into_part(self, index: usize) -> Result<Part, DropboxSignClientError> {
+        let UploadFile {
+            file_name,
+            mime_type,
+            source,
+            progress,
+        } = self.file;
+
+        let (chunks, length, file_name, sniffed): (Chunks, _, _, _) = match source {
+            FileSource::Memory(data) => {
+                let (extension, mime) = sniff(&data);
+                let file_name = file_name.unwrap_or_else(|| format!("file{index}{extension}"));
+                let length = data.len() as u64;
+                (memory_chunks(data), Some(length), file_name, mime)
+            }
+            FileSource::Path(path) => {
+                let file = tokio::fs::File::open(&path).await?;
+                let length = file.metadata().await?.len();
+                let file_name = file_name
+                    .or_else(|| {
+                        path.file_name()
+                            .map(|name| name.to_string_lossy().into_owned())
+                    })
+                    .unwrap_or_else(|| format!("file{index}"));
+                (
+                    ReaderStream::new(file).boxed(),
+                    Some(length),
+                    file_name,
+                    None,
+                )
+            }
+            FileSource::Reader { reader, length } => (
+                ReaderStream::new(reader.take().ok_or_else(|| {
+                    io::Error::other("the reader was already read by a clone of this upload")
+                })?)
+                .boxed(),
+                length,
+                file_name.unwrap_or_else(|| format!("file{index}")),
+                None,
+            ),
+        };
+
+        let body = Body::wrap_stream(track(chunks, length, progress));
+        let part = match length {
+            Some(length) => Part::stream_with_length(body, length),
+            None => Part::stream(body),
+        };
+
+        let mime = mime_type
+            .or_else(|| mime_from_extension(&file_name).map(str::to_string))
+            .or_else(|| sniffed.map(str::to_string))
+            .unwrap_or_else(|| "application/octet-stream".to_string());
+
+        Ok(part.file_name(file_name).mime_str(&mime)?)
+    }
+}
+
+/// Stream of file contents sent as a part body.
+type Chunks = BoxStream<'static, io::Result<Bytes>>;
+
+/// Size of the chunks in-memory files are sent in, so progress is reported
+/// while they upload.
+const MEMORY_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Splits in-memory file contents into chunks that share the original buffer.
+fn memory_chunks(data: Bytes) -> Chunks {
+    let chunks: Vec<_> = (0..data.len())
+        .step_by(MEMORY_CHUNK_SIZE)
+        .map(|start| Ok(data.slice(start..data.len().min(start + MEMORY_CHUNK_SIZE))))
+        .collect();
+
+    stream::iter(chunks).boxed()
+}
+
+/// Reports upload progress to `callback` as chunks are sent.
+fn track(chunks: Chunks, total: Option<u64>, callback: Option<ProgressCallback>) -> Chunks {
+    let Some(callback) = callback else {
+        return chunks;
+    };
+
+    let mut transferred = 0;
+    chunks
+        .inspect(move |chunk| {
+            if let Ok(chunk) = chunk {
+                transferred += chunk.len() as u64;
+                callback(Progress { transferred, total });
+            }
+        })
+        .boxed()
+}
+
+/// Attaches `fields` as a JSON body, or as multipart form data when files are
+/// uploaded.
+pub(super) async fn with_body<T: Serialize>(
+    request: RequestBuilder,
+    fields: &T,
+    files: Vec<FilePart>,
+) -> Result<RequestBuilder, DropboxSignClientError> {
+    if files.is_empty() {
+        return Ok(request.json(fields));
+    }
+
+    let mut flattened = Vec::new();
+    flatten(String::new(), serde_json::to_value(fields)?, &mut flattened);
+
+    let mut form = Form::new();
+    for (name, value) in flattened {
+        form = form.text(name, value);
+    }
+    for (index, file) in files.into_iter().enumerate() {
+        let field = file.field.clone();
+        form = form.part(field, file.into_part(index).await?);
+    }
+
+    Ok(request.multipart(form))
+}
+
+/// Flattens a JSON value into form fields with bracketed keys.
+///
+/// Booleans are sent as `1` and `0`, and null values are left out.
+fn flatten(key: String, value: Value, fields: &mut Vec<(String, String)>) {
+    match value {
+        Value::Null => {}
+        Value::Bool(value) => fields.push((key, if value { "1" } else { "0" }.to_string())),
+        Value::Number(value) => fields.push((key, value.to_string())),
+        Value::String(value) => fields.push((key, value)),
+        Value::Array(values) => {
+            for (index, value) in values.into_iter().enumerate() {
+                flatten(format!("{key}[{index}]"), value, fields);
+            }
+        }
+        Value::Object(map) => {
+            for (name, value) in map {
+                let name = if key.is_empty() {
+                    name
+                } else {
+                    format!("{key}[{name}]")
+                };
+                flatten(name, value, fields);
+            }
+        }
+    }
+}
+
+/// Known document and image types as (extension, MIME type, leading bytes).
+const FILE_TYPES: [(&str, &str, &[u8]); 6] = [
+    ("pdf", "application/pdf", b"%PDF"),
+    ("png", "image/png", b"\x89PNG"),
+    ("jpg", "image/jpeg", b"\xFF\xD8\xFF"),
+    ("gif", "image/gif", b"GIF8"),
+    (
+        "docx",
+        "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        b"PK\x03\x04",
+    ),
+    ("doc", "application/msword", b"\xD0\xCF\x11\xE0"),
+];
+
+/// Guesses the file extension and MIME type from the file's leading bytes.
+fn sniff(data: &[u8]) -> (String, Option<&'static str>) {
+    FILE_TYPES
+        .iter()
+        .find(|(_, _, magic)| data.starts_with(magic))
+        .map_or((String::new(), None), |(extension, mime, _)| {
+            (format!(".{extension}"), Some(*mime))
+        })
+}
+
+/// Returns the MIME type matching the extension of `file_name`.
+fn mime_from_extension(file_name: &str) -> Option<&'static str> {
+    let extension = Path::new(file_name)
+        .extension()?
+        .to_str()?
+        .to_ascii_lowercase();
+    let extension = if extension == "jpeg" {
+        "jpg"
+    } else {
+        extension.as_str()
+    };
+
+    FILE_TYPES
+        .iter()
+        .find(|(known, _, _)| *known == extension)
+        .map(|(_, mime, _)| *mime)
+}
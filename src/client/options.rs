@@ -0,0 +1,120 @@
+//! Overrides for the settings of individual calls.
+
+use super::RetryPolicy;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+
+/// Settings that override the client's configuration for some calls.
+///
+/// Unset options fall back to the client's configuration. Apply them with
+/// [`DropboxSignClient::with_options`](super::DropboxSignClient::with_options).
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::DropboxSignClient;
+/// use dropboxsign_client::client::{RequestOptions, RetryPolicy};
+/// use std::time::Duration;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = DropboxSignClient::new("your-api-key");
+///
+/// let (status, _) = client
+///     .with_options(
+///         RequestOptions::new()
+///             .timeout(Duration::from_secs(5))
+///             .retry(RetryPolicy::none()),
+///     )
+///     .get_signature_request(&"signature-request-id".into())
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RequestOptions {
+    pub(super) timeout: Option<Duration>,
+    pub(super) retry: Option<RetryPolicy>,
+    pub(super) cancellation: Option<CancellationToken>,
+    pub(super) deadline: Option<Duration>,
+}
+
+impl RequestOptions {
+    /// Creates options that keep the client's configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the total time allowed for each attempt, from connecting until the
+    /// response body has been read.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - Time allowed for each attempt
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the total time allowed for each call, covering every attempt, the
+    /// backoff between retries and reading the response.
+    ///
+    /// # Arguments
+    ///
+    /// * `deadline` - Total time allowed for each call
+    pub fn deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Sets how failed requests are retried.
+    ///
+    /// # Arguments
+    ///
+    /// * `retry` - Retry policy for the calls
+    pub fn retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+
+    /// Sets a token that cancels the calls.
+    ///
+    /// Once the token is cancelled, calls waiting on the rate limiter, a response,
+    /// a retry backoff or a response body, including file downloads written to a
+    /// writer, stop immediately with
+    /// [`DropboxSignClientError::Cancelled`](super::DropboxSignClientError::Cancelled),
+    /// and [`poll_stream`](crate::events::poll_stream) ends.
+    ///
+    /// # Arguments
+    ///
+    /// * `cancellation` - Token cancelling the calls
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::DropboxSignClient;
+    /// use dropboxsign_client::client::{CancellationToken, RequestOptions};
+    /// use dropboxsign_client::events::poll_stream;
+    /// use futures_util::StreamExt;
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() {
+    /// let shutdown = CancellationToken::new();
+    /// let client = DropboxSignClient::new("your-api-key")
+    ///     .with_options(RequestOptions::new().cancellation(shutdown.clone()));
+    ///
+    /// tokio::spawn(async move {
+    ///     let _ = tokio::signal::ctrl_c().await;
+    ///     shutdown.cancel();
+    /// });
+    ///
+    /// let mut events = Box::pin(poll_stream(client, Duration::from_secs(60)));
+    /// while let Some(event) = events.next().await {
+    ///     println!("{event:?}");
+    /// }
+    /// # }
+    /// ```
+    pub fn cancellation(mut self, cancellation: CancellationToken) -> Self {
+        self.cancellation = Some(cancellation);
+        self
+    }
+}
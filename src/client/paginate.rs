@@ -0,0 +1,53 @@
+//! Streams that walk every page of a list endpoint.
+
+use super::DropboxSignClientError;
+use crate::Page;
+use futures_util::stream::{self, Stream};
+use std::collections::VecDeque;
+use std::future::Future;
+
+/// State carried between pages.
+struct Pages<T, F> {
+    /// Fetches a page
+    fetch: F,
+    /// Page to fetch once `buffered` is drained, or `None` after the last page
+    next_page: Option<u64>,
+    /// Items of the current page not yielded yet
+    buffered: VecDeque<T>,
+}
+
+/// Yields the items of every page returned by `fetch`, starting at page 1.
+///
+/// The next page is only fetched once the items of the current one are consumed.
+/// The stream ends after the last page, or after yielding the first error.
+pub(super) fn paginate<T, F, Fut>(fetch: F) -> impl Stream<Item = Result<T, DropboxSignClientError>>
+where
+    F: FnMut(u64) -> Fut,
+    Fut: Future<Output = Result<Page<T>, DropboxSignClientError>>,
+{
+    let pages = Pages {
+        fetch,
+        next_page: Some(1),
+        buffered: VecDeque::new(),
+    };
+
+    stream::unfold(pages, |mut pages| async move {
+        loop {
+            if let Some(item) = pages.buffered.pop_front() {
+                return Some((Ok(item), pages));
+            }
+
+            let page = pages.next_page?;
+            match (pages.fetch)(page).await {
+                Ok(fetched) => {
+                    pages.next_page = (page < fetched.list_info.num_pages).then_some(page + 1);
+                    pages.buffered.extend(fetched);
+                }
+                Err(err) => {
+                    pages.next_page = None;
+                    return Some((Err(err), pages));
+                }
+            }
+        }
+    })
+}
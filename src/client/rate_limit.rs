@@ -0,0 +1,58 @@
+//! Client-side rate limiting.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+/// Length of the window requests are counted over, matching how the API counts
+/// its per-minute limits.
+const WINDOW: Duration = Duration::from_secs(60);
+
+/// Sliding one-minute window shared by all clones of a client.
+///
+/// No more than the configured number of requests are sent in any 60 seconds,
+/// so the limit holds even for a burst after the client was idle.
+#[derive(Debug)]
+pub(super) struct RateLimiter {
+    /// Maximum number of requests per window
+    limit: usize,
+    /// When each request in the current window was sent, oldest first
+    sent: Mutex<VecDeque<Instant>>,
+}
+
+impl RateLimiter {
+    /// Creates a limiter allowing `requests_per_minute` requests per minute.
+    pub(super) fn new(requests_per_minute: u32) -> Self {
+        let limit = usize::try_from(requests_per_minute.max(1)).unwrap_or(usize::MAX);
+        Self {
+            limit,
+            sent: Mutex::new(VecDeque::with_capacity(limit.min(1024))),
+        }
+    }
+
+    /// Waits until a request may be sent and records it.
+    pub(super) async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut sent = self.sent.lock().await;
+                let now = Instant::now();
+                while sent
+                    .front()
+                    .is_some_and(|sent_at| now.duration_since(*sent_at) >= WINDOW)
+                {
+                    sent.pop_front();
+                }
+
+                match sent.front() {
+                    Some(oldest) if sent.len() >= self.limit => *oldest + WINDOW - now,
+                    _ => {
+                        sent.push_back(now);
+                        return;
+                    }
+                }
+            };
+
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! Redaction of personal data in logged payloads.
+
+use serde_json::Value;
+
+/// Placeholder written over redacted values.
+const REDACTED: &str = "[redacted]";
+
+/// Keys whose values are always redacted.
+const SECRET_KEYS: [&str; 5] = [
+    "api_key",
+    "client_secret",
+    "access_token",
+    "refresh_token",
+    "code",
+];
+
+/// Personal data removed from payloads before they are logged.
+///
+/// Email addresses and phone numbers are redacted by default. Credentials such as
+/// API keys, client secrets and OAuth tokens are always redacted.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::DropboxSignClient;
+/// use dropboxsign_client::client::RedactionPolicy;
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = DropboxSignClient::builder()
+///     .api_key("your-api-key")
+///     .redaction(RedactionPolicy::new().emails(false))
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct RedactionPolicy {
+    emails: bool,
+    phone_numbers: bool,
+}
+
+impl Default for RedactionPolicy {
+    fn default() -> Self {
+        Self {
+            emails: true,
+            phone_numbers: true,
+        }
+    }
+}
+
+impl RedactionPolicy {
+    /// Creates a policy that redacts email addresses and phone numbers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether email addresses are redacted.
+    ///
+    /// # Arguments
+    ///
+    /// * `emails` - Whether to redact email addresses
+    pub fn emails(mut self, emails: bool) -> Self {
+        self.emails = emails;
+        self
+    }
+
+    /// Sets whether phone numbers are redacted.
+    ///
+    /// # Arguments
+    ///
+    /// * `phone_numbers` - Whether to redact phone numbers
+    pub fn phone_numbers(mut self, phone_numbers: bool) -> Self {
+        self.phone_numbers = phone_numbers;
+        self
+    }
+
+    /// Redacts the values of sensitive keys in a JSON payload.
+    #[cfg_attr(not(any(feature = "tracing", feature = "vcr")), allow(dead_code))]
+    pub(super) fn redact(&self, value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                for (key, value) in map.iter_mut() {
+                    if self.is_sensitive(key) {
+                        redact_all(value);
+                    } else {
+                        self.redact(value);
+                    }
+                }
+            }
+            Value::Array(values) => values.iter_mut().for_each(|value| self.redact(value)),
+            Value::String(string) if self.emails && string.contains('@') => {
+                *string = REDACTED.to_string();
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns whether values stored under `key` must be redacted.
+    fn is_sensitive(&self, key: &str) -> bool {
+        let key = key.to_ascii_lowercase();
+
+        SECRET_KEYS.contains(&key.as_str())
+            || (self.emails && key.contains("email"))
+            || (self.phone_numbers && key.contains("phone"))
+    }
+}
+
+/// Replaces every string and number in a value with the redaction placeholder.
+fn redact_all(value: &mut Value) {
+    match value {
+        Value::Object(map) => map.values_mut().for_each(redact_all),
+        Value::Array(values) => values.iter_mut().for_each(redact_all),
+        Value::String(_) | Value::Number(_) => *value = Value::String(REDACTED.to_string()),
+        _ => {}
+    }
+}
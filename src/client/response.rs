@@ -0,0 +1,172 @@
+//! Status and headers of the HTTP responses behind API calls.
+
+use reqwest::StatusCode;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use std::cell::RefCell;
+use std::future::Future;
+use std::time::Duration;
+
+tokio::task_local! {
+    /// Metadata of the last response received inside [`capture`].
+    static LAST_RESPONSE: RefCell<Option<ResponseMetadata>>;
+}
+
+/// Headers that may carry an identifier of the request, in order of preference.
+const REQUEST_ID_HEADERS: [&str; 3] = ["x-request-id", "x-dropbox-request-id", "request-id"];
+
+/// The result of a call together with the HTTP response it came from.
+///
+/// Returned by [`DropboxSignClient::with_metadata`](super::DropboxSignClient::with_metadata).
+/// The metadata is that of the last response the call received, or of the cached
+/// response it was answered from, and is missing when the call did not reach the
+/// API.
+#[derive(Debug)]
+pub struct Response<T> {
+    inner: T,
+    metadata: Option<ResponseMetadata>,
+}
+
+impl<T> Response<T> {
+    /// Returns the result of the call.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Returns the result of the call and the response metadata.
+    pub fn into_parts(self) -> (T, Option<ResponseMetadata>) {
+        (self.inner, self.metadata)
+    }
+
+    /// Returns the metadata of the HTTP response.
+    pub fn metadata(&self) -> Option<&ResponseMetadata> {
+        self.metadata.as_ref()
+    }
+
+    /// Returns the HTTP status of the response.
+    pub fn status(&self) -> Option<StatusCode> {
+        self.metadata.as_ref().map(ResponseMetadata::status)
+    }
+
+    /// Returns the headers of the response.
+    pub fn headers(&self) -> Option<&HeaderMap> {
+        self.metadata.as_ref().map(ResponseMetadata::headers)
+    }
+
+    /// Returns the identifier Dropbox Sign assigned to the request, if it sent one.
+    pub fn request_id(&self) -> Option<&str> {
+        self.metadata.as_ref()?.request_id()
+    }
+
+    /// Returns the rate limit state reported by the response.
+    pub fn rate_limit(&self) -> Option<RateLimitStatus> {
+        self.metadata.as_ref()?.rate_limit()
+    }
+}
+
+/// Status and headers of an HTTP response.
+#[derive(Debug, Clone)]
+pub struct ResponseMetadata {
+    status: StatusCode,
+    headers: HeaderMap,
+}
+
+impl ResponseMetadata {
+    /// Captures the status and headers of a response.
+    pub(super) fn from_response(response: &reqwest::Response) -> Self {
+        Self {
+            status: response.status(),
+            headers: response.headers().clone(),
+        }
+    }
+
+    /// Returns the HTTP status of the response.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Returns the headers of the response.
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// Returns the identifier Dropbox Sign assigned to the request, if it sent one.
+    ///
+    /// Quote this identifier when contacting Dropbox Sign support about a request.
+    pub fn request_id(&self) -> Option<&str> {
+        REQUEST_ID_HEADERS
+            .iter()
+            .find_map(|name| self.headers.get(*name)?.to_str().ok())
+    }
+
+    /// Returns the rate limit state reported by the response.
+    ///
+    /// Returns `None` when the response carries no rate limit headers.
+    pub fn rate_limit(&self) -> Option<RateLimitStatus> {
+        RateLimitStatus::from_headers(&self.headers)
+    }
+}
+
+/// Rate limit state reported by the API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    /// Number of requests allowed per window
+    pub limit: Option<u64>,
+    /// Number of requests left in the current window
+    pub remaining: Option<u64>,
+    /// Unix timestamp at which the window resets
+    pub reset: Option<u64>,
+}
+
+impl RateLimitStatus {
+    /// Parses the rate limit headers of a response.
+    pub(super) fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let limit = header_u64(headers, &["x-ratelimit-limit"]);
+        let remaining = header_u64(
+            headers,
+            &["x-ratelimit-limit-remaining", "x-ratelimit-remaining"],
+        );
+        let reset = header_u64(headers, &["x-ratelimit-reset"]);
+
+        (limit.is_some() || remaining.is_some() || reset.is_some()).then_some(Self {
+            limit,
+            remaining,
+            reset,
+        })
+    }
+}
+
+/// Parses the `Retry-After` header of a response, given in seconds.
+pub(super) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    header_u64(headers, &[RETRY_AFTER.as_str()]).map(Duration::from_secs)
+}
+
+/// Parses the first of `names` present in `headers` as an integer.
+fn header_u64(headers: &HeaderMap, names: &[&str]) -> Option<u64> {
+    names
+        .iter()
+        .find_map(|name| headers.get(*name)?.to_str().ok()?.trim().parse().ok())
+}
+
+/// Runs `call`, capturing the metadata of the last response it receives.
+pub(super) async fn capture<T>(call: impl Future<Output = T>) -> Response<T> {
+    LAST_RESPONSE
+        .scope(RefCell::new(None), async {
+            let inner = call.await;
+            let metadata = LAST_RESPONSE.with(RefCell::take);
+            Response { inner, metadata }
+        })
+        .await
+}
+
+/// Records the metadata of a response for an enclosing [`capture`], if any.
+pub(super) fn record(response: &reqwest::Response) {
+    record_metadata(ResponseMetadata::from_response(response));
+}
+
+/// Records the metadata of a response, such as one served from the cache, for an
+/// enclosing [`capture`], if any.
+pub(super) fn record_metadata(metadata: ResponseMetadata) {
+    let _ = LAST_RESPONSE.try_with(|last| {
+        last.replace(Some(metadata));
+    });
+}
@@ -0,0 +1,155 @@
+//! Retrying of failed requests with exponential backoff.
+
+use super::response;
+use reqwest::{Method, Response, StatusCode};
+use std::time::Duration;
+
+/// How failed requests are retried.
+///
+/// Requests are retried when the connection fails or times out, and when the
+/// API responds with `429 Too Many Requests` or a `502`, `503` or `504` status.
+/// The delay between attempts doubles each time, starting at the initial backoff
+/// and capped at the maximum backoff. A `Retry-After` header on the response is
+/// honored when it asks for a longer delay.
+///
+/// Only idempotent requests (`GET`, `HEAD`, `PUT`, `DELETE` and `OPTIONS`) are
+/// retried unless [`retry_non_idempotent`](Self::retry_non_idempotent) is set, as
+/// a `POST` that timed out or failed at a gateway may still have been processed.
+/// [`DropboxSignClient::send_with_template_idempotent`](super::DropboxSignClient::send_with_template_idempotent)
+/// retries its send safely by checking the idempotency key before every attempt.
+///
+/// Requests are not retried by default.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::DropboxSignClient;
+/// use dropboxsign_client::client::RetryPolicy;
+/// use std::time::Duration;
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = DropboxSignClient::builder()
+///     .api_key("your-api-key")
+///     .retry(RetryPolicy::new(3).initial_backoff(Duration::from_secs(1)))
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a policy that retries failed requests up to `max_retries` times.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_retries` - Maximum number of retries after the first attempt
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            retry_non_idempotent: false,
+        }
+    }
+
+    /// Creates a policy that never retries.
+    pub fn none() -> Self {
+        Self::new(0)
+    }
+
+    /// Sets the delay before the first retry.
+    ///
+    /// # Arguments
+    ///
+    /// * `initial_backoff` - Delay before the first retry
+    pub fn initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Sets the longest delay between two attempts.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_backoff` - Upper bound for the delay between attempts
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Sets whether non-idempotent requests such as `POST` are retried too.
+    ///
+    /// Only enable this if sending a request twice is harmless, e.g. when
+    /// duplicates are detected by other means.
+    ///
+    /// # Arguments
+    ///
+    /// * `retry_non_idempotent` - Whether to retry non-idempotent requests
+    pub fn retry_non_idempotent(mut self, retry_non_idempotent: bool) -> Self {
+        self.retry_non_idempotent = retry_non_idempotent;
+        self
+    }
+
+    /// Returns whether requests with `method` may be retried.
+    pub(super) fn allows_method(&self, method: &Method) -> bool {
+        self.retry_non_idempotent
+            || matches!(
+                *method,
+                Method::GET | Method::HEAD | Method::PUT | Method::DELETE | Method::OPTIONS
+            )
+    }
+
+    /// Returns whether another attempt is allowed after `retries` retries.
+    pub(super) fn allows(&self, retries: u32) -> bool {
+        retries < self.max_retries
+    }
+
+    /// Returns the delay before retry number `retries + 1`.
+    pub(super) fn backoff(&self, retries: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2_u32.saturating_pow(retries))
+            .min(self.max_backoff)
+    }
+
+    /// Returns the delay before retrying `response`, or `None` if its status is
+    /// not retryable.
+    pub(super) fn retry_response(&self, response: &Response, retries: u32) -> Option<Duration> {
+        if !is_retryable_status(response.status()) {
+            return None;
+        }
+
+        let retry_after =
+            response::retry_after(response.headers()).map(|delay| delay.min(self.max_backoff));
+
+        Some(self.backoff(retries).max(retry_after.unwrap_or_default()))
+    }
+
+    /// Returns the delay before retrying after `error`, or `None` if the failure is
+    /// not retryable.
+    pub(super) fn retry_error(&self, error: &reqwest::Error, retries: u32) -> Option<Duration> {
+        (error.is_connect() || error.is_timeout()).then(|| self.backoff(retries))
+    }
+}
+
+/// Returns whether a response status is worth retrying.
+pub(super) fn is_retryable_status(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::TOO_MANY_REQUESTS
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT
+    )
+}
@@ -0,0 +1,80 @@
+//! Tracing instrumentation for API calls.
+//!
+//! With the `tracing` feature every API call runs in a `dropboxsign.request` span
+//! recording the method, endpoint, status, duration and warning count. Without
+//! the feature these helpers compile to nothing.
+
+use super::{DropboxSignClientError, RedactionPolicy};
+use reqwest::{Request, StatusCode};
+use std::future::Future;
+
+/// Runs an API call inside a span and records its outcome.
+#[cfg(feature = "tracing")]
+pub(super) async fn traced<R>(
+    call: impl Future<Output = Result<R, DropboxSignClientError>>,
+    warning_count: impl FnOnce(&R) -> usize,
+) -> Result<R, DropboxSignClientError> {
+    use tracing::Instrument;
+    use tracing::field::Empty;
+
+    let span = tracing::info_span!(
+        "dropboxsign.request",
+        http.method = Empty,
+        endpoint = Empty,
+        http.status = Empty,
+        duration_ms = Empty,
+        warnings = Empty,
+    );
+    let started = std::time::Instant::now();
+    let result = call.instrument(span.clone()).await;
+
+    span.record("duration_ms", started.elapsed().as_millis() as u64);
+    match &result {
+        Ok(value) => {
+            span.record("warnings", warning_count(value));
+        }
+        Err(err) => span.in_scope(|| tracing::error!(error = %err, "request failed")),
+    }
+
+    result
+}
+
+/// Runs an API call.
+#[cfg(not(feature = "tracing"))]
+pub(super) async fn traced<R>(
+    call: impl Future<Output = Result<R, DropboxSignClientError>>,
+    _warning_count: impl FnOnce(&R) -> usize,
+) -> Result<R, DropboxSignClientError> {
+    call.await
+}
+
+/// Records the method and endpoint of a request on the current span.
+pub(super) fn record_request(_request: &Request) {
+    #[cfg(feature = "tracing")]
+    {
+        let span = tracing::Span::current();
+        span.record("http.method", _request.method().as_str());
+        span.record("endpoint", _request.url().path());
+    }
+}
+
+/// Records the response status on the current span.
+pub(super) fn record_status(_status: StatusCode) {
+    #[cfg(feature = "tracing")]
+    tracing::Span::current().record("http.status", _status.as_u16());
+}
+
+/// Logs a payload at debug level after redacting personal data.
+pub(super) fn debug_payload(
+    _message: &str,
+    _payload: &impl serde::Serialize,
+    _redaction: &RedactionPolicy,
+) {
+    #[cfg(feature = "tracing")]
+    if tracing::enabled!(tracing::Level::DEBUG)
+        && let Ok(mut payload) = serde_json::to_value(_payload)
+    {
+        _redaction.redact(&mut payload);
+        tracing::debug!(%payload, "{_message}");
+    }
+}
@@ -0,0 +1,310 @@
+//! Record and replay of API interactions.
+//!
+//! Enabled with the `vcr` feature. A client configured with a recording
+//! [`Cassette`] saves every request and response to disk; a client configured with
+//! a replaying cassette answers requests from the saved interactions without
+//! touching the network.
+
+use super::RedactionPolicy;
+use reqwest::{Client, Request, Response};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use thiserror::Error;
+use tokio::sync::Mutex as AsyncMutex;
+
+/// Errors that can occur when recording or replaying interactions.
+#[derive(Error, Debug)]
+pub enum CassetteError {
+    #[error("cassette I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("cassette Json error: {0}")]
+    Serde(#[from] serde_json::Error),
+
+    #[error("no recorded interaction left for {method} {path}")]
+    NoInteraction { method: String, path: String },
+
+    #[error("invalid recorded response: {0}")]
+    InvalidResponse(String),
+}
+
+/// A request and response pair saved to a cassette.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Interaction {
+    /// HTTP method of the request
+    method: String,
+    /// Path and query of the request, relative to the host
+    path: String,
+    /// Request body, when it was sent as a single buffer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    request_body: Option<Value>,
+    /// Status code of the response
+    status: u16,
+    /// Content type of the response
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    content_type: Option<String>,
+    /// Response body as text, for textual responses
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+    /// Response body as hex, for binary responses
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body_hex: Option<String>,
+}
+
+/// Whether a cassette records or replays interactions.
+#[derive(Debug)]
+enum Mode {
+    /// Send requests and save the interactions
+    Record,
+    /// Answer requests from saved interactions, tracking which were used
+    Replay(Vec<bool>),
+}
+
+/// File of recorded API interactions.
+///
+/// Authentication headers are never recorded, and API keys, client secrets and
+/// OAuth tokens are scrubbed from request and response bodies before saving.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::DropboxSignClient;
+/// use dropboxsign_client::client::Cassette;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let cassette = if std::env::var("RECORD").is_ok() {
+///     Cassette::record("tests/cassettes/get_account.json")
+/// } else {
+///     Cassette::replay("tests/cassettes/get_account.json")?
+/// };
+///
+/// let client = DropboxSignClient::builder()
+///     .api_key(std::env::var("DROPBOXSIGN_API_KEY").unwrap_or_default())
+///     .cassette(cassette)
+///     .build()?;
+///
+/// let (account, _) = client.get_account().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Cassette {
+    path: PathBuf,
+    mode: Mutex<Mode>,
+    interactions: Mutex<Vec<Interaction>>,
+    /// Serializes rewrites of the file so a stale copy never lands last
+    write_lock: AsyncMutex<()>,
+}
+
+impl Cassette {
+    /// Creates a cassette that records interactions to `path`.
+    ///
+    /// Any existing recording at `path` is replaced once the first interaction is
+    /// saved.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - File the interactions are written to
+    pub fn record(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            mode: Mutex::new(Mode::Record),
+            interactions: Mutex::new(Vec::new()),
+            write_lock: AsyncMutex::new(()),
+        }
+    }
+
+    /// Loads a cassette that replays the interactions saved at `path`.
+    ///
+    /// Requests are matched by method and path, in the order they were recorded.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - File the interactions were recorded to
+    ///
+    /// # Errors
+    ///
+    /// Returns `CassetteError` if the file cannot be read or parsed.
+    pub fn replay(path: impl AsRef<Path>) -> Result<Self, CassetteError> {
+        let interactions: Vec<Interaction> =
+            serde_json::from_slice(&std::fs::read(path.as_ref())?)?;
+
+        Ok(Self {
+            path: path.as_ref().to_path_buf(),
+            mode: Mutex::new(Mode::Replay(vec![false; interactions.len()])),
+            interactions: Mutex::new(interactions),
+            write_lock: AsyncMutex::new(()),
+        })
+    }
+
+    /// Returns the file backing the cassette.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Sends a request through the cassette.
+    pub(super) async fn execute(
+        &self,
+        client: &Client,
+        request: Request,
+    ) -> Result<Response, super::DropboxSignClientError> {
+        let method = request.method().to_string();
+        let path = match request.url().query() {
+            Some(query) => format!("{}?{query}", request.url().path()),
+            None => request.url().path().to_string(),
+        };
+
+        if let Some(interaction) = self.next_recorded(&method, &path)? {
+            return Ok(interaction.into_response()?);
+        }
+
+        let request_body = request
+            .body()
+            .and_then(|body| body.as_bytes())
+            .map(scrubbed_body);
+        let response = client.execute(request).await?;
+
+        let status = response.status().as_u16();
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let bytes = response.bytes().await?;
+
+        let mut interaction = Interaction {
+            method,
+            path,
+            request_body,
+            status,
+            content_type,
+            body: None,
+            body_hex: None,
+        };
+        match std::str::from_utf8(&bytes) {
+            Ok(text) => interaction.body = Some(text.to_string()),
+            Err(_) => interaction.body_hex = Some(hex::encode(&bytes)),
+        }
+
+        // The caller gets the live response; only the saved copy is scrubbed.
+        let mut recorded = interaction.clone();
+        recorded.body = recorded.body.as_deref().map(scrubbed_text);
+        self.save(recorded).await?;
+
+        Ok(interaction.into_response()?)
+    }
+
+    /// Takes the next unused recorded interaction matching the request.
+    ///
+    /// Returns `None` when recording.
+    fn next_recorded(
+        &self,
+        method: &str,
+        path: &str,
+    ) -> Result<Option<Interaction>, CassetteError> {
+        let mut mode = self.mode.lock().map_err(|_| poisoned())?;
+        let Mode::Replay(used) = &mut *mode else {
+            return Ok(None);
+        };
+
+        let interactions = self.interactions.lock().map_err(|_| poisoned())?;
+        let index = interactions
+            .iter()
+            .enumerate()
+            .position(|(index, interaction)| {
+                !used[index] && interaction.method == method && interaction.path == path
+            })
+            .ok_or_else(|| CassetteError::NoInteraction {
+                method: method.to_string(),
+                path: path.to_string(),
+            })?;
+
+        used[index] = true;
+        Ok(Some(interactions[index].clone()))
+    }
+
+    /// Appends an interaction and rewrites the cassette file.
+    ///
+    /// The file is written without blocking the runtime. Each write snapshots the
+    /// interactions after taking the write lock, so the last write always holds
+    /// every saved interaction.
+    async fn save(&self, interaction: Interaction) -> Result<(), CassetteError> {
+        self.interactions
+            .lock()
+            .map_err(|_| poisoned())?
+            .push(interaction);
+
+        let _guard = self.write_lock.lock().await;
+        let contents = {
+            let interactions = self.interactions.lock().map_err(|_| poisoned())?;
+            serde_json::to_vec_pretty(&*interactions)?
+        };
+        if let Some(parent) = self.path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        tokio::fs::write(&self.path, contents).await?;
+        Ok(())
+    }
+}
+
+impl Interaction {
+    /// Rebuilds the recorded response.
+    fn into_response(self) -> Result<Response, CassetteError> {
+        let body = match (self.body, self.body_hex) {
+            (Some(body), _) => body.into_bytes(),
+            (None, Some(body_hex)) => hex::decode(body_hex)
+                .map_err(|err| CassetteError::InvalidResponse(err.to_string()))?,
+            (None, None) => Vec::new(),
+        };
+
+        let mut builder = http::Response::builder().status(self.status);
+        if let Some(content_type) = self.content_type {
+            builder = builder.header(http::header::CONTENT_TYPE, content_type);
+        }
+        let response = builder
+            .body(body)
+            .map_err(|err| CassetteError::InvalidResponse(err.to_string()))?;
+
+        Ok(Response::from(response))
+    }
+}
+
+impl From<CassetteError> for super::DropboxSignClientError {
+    fn from(err: CassetteError) -> Self {
+        Self::Other(Box::new(err))
+    }
+}
+
+/// Error for a cassette whose lock was poisoned by a panicking thread.
+fn poisoned() -> CassetteError {
+    CassetteError::InvalidResponse("cassette lock poisoned".to_string())
+}
+
+/// Returns a recorded request body with secrets scrubbed.
+fn scrubbed_body(bytes: &[u8]) -> Value {
+    match serde_json::from_slice(bytes) {
+        Ok(mut value) => {
+            secrets_only().redact(&mut value);
+            value
+        }
+        Err(_) => Value::String(String::from_utf8_lossy(bytes).into_owned()),
+    }
+}
+
+/// Returns a recorded response body with secrets scrubbed.
+fn scrubbed_text(text: &str) -> String {
+    match serde_json::from_str::<Value>(text) {
+        Ok(mut value) => {
+            secrets_only().redact(&mut value);
+            value.to_string()
+        }
+        Err(_) => text.to_string(),
+    }
+}
+
+/// Redaction policy that only removes credentials.
+fn secrets_only() -> RedactionPolicy {
+    RedactionPolicy::new().emails(false).phone_numbers(false)
+}
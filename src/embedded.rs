@@ -0,0 +1,96 @@
+//! Data models and types for embedded signing and template editing.
+//!
+//! Embedded URLs are opened in an iframe on the API app's domain. They expire
+//! shortly after they are issued, so frontends should check
+//! [`is_expired`](EmbeddedResponse::is_expired) and request a fresh URL instead
+//! of reusing an old one.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// URL for signing a signature request in an embedded iframe.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::DropboxSignClient;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = DropboxSignClient::new("your-api-key");
+///
+/// let (embedded, _) = client.get_embedded_sign_url(&"signature-id".into()).await?;
+/// if let Some(remaining) = embedded.expires_in() {
+///     println!("{} is valid for {}s", embedded.sign_url, remaining.as_secs());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmbeddedResponse {
+    /// URL to open in the embedded signing iframe
+    pub sign_url: String,
+    /// Unix timestamp when the URL expires
+    pub expires_at: u64,
+    /// Fields returned by the API that this crate does not model yet
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "crate::strict::extra",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    pub extra: HashMap<String, Value>,
+}
+
+impl EmbeddedResponse {
+    /// Returns whether the sign URL has expired.
+    pub fn is_expired(&self) -> bool {
+        expires_in(self.expires_at).is_none()
+    }
+
+    /// Returns how long the sign URL remains valid, or `None` once it has
+    /// expired.
+    pub fn expires_in(&self) -> Option<Duration> {
+        expires_in(self.expires_at)
+    }
+}
+
+/// URL for editing a template in an embedded iframe.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TemplateEditResponse {
+    /// URL to open in the embedded template editor iframe
+    pub edit_url: String,
+    /// Unix timestamp when the URL expires
+    pub expires_at: u64,
+    /// Fields returned by the API that this crate does not model yet
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "crate::strict::extra",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    pub extra: HashMap<String, Value>,
+}
+
+impl TemplateEditResponse {
+    /// Returns whether the edit URL has expired.
+    pub fn is_expired(&self) -> bool {
+        expires_in(self.expires_at).is_none()
+    }
+
+    /// Returns how long the edit URL remains valid, or `None` once it has
+    /// expired.
+    pub fn expires_in(&self) -> Option<Duration> {
+        expires_in(self.expires_at)
+    }
+}
+
+/// Returns the time left until the Unix timestamp `expires_at`, or `None` if it
+/// has passed.
+fn expires_in(expires_at: u64) -> Option<Duration> {
+    (UNIX_EPOCH + Duration::from_secs(expires_at))
+        .duration_since(SystemTime::now())
+        .ok()
+        .filter(|remaining| !remaining.is_zero())
+}
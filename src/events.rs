@@ -0,0 +1,639 @@
+//! Data models and types for webhook callback events.
+//!
+//! Dropbox Sign notifies your callback URLs about activity on signature requests,
+//! templates and accounts. This module contains the data structures needed to
+//! deserialize those callbacks into the response types this crate already defines.
+
+use crate::account::AccountResponse;
+use crate::id::{AccountId, ClientId, SignatureId};
+use crate::signature_request::SignatureRequestResponse;
+use crate::template::TemplateResponse;
+use hmac::{Hmac, KeyInit, Mac};
+use reqwest::StatusCode;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sha2::Sha256;
+use std::fmt;
+use thiserror::Error;
+
+mod handler;
+mod poll;
+mod replay;
+
+pub use handler::{EventHandler, EventHandlerError, dispatch};
+pub use poll::poll_stream;
+pub use replay::{
+    EventDeduplicator, EventDeduplicatorError, InMemoryEventDeduplicator, VerificationPolicy,
+};
+
+#[cfg(feature = "actix")]
+pub mod actix;
+#[cfg(feature = "axum")]
+pub mod axum;
+#[cfg(feature = "dev-server")]
+pub mod dev_server;
+#[cfg(feature = "lambda")]
+pub mod lambda;
+
+/// Complete payload of a webhook callback.
+///
+/// Besides the event itself, the payload carries a snapshot of the object the
+/// event is about.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::events::EventCallbackRequest;
+///
+/// # fn example(json: &str) -> Result<(), serde_json::Error> {
+/// let callback: EventCallbackRequest = serde_json::from_str(json)?;
+///
+/// if let Some(signature_request) = &callback.signature_request {
+///     println!(
+///         "{} for {}",
+///         callback.event.event_type, signature_request.signature_request_id
+///     );
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventCallbackRequest {
+    /// The event that triggered the callback
+    pub event: Event,
+    /// The signature request the event is about
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature_request: Option<SignatureRequestResponse>,
+    /// The template the event is about
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<TemplateResponse>,
+    /// The account the event is about
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account: Option<AccountResponse>,
+}
+
+impl EventCallbackRequest {
+    /// Returns the key that identifies this event when deduplicating deliveries.
+    ///
+    /// The `event_hash` only covers the event time and type, so two events of the
+    /// same type within the same second share it. The key adds the ID of the
+    /// signature request, template or account the event is about, and the
+    /// related signature for signer-specific events.
+    pub fn deduplication_key(&self) -> String {
+        let object_id = self
+            .signature_request
+            .as_ref()
+            .map(|request| request.signature_request_id.as_str())
+            .or_else(|| {
+                self.template
+                    .as_ref()
+                    .map(|template| template.template_id.as_str())
+            })
+            .or_else(|| {
+                self.account
+                    .as_ref()
+                    .map(|account| account.account_id.as_str())
+            })
+            .unwrap_or_default();
+        let related_signature_id = self
+            .event
+            .event_metadata
+            .as_ref()
+            .and_then(|metadata| metadata.related_signature_id.as_ref())
+            .map(SignatureId::as_str)
+            .unwrap_or_default();
+
+        format!(
+            "{}:{object_id}:{related_signature_id}",
+            self.event.event_hash
+        )
+    }
+}
+
+/// A single callback event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Event {
+    /// Unix timestamp when the event occurred, as a string
+    pub event_time: String,
+    /// Type of the event
+    pub event_type: EventType,
+    /// HMAC of the event time and type, used to verify the callback
+    pub event_hash: String,
+    /// Additional information about the event
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event_metadata: Option<EventMetadata>,
+}
+
+impl Event {
+    /// Returns whether the callback is an account or an app callback.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::events::{EventCallbackRequest, EventSource};
+    ///
+    /// # fn example(callback: EventCallbackRequest) {
+    /// match callback.event.source() {
+    ///     Some(EventSource::App(client_id)) => println!("app callback for {client_id}"),
+    ///     Some(EventSource::Account(account_id)) => println!("account callback for {account_id}"),
+    ///     None => {}
+    /// }
+    /// # }
+    /// ```
+    pub fn source(&self) -> Option<EventSource> {
+        self.event_metadata.as_ref().and_then(EventMetadata::source)
+    }
+
+    /// Verifies that the event was sent by Dropbox Sign.
+    ///
+    /// The `event_hash` is an HMAC-SHA256 of `event_time` followed by `event_type`,
+    /// keyed with the API key of the account (or API app owner) the callback was
+    /// sent for. The comparison runs in constant time.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - API key the callback was signed with
+    ///
+    /// # Errors
+    ///
+    /// Returns `EventVerificationError` if the hash is malformed or does not match,
+    /// in which case the callback should be rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dropboxsign_client::events::{Event, EventType, EventVerificationError};
+    ///
+    /// let mut event = Event {
+    ///     event_time: "1700000000".to_string(),
+    ///     event_type: EventType::SignatureRequestSent,
+    ///     event_hash: "f852ed57f6af09b9e4541c9688d3d84b29015c0d4969592f49cc248b749aac48"
+    ///         .to_string(),
+    ///     event_metadata: None,
+    /// };
+    /// assert!(event.verify("your-api-key").is_ok());
+    /// assert!(matches!(
+    ///     event.verify("another-api-key"),
+    ///     Err(EventVerificationError::HashMismatch)
+    /// ));
+    ///
+    /// // A tampered event type no longer matches the hash.
+    /// event.event_type = EventType::SignatureRequestAllSigned;
+    /// assert!(matches!(
+    ///     event.verify("your-api-key"),
+    ///     Err(EventVerificationError::HashMismatch)
+    /// ));
+    ///
+    /// // So does a tampered hash.
+    /// event.event_type = EventType::SignatureRequestSent;
+    /// event.event_hash.replace_range(..2, "00");
+    /// assert!(matches!(
+    ///     event.verify("your-api-key"),
+    ///     Err(EventVerificationError::HashMismatch)
+    /// ));
+    ///
+    /// event.event_hash = "not-hex".to_string();
+    /// assert!(matches!(
+    ///     event.verify("your-api-key"),
+    ///     Err(EventVerificationError::MalformedHash)
+    /// ));
+    /// ```
+    pub fn verify(&self, api_key: &str) -> Result<(), EventVerificationError> {
+        let expected =
+            hex::decode(&self.event_hash).map_err(|_| EventVerificationError::MalformedHash)?;
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(api_key.as_bytes())
+            .map_err(|_| EventVerificationError::HashMismatch)?;
+        mac.update(self.event_time.as_bytes());
+        mac.update(self.event_type.as_str().as_bytes());
+
+        mac.verify_slice(&expected)
+            .map_err(|_| EventVerificationError::HashMismatch)
+    }
+}
+
+/// Additional information about a callback event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventMetadata {
+    /// Signature the event relates to, for signer-specific events
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub related_signature_id: Option<SignatureId>,
+    /// Account the callback was sent for, for account callbacks
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reported_for_account_id: Option<AccountId>,
+    /// Client ID of the API app the callback was sent for, for app callbacks
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reported_for_app_id: Option<ClientId>,
+    /// Human-readable message describing the event
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event_message: Option<String>,
+}
+
+impl EventMetadata {
+    /// Returns whether the callback is an account or an app callback.
+    ///
+    /// Returns `None` if the metadata does not report either.
+    pub fn source(&self) -> Option<EventSource> {
+        self.reported_for_app_id
+            .clone()
+            .map(EventSource::App)
+            .or_else(|| {
+                self.reported_for_account_id
+                    .clone()
+                    .map(EventSource::Account)
+            })
+    }
+}
+
+/// Where a callback was configured to be sent from.
+///
+/// Account callbacks go to the URL set on the account, app callbacks to the URL set
+/// on an API app.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EventSource {
+    /// Callback configured on the account with the given ID
+    Account(AccountId),
+    /// Callback configured on the API app with the given client ID
+    App(ClientId),
+}
+
+/// Types of callback events sent by Dropbox Sign.
+///
+/// Event types that this crate does not know about yet are kept in
+/// [`EventType::Unknown`], so matching stays exhaustive as the API adds events.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EventType {
+    /// The account's email address was confirmed
+    AccountConfirmed,
+    /// An unexpected error occurred
+    UnknownError,
+    /// A document could not be processed
+    FileError,
+    /// An embedded sign URL was invalid
+    SignUrlInvalid,
+    /// A signer viewed the signature request
+    SignatureRequestViewed,
+    /// A signer signed the signature request
+    SignatureRequestSigned,
+    /// The signature request was sent
+    SignatureRequestSent,
+    /// Every signer has signed the signature request
+    SignatureRequestAllSigned,
+    /// An email to a signer bounced
+    SignatureRequestEmailBounce,
+    /// A reminder was sent to a signer
+    SignatureRequestRemind,
+    /// A qualified electronic signature could not be completed
+    SignatureRequestIncompleteQes,
+    /// The signature request was deleted
+    SignatureRequestDestroyed,
+    /// The signature request was canceled
+    SignatureRequestCanceled,
+    /// The signed documents are ready to download
+    SignatureRequestDownloadable,
+    /// A signer declined the signature request
+    SignatureRequestDeclined,
+    /// A signer reassigned the signature request
+    SignatureRequestReassigned,
+    /// The signature request could not be created
+    SignatureRequestInvalid,
+    /// The signature request was prepared for sending
+    SignatureRequestPrepared,
+    /// The signature request expired
+    SignatureRequestExpired,
+    /// A signer was removed from the signature request
+    SignatureRequestSignerRemoved,
+    /// A template was created
+    TemplateCreated,
+    /// A template could not be created
+    TemplateError,
+    /// A test callback sent while configuring a callback URL
+    CallbackTest,
+    /// An event type this crate does not recognize
+    Unknown(String),
+}
+
+impl EventType {
+    /// Returns the event type as it appears in callbacks.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::AccountConfirmed => "account_confirmed",
+            Self::UnknownError => "unknown_error",
+            Self::FileError => "file_error",
+            Self::SignUrlInvalid => "sign_url_invalid",
+            Self::SignatureRequestViewed => "signature_request_viewed",
+            Self::SignatureRequestSigned => "signature_request_signed",
+            Self::SignatureRequestSent => "signature_request_sent",
+            Self::SignatureRequestAllSigned => "signature_request_all_signed",
+            Self::SignatureRequestEmailBounce => "signature_request_email_bounce",
+            Self::SignatureRequestRemind => "signature_request_remind",
+            Self::SignatureRequestIncompleteQes => "signature_request_incomplete_qes",
+            Self::SignatureRequestDestroyed => "signature_request_destroyed",
+            Self::SignatureRequestCanceled => "signature_request_canceled",
+            Self::SignatureRequestDownloadable => "signature_request_downloadable",
+            Self::SignatureRequestDeclined => "signature_request_declined",
+            Self::SignatureRequestReassigned => "signature_request_reassigned",
+            Self::SignatureRequestInvalid => "signature_request_invalid",
+            Self::SignatureRequestPrepared => "signature_request_prepared",
+            Self::SignatureRequestExpired => "signature_request_expired",
+            Self::SignatureRequestSignerRemoved => "signature_request_signer_removed",
+            Self::TemplateCreated => "template_created",
+            Self::TemplateError => "template_error",
+            Self::CallbackTest => "callback_test",
+            Self::Unknown(event_type) => event_type,
+        }
+    }
+}
+
+impl From<&str> for EventType {
+    fn from(event_type: &str) -> Self {
+        match event_type {
+            "account_confirmed" => Self::AccountConfirmed,
+            "unknown_error" => Self::UnknownError,
+            "file_error" => Self::FileError,
+            "sign_url_invalid" => Self::SignUrlInvalid,
+            "signature_request_viewed" => Self::SignatureRequestViewed,
+            "signature_request_signed" => Self::SignatureRequestSigned,
+            "signature_request_sent" => Self::SignatureRequestSent,
+            "signature_request_all_signed" => Self::SignatureRequestAllSigned,
+            "signature_request_email_bounce" => Self::SignatureRequestEmailBounce,
+            "signature_request_remind" => Self::SignatureRequestRemind,
+            "signature_request_incomplete_qes" => Self::SignatureRequestIncompleteQes,
+            "signature_request_destroyed" => Self::SignatureRequestDestroyed,
+            "signature_request_canceled" => Self::SignatureRequestCanceled,
+            "signature_request_downloadable" => Self::SignatureRequestDownloadable,
+            "signature_request_declined" => Self::SignatureRequestDeclined,
+            "signature_request_reassigned" => Self::SignatureRequestReassigned,
+            "signature_request_invalid" => Self::SignatureRequestInvalid,
+            "signature_request_prepared" => Self::SignatureRequestPrepared,
+            "signature_request_expired" => Self::SignatureRequestExpired,
+            "signature_request_signer_removed" => Self::SignatureRequestSignerRemoved,
+            "template_created" => Self::TemplateCreated,
+            "template_error" => Self::TemplateError,
+            "callback_test" => Self::CallbackTest,
+            other => Self::Unknown(other.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for EventType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for EventType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for EventType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let event_type = String::deserialize(deserializer)?;
+        Ok(Self::from(event_type.as_str()))
+    }
+}
+
+/// Errors that can occur when verifying a callback event.
+#[derive(Error, Debug)]
+pub enum EventVerificationError {
+    #[error("event hash is not valid hex")]
+    MalformedHash,
+
+    #[error("event hash does not match")]
+    HashMismatch,
+
+    #[error("event time is not a unix timestamp")]
+    MalformedTimestamp,
+
+    #[error("event time is outside the accepted window")]
+    TimestampOutOfRange,
+
+    #[error("event has already been claimed for processing")]
+    Duplicate,
+
+    #[error("Deduplicator error: {0}")]
+    Deduplicator(EventDeduplicatorError),
+}
+
+/// Current state of the object a callback is about.
+///
+/// Returned by `DropboxSignClient::hydrate_event`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HydratedEvent {
+    /// The signature request the event relates to
+    SignatureRequest(Box<SignatureRequestResponse>),
+    /// The template the event relates to
+    Template(Box<TemplateResponse>),
+}
+
+/// API key used to verify incoming callbacks.
+///
+/// Framework integrations read this from the application state to check the
+/// `event_hash` of every callback they accept.
+#[derive(Debug, Clone)]
+pub struct WebhookKey(String);
+
+impl WebhookKey {
+    /// Creates a new webhook key.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - API key the callbacks are signed with
+    pub fn new(api_key: impl Into<String>) -> Self {
+        Self(api_key.into())
+    }
+
+    /// Returns the API key as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Body Dropbox Sign expects in the response to a callback.
+///
+/// Any other response is treated as a failed delivery and the callback is retried.
+pub const CALLBACK_ACKNOWLEDGEMENT: &str = "Hello API Event Received";
+
+/// Returns the status code and body that acknowledge a callback.
+///
+/// Use this when answering callbacks from a server without a dedicated
+/// integration.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::events::acknowledgement;
+///
+/// let (status, body) = acknowledgement();
+/// assert_eq!(status.as_u16(), 200);
+/// assert_eq!(body, "Hello API Event Received");
+/// ```
+pub fn acknowledgement() -> (StatusCode, &'static str) {
+    (StatusCode::OK, CALLBACK_ACKNOWLEDGEMENT)
+}
+
+/// Errors that can occur when parsing a callback request body.
+#[derive(Error, Debug)]
+pub enum EventParseError {
+    #[error("unsupported content type: {0}")]
+    UnsupportedContentType(String),
+
+    #[error("multipart content type has no boundary")]
+    MissingBoundary,
+
+    #[error("multipart body has no `json` field")]
+    MissingJsonField,
+
+    #[error("Json error: {0}")]
+    Serde(#[from] serde_json::Error),
+}
+
+/// Parses the body of a callback request into its typed payload.
+///
+/// Dropbox Sign posts callbacks as `multipart/form-data` with the JSON payload in
+/// a field named `json`. Bodies sent as `application/json` are accepted as well.
+///
+/// # Arguments
+///
+/// * `body` - Raw request body
+/// * `content_type` - Value of the request's `Content-Type` header
+///
+/// # Errors
+///
+/// Returns `EventParseError` if the content type is not supported, the multipart
+/// body has no `json` field, or the payload cannot be deserialized.
+///
+/// # Examples
+///
+/// ```
+/// use dropboxsign_client::events::{EventParseError, EventType, parse_callback};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let content_type = "multipart/form-data; boundary=------------------------d74496d66958873e";
+/// let body = concat!(
+///     "--------------------------d74496d66958873e\r\n",
+///     "Content-Disposition: form-data; name=\"json\"\r\n",
+///     "\r\n",
+///     r#"{"event":{"event_time":"1700000000","event_type":"signature_request_sent","#,
+///     r#""event_hash":"f852ed57f6af09b9e4541c9688d3d84b29015c0d4969592f49cc248b749aac48","#,
+///     r#""event_metadata":{"related_signature_id":null,"reported_for_account_id":"63522885f9261e2b04eea043933ee7313eb674fd","#,
+///     r#""reported_for_app_id":null,"event_message":null}}}"#,
+///     "\r\n",
+///     "--------------------------d74496d66958873e--\r\n",
+/// );
+///
+/// let callback = parse_callback(body.as_bytes(), content_type)?;
+/// assert_eq!(callback.event.event_type, EventType::SignatureRequestSent);
+/// callback.event.verify("your-api-key")?;
+///
+/// // A body without a `json` part is rejected.
+/// let body = concat!(
+///     "--------------------------d74496d66958873e\r\n",
+///     "Content-Disposition: form-data; name=\"other\"\r\n",
+///     "\r\n",
+///     "{}\r\n",
+///     "--------------------------d74496d66958873e--\r\n",
+/// );
+/// assert!(matches!(
+///     parse_callback(body.as_bytes(), content_type),
+///     Err(EventParseError::MissingJsonField)
+/// ));
+/// assert!(matches!(
+///     parse_callback(b"", content_type),
+///     Err(EventParseError::MissingJsonField)
+/// ));
+/// # Ok(())
+/// # }
+/// ```
+pub fn parse_callback(
+    body: &[u8],
+    content_type: &str,
+) -> Result<EventCallbackRequest, EventParseError> {
+    let mime = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+
+    let json = match mime.as_str() {
+        "multipart/form-data" => {
+            let boundary =
+                multipart_boundary(content_type).ok_or(EventParseError::MissingBoundary)?;
+            multipart_field(body, &boundary, "json").ok_or(EventParseError::MissingJsonField)?
+        }
+        "application/json" => body,
+        _ => {
+            return Err(EventParseError::UnsupportedContentType(
+                content_type.to_string(),
+            ));
+        }
+    };
+
+    Ok(serde_json::from_slice(json)?)
+}
+
+/// Extracts the boundary parameter from a `multipart/form-data` content type.
+pub(crate) fn multipart_boundary(content_type: &str) -> Option<String> {
+    content_type.split(';').skip(1).find_map(|param| {
+        let (name, value) = param.split_once('=')?;
+        name.trim()
+            .eq_ignore_ascii_case("boundary")
+            .then(|| value.trim().trim_matches('"').to_string())
+    })
+}
+
+/// Returns the contents of the multipart field called `name`.
+fn multipart_field<'a>(body: &'a [u8], boundary: &str, name: &str) -> Option<&'a [u8]> {
+    let delimiter = format!("--{boundary}");
+    let disposition = format!("name=\"{name}\"");
+
+    split_bytes(body, delimiter.as_bytes())
+        .skip(1)
+        .find_map(|part| {
+            let part = part.strip_prefix(b"\r\n").unwrap_or(part);
+            let header_end = find_bytes(part, b"\r\n\r\n")?;
+            let headers = std::str::from_utf8(&part[..header_end]).ok()?;
+
+            let is_field = headers.lines().any(|line| {
+                line.to_ascii_lowercase()
+                    .starts_with("content-disposition:")
+                    && line.contains(&disposition)
+            });
+            if !is_field {
+                return None;
+            }
+
+            let content = &part[header_end + 4..];
+            Some(content.strip_suffix(b"\r\n").unwrap_or(content))
+        })
+}
+
+/// Splits `haystack` on every occurrence of `needle`.
+pub(crate) fn split_bytes<'a, 'n>(
+    haystack: &'a [u8],
+    needle: &'n [u8],
+) -> impl Iterator<Item = &'a [u8]> + use<'a, 'n> {
+    let mut rest = Some(haystack);
+    std::iter::from_fn(move || {
+        let current = rest?;
+        match find_bytes(current, needle) {
+            Some(index) => {
+                rest = Some(&current[index + needle.len()..]);
+                Some(&current[..index])
+            }
+            None => {
+                rest = None;
+                Some(current)
+            }
+        }
+    })
+}
+
+/// Returns the index of the first occurrence of `needle` in `haystack`.
+pub(crate) fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
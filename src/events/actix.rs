@@ -0,0 +1,109 @@
+//! Actix Web integration for webhook callbacks.
+//!
+//! Enabled with the `actix` feature. Handlers take a [`DropboxSignEvent`] argument to
+//! receive callbacks that have already been parsed and verified.
+
+use crate::events::{
+    CALLBACK_ACKNOWLEDGEMENT, EventCallbackRequest, EventParseError, EventVerificationError,
+    WebhookKey, parse_callback,
+};
+use actix_web::dev::Payload;
+use actix_web::http::StatusCode;
+use actix_web::http::header::CONTENT_TYPE;
+use actix_web::web::{Bytes, Data};
+use actix_web::{FromRequest, HttpRequest, HttpResponse, ResponseError};
+use std::future::Future;
+use std::pin::Pin;
+use thiserror::Error;
+
+/// Extractor for a verified webhook callback.
+///
+/// The request body is parsed with [`parse_callback`] and the event hash is checked
+/// against the [`WebhookKey`] registered as app data, either directly or wrapped
+/// in `web::Data`. Requests that fail either step are rejected before the handler
+/// runs.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actix_web::{App, HttpResponse, web};
+/// use dropboxsign_client::events::WebhookKey;
+/// use dropboxsign_client::events::actix::{DropboxSignEvent, acknowledge};
+///
+/// async fn callback(DropboxSignEvent(callback): DropboxSignEvent) -> HttpResponse {
+///     println!("received {}", callback.event.event_type);
+///     acknowledge()
+/// }
+///
+/// let app = App::new()
+///     .app_data(web::Data::new(WebhookKey::new("your-api-key")))
+///     .route("/dropboxsign/callback", web::post().to(callback));
+/// ```
+#[derive(Debug)]
+pub struct DropboxSignEvent(pub EventCallbackRequest);
+
+/// Rejection returned when a request is not a valid webhook callback.
+#[derive(Error, Debug)]
+pub enum DropboxSignEventRejection {
+    #[error("no WebhookKey registered as app data")]
+    MissingKey,
+
+    #[error(transparent)]
+    Parse(#[from] EventParseError),
+
+    #[error(transparent)]
+    Verification(#[from] EventVerificationError),
+}
+
+impl ResponseError for DropboxSignEventRejection {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::MissingKey => StatusCode::INTERNAL_SERVER_ERROR,
+            Self::Parse(EventParseError::UnsupportedContentType(_)) => {
+                StatusCode::UNSUPPORTED_MEDIA_TYPE
+            }
+            Self::Parse(_) => StatusCode::BAD_REQUEST,
+            Self::Verification(_) => StatusCode::UNAUTHORIZED,
+        }
+    }
+}
+
+impl FromRequest for DropboxSignEvent {
+    type Error = actix_web::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let key = req
+            .app_data::<Data<WebhookKey>>()
+            .map(|key| key.get_ref().clone())
+            .or_else(|| req.app_data::<WebhookKey>().cloned());
+        let content_type = req
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+        let body = Bytes::from_request(req, payload);
+
+        Box::pin(async move {
+            let key = key.ok_or(DropboxSignEventRejection::MissingKey)?;
+            let body = body.await?;
+
+            let callback =
+                parse_callback(&body, &content_type).map_err(DropboxSignEventRejection::from)?;
+            callback
+                .event
+                .verify(key.as_str())
+                .map_err(DropboxSignEventRejection::from)?;
+
+            Ok(Self(callback))
+        })
+    }
+}
+
+/// Builds the response that acknowledges a callback.
+pub fn acknowledge() -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type("text/plain")
+        .body(CALLBACK_ACKNOWLEDGEMENT)
+}
@@ -0,0 +1,97 @@
+//! Axum integration for webhook callbacks.
+//!
+//! Enabled with the `axum` feature. Handlers take a [`DropboxSignEvent`] argument to
+//! receive callbacks that have already been parsed and verified.
+
+use crate::events::{
+    CALLBACK_ACKNOWLEDGEMENT, EventCallbackRequest, EventParseError, EventVerificationError,
+    WebhookKey, parse_callback,
+};
+use ::axum::body::Bytes;
+use ::axum::extract::rejection::BytesRejection;
+use ::axum::extract::{FromRef, FromRequest, Request};
+use ::axum::http::StatusCode;
+use ::axum::http::header::CONTENT_TYPE;
+use ::axum::response::{IntoResponse, Response};
+use thiserror::Error;
+
+/// Extractor for a verified webhook callback.
+///
+/// The request body is parsed with [`parse_callback`] and the event hash is checked
+/// against the [`WebhookKey`] taken from the router state. Requests that fail either
+/// step are rejected before the handler runs.
+///
+/// # Examples
+///
+/// ```no_run
+/// use axum::{Router, response::IntoResponse, routing::post};
+/// use dropboxsign_client::events::WebhookKey;
+/// use dropboxsign_client::events::axum::{DropboxSignEvent, acknowledge};
+///
+/// async fn callback(DropboxSignEvent(callback): DropboxSignEvent) -> impl IntoResponse {
+///     println!("received {}", callback.event.event_type);
+///     acknowledge()
+/// }
+///
+/// let app: Router = Router::new()
+///     .route("/dropboxsign/callback", post(callback))
+///     .with_state(WebhookKey::new("your-api-key"));
+/// ```
+#[derive(Debug)]
+pub struct DropboxSignEvent(pub EventCallbackRequest);
+
+/// Rejection returned when a request is not a valid webhook callback.
+#[derive(Error, Debug)]
+pub enum DropboxSignEventRejection {
+    #[error(transparent)]
+    Body(#[from] BytesRejection),
+
+    #[error(transparent)]
+    Parse(#[from] EventParseError),
+
+    #[error(transparent)]
+    Verification(#[from] EventVerificationError),
+}
+
+impl IntoResponse for DropboxSignEventRejection {
+    fn into_response(self) -> Response {
+        match self {
+            Self::Body(rejection) => rejection.into_response(),
+            Self::Parse(EventParseError::UnsupportedContentType(_)) => {
+                (StatusCode::UNSUPPORTED_MEDIA_TYPE, self.to_string()).into_response()
+            }
+            Self::Parse(_) => (StatusCode::BAD_REQUEST, self.to_string()).into_response(),
+            Self::Verification(_) => (StatusCode::UNAUTHORIZED, self.to_string()).into_response(),
+        }
+    }
+}
+
+impl<S> FromRequest<S> for DropboxSignEvent
+where
+    S: Send + Sync,
+    WebhookKey: FromRef<S>,
+{
+    type Rejection = DropboxSignEventRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let content_type = req
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+        let body = Bytes::from_request(req, state).await?;
+
+        let callback = parse_callback(&body, &content_type)?;
+        callback
+            .event
+            .verify(WebhookKey::from_ref(state).as_str())?;
+
+        Ok(Self(callback))
+    }
+}
+
+/// Builds the response that acknowledges a callback.
+pub fn acknowledge() -> impl IntoResponse {
+    (StatusCode::OK, CALLBACK_ACKNOWLEDGEMENT)
+}
@@ -0,0 +1,114 @@
+//! Local development server for webhook callbacks.
+//!
+//! Enabled with the `dev-server` feature. Run it behind a tunnel such as ngrok and
+//! point an account or API app callback URL at the tunnel to exercise handlers
+//! against real callbacks.
+
+use crate::events::axum::{DropboxSignEvent, DropboxSignEventRejection, acknowledge};
+use crate::events::{EventCallbackRequest, EventHandlerError, WebhookKey};
+use ::axum::Router;
+use ::axum::extract::{FromRef, State};
+use ::axum::http::StatusCode;
+use ::axum::response::{IntoResponse, Response};
+use std::future::Future;
+use std::sync::Arc;
+use tokio::net::{TcpListener, ToSocketAddrs};
+
+/// Shared state of the development server.
+struct DevServerState<F> {
+    key: WebhookKey,
+    handler: F,
+}
+
+impl<F> FromRef<Arc<DevServerState<F>>> for WebhookKey {
+    fn from_ref(state: &Arc<DevServerState<F>>) -> Self {
+        state.key.clone()
+    }
+}
+
+/// Runs a server that receives, verifies and prints callbacks on every path.
+///
+/// Each verified callback is pretty-printed to stdout and then passed to
+/// `handler`. Callbacks are acknowledged when the handler succeeds; handler errors
+/// are answered with `500 Internal Server Error` so Dropbox Sign retries them.
+///
+/// # Arguments
+///
+/// * `addr` - Address to listen on
+/// * `api_key` - API key the callbacks are signed with
+/// * `handler` - Async function invoked for every verified callback
+///
+/// # Errors
+///
+/// Returns an I/O error if the address cannot be bound or the server fails.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::events::EventCallbackRequest;
+/// use dropboxsign_client::events::dev_server::serve;
+///
+/// #[tokio::main]
+/// async fn main() -> std::io::Result<()> {
+///     serve("127.0.0.1:3000", "your-api-key", |callback: EventCallbackRequest| async move {
+///         println!("handling {}", callback.event.event_type);
+///         Ok(())
+///     })
+///     .await
+/// }
+/// ```
+pub async fn serve<A, F, Fut>(
+    addr: A,
+    api_key: impl Into<String>,
+    handler: F,
+) -> std::io::Result<()>
+where
+    A: ToSocketAddrs,
+    F: Fn(EventCallbackRequest) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), EventHandlerError>> + Send + 'static,
+{
+    let state = Arc::new(DevServerState {
+        key: WebhookKey::new(api_key),
+        handler,
+    });
+    let app = Router::new().fallback(receive::<F, Fut>).with_state(state);
+
+    let listener = TcpListener::bind(addr).await?;
+    println!(
+        "Listening for Dropbox Sign callbacks on http://{}",
+        listener.local_addr()?
+    );
+
+    ::axum::serve(listener, app).await
+}
+
+/// Handles a single callback request.
+async fn receive<F, Fut>(
+    State(state): State<Arc<DevServerState<F>>>,
+    event: Result<DropboxSignEvent, DropboxSignEventRejection>,
+) -> Response
+where
+    F: Fn(EventCallbackRequest) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), EventHandlerError>> + Send + 'static,
+{
+    let DropboxSignEvent(callback) = match event {
+        Ok(event) => event,
+        Err(rejection) => {
+            println!("Rejected callback: {rejection}");
+            return rejection.into_response();
+        }
+    };
+
+    match serde_json::to_string_pretty(&callback) {
+        Ok(json) => println!("{json}"),
+        Err(_) => println!("{callback:#?}"),
+    }
+
+    match (state.handler)(callback).await {
+        Ok(()) => acknowledge().into_response(),
+        Err(err) => {
+            println!("Callback handler failed: {err}");
+            (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response()
+        }
+    }
+}
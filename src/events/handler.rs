@@ -0,0 +1,234 @@
+//! Dispatching webhook callbacks to per-event handler methods.
+
+use crate::events::{EventCallbackRequest, EventType};
+use async_trait::async_trait;
+
+/// Error type returned by event handlers.
+pub type EventHandlerError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Callbacks for webhook events.
+///
+/// Every method has a no-op default, so implementations only override the events
+/// they care about. Use [`dispatch`] to route a parsed callback to the matching
+/// method.
+///
+/// # Examples
+///
+/// ```no_run
+/// use async_trait::async_trait;
+/// use dropboxsign_client::events::{EventCallbackRequest, EventHandler, EventHandlerError, dispatch};
+///
+/// struct Notifier;
+///
+/// #[async_trait]
+/// impl EventHandler for Notifier {
+///     async fn on_all_signed(&self, callback: &EventCallbackRequest) -> Result<(), EventHandlerError> {
+///         println!("completed at {}", callback.event.event_time);
+///         Ok(())
+///     }
+/// }
+///
+/// # async fn example(callback: EventCallbackRequest) -> Result<(), EventHandlerError> {
+/// dispatch(&Notifier, &callback).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[async_trait]
+pub trait EventHandler: Send + Sync {
+    /// Called when an account's email address is confirmed.
+    async fn on_account_confirmed(
+        &self,
+        _callback: &EventCallbackRequest,
+    ) -> Result<(), EventHandlerError> {
+        Ok(())
+    }
+
+    /// Called when a signature request is sent.
+    async fn on_signature_request_sent(
+        &self,
+        _callback: &EventCallbackRequest,
+    ) -> Result<(), EventHandlerError> {
+        Ok(())
+    }
+
+    /// Called when a signer views a signature request.
+    async fn on_signature_request_viewed(
+        &self,
+        _callback: &EventCallbackRequest,
+    ) -> Result<(), EventHandlerError> {
+        Ok(())
+    }
+
+    /// Called when a signer signs a signature request.
+    async fn on_signature_request_signed(
+        &self,
+        _callback: &EventCallbackRequest,
+    ) -> Result<(), EventHandlerError> {
+        Ok(())
+    }
+
+    /// Called when every signer has signed a signature request.
+    async fn on_all_signed(
+        &self,
+        _callback: &EventCallbackRequest,
+    ) -> Result<(), EventHandlerError> {
+        Ok(())
+    }
+
+    /// Called when the signed documents are ready to download.
+    async fn on_downloadable(
+        &self,
+        _callback: &EventCallbackRequest,
+    ) -> Result<(), EventHandlerError> {
+        Ok(())
+    }
+
+    /// Called when a signer declines a signature request.
+    async fn on_declined(&self, _callback: &EventCallbackRequest) -> Result<(), EventHandlerError> {
+        Ok(())
+    }
+
+    /// Called when a signer reassigns a signature request.
+    async fn on_reassigned(
+        &self,
+        _callback: &EventCallbackRequest,
+    ) -> Result<(), EventHandlerError> {
+        Ok(())
+    }
+
+    /// Called when a reminder is sent to a signer.
+    async fn on_reminded(&self, _callback: &EventCallbackRequest) -> Result<(), EventHandlerError> {
+        Ok(())
+    }
+
+    /// Called when an email to a signer bounces.
+    async fn on_email_bounce(
+        &self,
+        _callback: &EventCallbackRequest,
+    ) -> Result<(), EventHandlerError> {
+        Ok(())
+    }
+
+    /// Called when a signer is removed from a signature request.
+    async fn on_signer_removed(
+        &self,
+        _callback: &EventCallbackRequest,
+    ) -> Result<(), EventHandlerError> {
+        Ok(())
+    }
+
+    /// Called when a signature request is prepared for sending.
+    async fn on_prepared(&self, _callback: &EventCallbackRequest) -> Result<(), EventHandlerError> {
+        Ok(())
+    }
+
+    /// Called when a signature request is canceled.
+    async fn on_canceled(&self, _callback: &EventCallbackRequest) -> Result<(), EventHandlerError> {
+        Ok(())
+    }
+
+    /// Called when a signature request expires.
+    async fn on_expired(&self, _callback: &EventCallbackRequest) -> Result<(), EventHandlerError> {
+        Ok(())
+    }
+
+    /// Called when a signature request is deleted.
+    async fn on_destroyed(
+        &self,
+        _callback: &EventCallbackRequest,
+    ) -> Result<(), EventHandlerError> {
+        Ok(())
+    }
+
+    /// Called when a signature request could not be created.
+    async fn on_invalid(&self, _callback: &EventCallbackRequest) -> Result<(), EventHandlerError> {
+        Ok(())
+    }
+
+    /// Called when a qualified electronic signature could not be completed.
+    async fn on_incomplete_qes(
+        &self,
+        _callback: &EventCallbackRequest,
+    ) -> Result<(), EventHandlerError> {
+        Ok(())
+    }
+
+    /// Called when a template is created.
+    async fn on_template_created(
+        &self,
+        _callback: &EventCallbackRequest,
+    ) -> Result<(), EventHandlerError> {
+        Ok(())
+    }
+
+    /// Called when a template could not be created.
+    async fn on_template_error(
+        &self,
+        _callback: &EventCallbackRequest,
+    ) -> Result<(), EventHandlerError> {
+        Ok(())
+    }
+
+    /// Called for `unknown_error`, `file_error` and `sign_url_invalid` events.
+    async fn on_error(&self, _callback: &EventCallbackRequest) -> Result<(), EventHandlerError> {
+        Ok(())
+    }
+
+    /// Called for test callbacks sent while configuring a callback URL.
+    async fn on_callback_test(
+        &self,
+        _callback: &EventCallbackRequest,
+    ) -> Result<(), EventHandlerError> {
+        Ok(())
+    }
+
+    /// Called for event types this crate does not recognize.
+    async fn on_unknown(&self, _callback: &EventCallbackRequest) -> Result<(), EventHandlerError> {
+        Ok(())
+    }
+}
+
+/// Routes a callback to the handler method matching its event type.
+///
+/// # Arguments
+///
+/// * `handler` - Handler to invoke
+/// * `callback` - Parsed callback payload
+///
+/// # Errors
+///
+/// Returns the error produced by the handler method.
+pub async fn dispatch<H>(
+    handler: &H,
+    callback: &EventCallbackRequest,
+) -> Result<(), EventHandlerError>
+where
+    H: EventHandler + ?Sized,
+{
+    match callback.event.event_type {
+        EventType::AccountConfirmed => handler.on_account_confirmed(callback).await,
+        EventType::SignatureRequestSent => handler.on_signature_request_sent(callback).await,
+        EventType::SignatureRequestViewed => handler.on_signature_request_viewed(callback).await,
+        EventType::SignatureRequestSigned => handler.on_signature_request_signed(callback).await,
+        EventType::SignatureRequestAllSigned => handler.on_all_signed(callback).await,
+        EventType::SignatureRequestDownloadable => handler.on_downloadable(callback).await,
+        EventType::SignatureRequestDeclined => handler.on_declined(callback).await,
+        EventType::SignatureRequestReassigned => handler.on_reassigned(callback).await,
+        EventType::SignatureRequestRemind => handler.on_reminded(callback).await,
+        EventType::SignatureRequestEmailBounce => handler.on_email_bounce(callback).await,
+        EventType::SignatureRequestSignerRemoved => handler.on_signer_removed(callback).await,
+        EventType::SignatureRequestPrepared => handler.on_prepared(callback).await,
+        EventType::SignatureRequestCanceled => handler.on_canceled(callback).await,
+        EventType::SignatureRequestExpired => handler.on_expired(callback).await,
+        EventType::SignatureRequestDestroyed => handler.on_destroyed(callback).await,
+        EventType::SignatureRequestInvalid => handler.on_invalid(callback).await,
+        EventType::SignatureRequestIncompleteQes => handler.on_incomplete_qes(callback).await,
+        EventType::TemplateCreated => handler.on_template_created(callback).await,
+        EventType::TemplateError => handler.on_template_error(callback).await,
+        EventType::UnknownError | EventType::FileError | EventType::SignUrlInvalid => {
+            handler.on_error(callback).await
+        }
+        EventType::CallbackTest => handler.on_callback_test(callback).await,
+        EventType::Unknown(_) => handler.on_unknown(callback).await,
+    }
+}
@@ -0,0 +1,101 @@
+//! AWS Lambda integration for webhook callbacks.
+//!
+//! Enabled with the `lambda` feature. Use [`verify_request`] to turn a
+//! `lambda_http::Request` into a verified callback and [`acknowledge`] to build the
+//! response Dropbox Sign expects.
+
+use crate::events::{
+    CALLBACK_ACKNOWLEDGEMENT, EventCallbackRequest, EventParseError, EventVerificationError,
+    parse_callback,
+};
+use lambda_http::http::header::CONTENT_TYPE;
+use lambda_http::http::{Response, StatusCode};
+use lambda_http::{Body, Request};
+use thiserror::Error;
+
+/// Rejection returned when a request is not a valid webhook callback.
+#[derive(Error, Debug)]
+pub enum DropboxSignEventRejection {
+    #[error(transparent)]
+    Parse(#[from] EventParseError),
+
+    #[error(transparent)]
+    Verification(#[from] EventVerificationError),
+}
+
+impl DropboxSignEventRejection {
+    /// Returns the HTTP status code the rejection should be answered with.
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            Self::Parse(EventParseError::UnsupportedContentType(_)) => {
+                StatusCode::UNSUPPORTED_MEDIA_TYPE
+            }
+            Self::Parse(_) => StatusCode::BAD_REQUEST,
+            Self::Verification(_) => StatusCode::UNAUTHORIZED,
+        }
+    }
+
+    /// Converts the rejection into a Lambda response.
+    pub fn into_response(self) -> Response<Body> {
+        let mut response = Response::new(Body::from(self.to_string()));
+        *response.status_mut() = self.status_code();
+        response
+    }
+}
+
+/// Parses and verifies a webhook callback received through API Gateway.
+///
+/// # Arguments
+///
+/// * `request` - The incoming Lambda HTTP request
+/// * `api_key` - API key the callbacks are signed with
+///
+/// # Errors
+///
+/// Returns `DropboxSignEventRejection` if the body is not a valid callback or the
+/// event hash does not match.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::events::lambda::{acknowledge, verify_request};
+/// use lambda_http::{Body, Error, Request, Response, run, service_fn};
+///
+/// async fn handler(request: Request) -> Result<Response<Body>, Error> {
+///     let callback = match verify_request(&request, "your-api-key") {
+///         Ok(callback) => callback,
+///         Err(rejection) => return Ok(rejection.into_response()),
+///     };
+///
+///     println!("received {}", callback.event.event_type);
+///     Ok(acknowledge())
+/// }
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Error> {
+///     run(service_fn(handler)).await
+/// }
+/// ```
+pub fn verify_request(
+    request: &Request,
+    api_key: &str,
+) -> Result<EventCallbackRequest, DropboxSignEventRejection> {
+    let content_type = request
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+
+    let callback = parse_callback(request.body(), content_type)?;
+    callback.event.verify(api_key)?;
+
+    Ok(callback)
+}
+
+/// Builds the response that acknowledges a callback.
+///
+/// Dropbox Sign treats any other response as a failed delivery and retries the
+/// callback.
+pub fn acknowledge() -> Response<Body> {
+    Response::new(Body::from(CALLBACK_ACKNOWLEDGEMENT))
+}
@@ -0,0 +1,246 @@
+//! Polling-based event stream for deployments without a public callback URL.
+
+use crate::client::{DropboxSignClient, DropboxSignClientError};
+use crate::events::{Event, EventCallbackRequest, EventMetadata, EventType};
+use crate::id::{SignatureId, SignatureRequestId};
+use crate::signature_request::{SignatureRequestResponse, SignatureRequestSummary};
+use futures_util::stream::{self, Stream};
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::time::{Interval, MissedTickBehavior};
+
+/// Number of signature requests fetched per page while polling.
+const POLL_PAGE_SIZE: u64 = 100;
+
+/// Statuses of a signature request as of the last poll.
+struct SignatureRequestSnapshot {
+    is_complete: bool,
+    signatures: HashMap<SignatureId, String>,
+}
+
+/// State carried between polls.
+struct PollState {
+    client: DropboxSignClient,
+    interval: Interval,
+    snapshot: Option<HashMap<SignatureRequestId, SignatureRequestSnapshot>>,
+    pending: VecDeque<EventCallbackRequest>,
+}
+
+/// Polls signature requests and yields synthesized callbacks for status changes.
+///
+/// Every `interval` the summaries of all signature requests are listed and
+/// compared with the state seen so far. The first poll only records the current
+/// state; afterwards the stream yields `signature_request_sent` for new signature
+/// requests, `signature_request_signed` and `signature_request_declined` for
+/// signer status changes, and `signature_request_all_signed` once a request
+/// completes. Only requests that changed are fetched in full, to attach them to
+/// their events.
+///
+/// Requests missing from a poll, e.g. because a new request shifted the page
+/// boundaries while listing, keep their last known state and are compared again
+/// on the next poll they appear in.
+///
+/// Synthesized events are not signed by Dropbox Sign: their `event_hash` is empty
+/// and `event_time` is the time of the poll that noticed the change.
+///
+/// If the client carries a cancellation token (see
+/// [`RequestOptions::cancellation`](crate::client::RequestOptions::cancellation)),
+/// the stream ends as soon as the token is cancelled, including while it waits
+/// for the next poll.
+///
+/// # Arguments
+///
+/// * `client` - Client used to list signature requests
+/// * `interval` - Time between polls
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::DropboxSignClient;
+/// use dropboxsign_client::events::poll_stream;
+/// use futures_util::StreamExt;
+/// use std::time::Duration;
+///
+/// # async fn example() {
+/// let client = DropboxSignClient::new("your-api-key");
+/// let mut events = Box::pin(poll_stream(client, Duration::from_secs(60)));
+///
+/// while let Some(event) = events.next().await {
+///     match event {
+///         Ok(callback) => println!("{}", callback.event.event_type),
+///         Err(err) => eprintln!("poll failed: {err}"),
+///     }
+/// }
+/// # }
+/// ```
+pub fn poll_stream(
+    client: DropboxSignClient,
+    interval: Duration,
+) -> impl Stream<Item = Result<EventCallbackRequest, DropboxSignClientError>> {
+    let mut interval = tokio::time::interval(interval);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+    let state = PollState {
+        client,
+        interval,
+        snapshot: None,
+        pending: VecDeque::new(),
+    };
+
+    stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(callback) = state.pending.pop_front() {
+                return Some((Ok(callback), state));
+            }
+
+            let cancellation = state.client.cancellation().cloned();
+            let tick = state.interval.tick();
+            match &cancellation {
+                Some(cancellation) => {
+                    cancellation.run_until_cancelled(tick).await?;
+                }
+                None => {
+                    tick.await;
+                }
+            }
+
+            match state.poll().await {
+                Ok(()) => {}
+                Err(DropboxSignClientError::Cancelled) => return None,
+                Err(err) => return Some((Err(err), state)),
+            }
+        }
+    })
+}
+
+impl PollState {
+    /// Lists every signature request and queues events for the changes found.
+    ///
+    /// The snapshot is only updated once every changed request has been fetched,
+    /// so a failed poll is repeated in full by the next one.
+    async fn poll(&mut self) -> Result<(), DropboxSignClientError> {
+        let mut summaries = Vec::new();
+        let mut page = 1;
+        loop {
+            let (response, _) = self
+                .client
+                .list_signature_request_summaries(Some(page), Some(POLL_PAGE_SIZE))
+                .await?;
+            let next_page = response.next_page();
+            summaries.extend(response);
+
+            match next_page {
+                Some(next_page) => page = next_page,
+                None => break,
+            }
+        }
+
+        if let Some(previous) = &self.snapshot {
+            // Listed newest first, so walk backwards to queue events in order.
+            let changes: Vec<_> = summaries
+                .iter()
+                .rev()
+                .map(|summary| {
+                    (
+                        &summary.signature_request_id,
+                        diff(previous.get(&summary.signature_request_id), summary),
+                    )
+                })
+                .filter(|(_, events)| !events.is_empty())
+                .collect();
+
+            let mut pending = Vec::new();
+            for (signature_request_id, events) in changes {
+                let (signature_request, _) = self
+                    .client
+                    .get_signature_request(signature_request_id)
+                    .await?;
+                pending.extend(
+                    events
+                        .into_iter()
+                        .map(|(event_type, related_signature_id)| {
+                            callback(event_type, related_signature_id, &signature_request)
+                        }),
+                );
+            }
+            self.pending.extend(pending);
+        }
+
+        let snapshot = self.snapshot.get_or_insert_with(HashMap::new);
+        for summary in summaries {
+            snapshot.insert(
+                summary.signature_request_id,
+                SignatureRequestSnapshot {
+                    is_complete: summary.is_complete,
+                    signatures: summary
+                        .signatures
+                        .into_iter()
+                        .map(|signature| (signature.signature_id, signature.status_code))
+                        .collect(),
+                },
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the events for the differences between a snapshot and the current
+/// state, with the signature each one relates to.
+fn diff(
+    previous: Option<&SignatureRequestSnapshot>,
+    summary: &SignatureRequestSummary,
+) -> Vec<(EventType, Option<SignatureId>)> {
+    let Some(previous) = previous else {
+        return vec![(EventType::SignatureRequestSent, None)];
+    };
+
+    let mut events = Vec::new();
+    for signature in &summary.signatures {
+        if previous.signatures.get(&signature.signature_id) == Some(&signature.status_code) {
+            continue;
+        }
+
+        let event_type = match signature.status_code.as_str() {
+            "signed" => EventType::SignatureRequestSigned,
+            "declined" => EventType::SignatureRequestDeclined,
+            _ => continue,
+        };
+        events.push((event_type, Some(signature.signature_id.clone())));
+    }
+
+    if summary.is_complete && !previous.is_complete {
+        events.push((EventType::SignatureRequestAllSigned, None));
+    }
+
+    events
+}
+
+/// Builds a synthesized callback.
+fn callback(
+    event_type: EventType,
+    related_signature_id: Option<SignatureId>,
+    signature_request: &SignatureRequestResponse,
+) -> EventCallbackRequest {
+    let event_time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+
+    EventCallbackRequest {
+        event: Event {
+            event_time: event_time.to_string(),
+            event_type,
+            event_hash: String::new(),
+            event_metadata: Some(EventMetadata {
+                related_signature_id,
+                reported_for_account_id: None,
+                reported_for_app_id: None,
+                event_message: None,
+            }),
+        },
+        signature_request: Some(signature_request.clone()),
+        template: None,
+        account: None,
+    }
+}
@@ -0,0 +1,226 @@
+//! Replay protection for webhook callbacks.
+
+use crate::events::{EventCallbackRequest, EventVerificationError};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::sync::Mutex;
+
+/// Error type returned by event deduplicators.
+pub type EventDeduplicatorError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Record of the events that have been claimed for processing.
+///
+/// Events are identified by [`EventCallbackRequest::deduplication_key`]. Claiming
+/// must be atomic, so that of two concurrent deliveries of the same callback only
+/// one is processed. Implement this trait to share the record between
+/// instances, e.g. with Redis `SET NX` or an insert into a database table with a
+/// unique constraint on the key.
+#[async_trait]
+pub trait EventDeduplicator: Send + Sync {
+    /// Claims the event with this key for processing.
+    ///
+    /// Returns `false` if the event was already claimed, in which case it must
+    /// not be processed again.
+    async fn try_claim(&self, key: &str) -> Result<bool, EventDeduplicatorError>;
+
+    /// Releases a claim after the event could not be processed, so a retried
+    /// delivery is accepted.
+    async fn release(&self, key: &str) -> Result<(), EventDeduplicatorError>;
+}
+
+#[async_trait]
+impl<T: EventDeduplicator + ?Sized> EventDeduplicator for Arc<T> {
+    async fn try_claim(&self, key: &str) -> Result<bool, EventDeduplicatorError> {
+        (**self).try_claim(key).await
+    }
+
+    async fn release(&self, key: &str) -> Result<(), EventDeduplicatorError> {
+        (**self).release(key).await
+    }
+}
+
+/// Event deduplicator that keeps claimed event keys in memory.
+///
+/// Keys are forgotten once they are older than the retention period, which
+/// should be at least as long as Dropbox Sign keeps retrying a callback.
+#[derive(Debug)]
+pub struct InMemoryEventDeduplicator {
+    retention: Duration,
+    seen: Mutex<HashMap<String, Instant>>,
+}
+
+impl InMemoryEventDeduplicator {
+    /// Creates a deduplicator that remembers event keys for `retention`.
+    ///
+    /// # Arguments
+    ///
+    /// * `retention` - How long a claimed event key is remembered
+    pub fn new(retention: Duration) -> Self {
+        Self {
+            retention,
+            seen: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl EventDeduplicator for InMemoryEventDeduplicator {
+    async fn try_claim(&self, key: &str) -> Result<bool, EventDeduplicatorError> {
+        let now = Instant::now();
+        let mut seen = self.seen.lock().await;
+        seen.retain(|_, claimed| now.duration_since(*claimed) < self.retention);
+
+        Ok(seen.insert(key.to_string(), now).is_none())
+    }
+
+    async fn release(&self, key: &str) -> Result<(), EventDeduplicatorError> {
+        self.seen.lock().await.remove(key);
+        Ok(())
+    }
+}
+
+/// Checks applied to incoming callbacks on top of hash verification.
+///
+/// With a deduplicator, [`verify`](Self::verify) claims the event, so concurrent
+/// deliveries of the same callback are rejected. Call
+/// [`release`](Self::release) if handling the callback fails, so the delivery is
+/// accepted again when Dropbox Sign retries it.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::events::{
+///     EventCallbackRequest, EventHandler, InMemoryEventDeduplicator, VerificationPolicy, dispatch,
+/// };
+/// use std::sync::Arc;
+/// use std::time::Duration;
+///
+/// # async fn example(
+/// #     callback: EventCallbackRequest,
+/// #     handler: impl EventHandler,
+/// # ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+/// let policy = VerificationPolicy::new()
+///     .max_skew(Duration::from_secs(300))
+///     .deduplicator(Arc::new(InMemoryEventDeduplicator::new(Duration::from_secs(86400))));
+///
+/// policy.verify(&callback, "your-api-key").await?;
+/// if let Err(error) = dispatch(&handler, &callback).await {
+///     policy.release(&callback).await?;
+///     return Err(error);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct VerificationPolicy {
+    max_skew: Option<Duration>,
+    deduplicator: Option<Arc<dyn EventDeduplicator>>,
+}
+
+impl VerificationPolicy {
+    /// Creates a policy that only checks the event hash.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rejects events whose `event_time` differs from the current time by more
+    /// than `max_skew`.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_skew` - Largest accepted difference between event time and now
+    pub fn max_skew(mut self, max_skew: Duration) -> Self {
+        self.max_skew = Some(max_skew);
+        self
+    }
+
+    /// Rejects events that have already been processed.
+    ///
+    /// # Arguments
+    ///
+    /// * `deduplicator` - Record of claimed events
+    pub fn deduplicator(mut self, deduplicator: Arc<dyn EventDeduplicator>) -> Self {
+        self.deduplicator = Some(deduplicator);
+        self
+    }
+
+    /// Verifies a callback against the API key and the policy.
+    ///
+    /// The event is claimed last, once every other check has passed. Call
+    /// [`release`](Self::release) if it cannot be handled.
+    ///
+    /// # Arguments
+    ///
+    /// * `callback` - Callback to verify
+    /// * `api_key` - API key the callback was signed with
+    ///
+    /// # Errors
+    ///
+    /// Returns `EventVerificationError` if the hash does not match, the event is
+    /// outside the accepted time window, or the event was already claimed.
+    pub async fn verify(
+        &self,
+        callback: &EventCallbackRequest,
+        api_key: &str,
+    ) -> Result<(), EventVerificationError> {
+        let event = &callback.event;
+        event.verify(api_key)?;
+
+        if let Some(max_skew) = self.max_skew {
+            let event_time = event
+                .event_time
+                .parse::<u64>()
+                .map_err(|_| EventVerificationError::MalformedTimestamp)?;
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs();
+
+            if now.abs_diff(event_time) > max_skew.as_secs() {
+                return Err(EventVerificationError::TimestampOutOfRange);
+            }
+        }
+
+        if let Some(deduplicator) = &self.deduplicator {
+            let claimed = deduplicator
+                .try_claim(&callback.deduplication_key())
+                .await
+                .map_err(EventVerificationError::Deduplicator)?;
+
+            if !claimed {
+                return Err(EventVerificationError::Duplicate);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Releases the claim taken by [`verify`](Self::verify) after the callback
+    /// could not be handled, so a retried delivery is accepted.
+    ///
+    /// Does nothing if the policy has no deduplicator.
+    ///
+    /// # Arguments
+    ///
+    /// * `callback` - Callback whose handling failed
+    ///
+    /// # Errors
+    ///
+    /// Returns `EventVerificationError::Deduplicator` if the claim cannot be
+    /// released.
+    pub async fn release(
+        &self,
+        callback: &EventCallbackRequest,
+    ) -> Result<(), EventVerificationError> {
+        if let Some(deduplicator) = &self.deduplicator {
+            deduplicator
+                .release(&callback.deduplication_key())
+                .await
+                .map_err(EventVerificationError::Deduplicator)?;
+        }
+
+        Ok(())
+    }
+}
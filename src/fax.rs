@@ -0,0 +1,111 @@
+//! Data models and types for fax operations.
+//!
+//! This module contains the data structures needed for reading and managing
+//! faxes sent through Dropbox Fax via the API.
+
+use crate::metadata::Metadata;
+use crate::{ListItem, Page};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Complete response data for a fax.
+///
+/// Contains the fax details along with the delivery status of each transmission.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FaxResponse {
+    /// Unique identifier for this fax
+    pub fax_id: String,
+    /// Current title of the fax
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Original title of the fax
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub original_title: Option<String>,
+    /// Subject of the fax cover page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    /// Message on the fax cover page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Custom metadata key-value pairs
+    #[serde(default)]
+    pub metadata: Metadata,
+    /// Unix timestamp when the fax was created
+    pub created_at: u64,
+    /// Fax number or email address of the sender
+    pub sender: String,
+    /// Delivery status for each recipient
+    #[serde(default)]
+    pub transmissions: Vec<FaxResponseTransmission>,
+    /// URL to download the fax document
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub files_url: Option<String>,
+    /// Fields returned by the API that this crate does not model yet
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "crate::strict::extra",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    pub extra: HashMap<String, Value>,
+}
+
+/// Delivery status of a fax to a single recipient.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FaxResponseTransmission {
+    /// Fax number of the recipient
+    pub recipient: String,
+    /// Current delivery status
+    pub status_code: FaxTransmissionStatus,
+    /// Unix timestamp when the fax was delivered
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sent_at: Option<u64>,
+}
+
+/// Delivery status of a fax transmission.
+///
+/// Statuses added to the API after this crate was released deserialize as
+/// [`FaxTransmissionStatus::Unknown`] instead of failing the whole response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FaxTransmissionStatus {
+    /// The fax was delivered
+    Success,
+    /// The fax is being sent
+    Transmitting,
+    /// The fax could not be sent
+    ErrorCouldNotFax,
+    /// The fax failed for an unknown reason
+    ErrorUnknown,
+    /// The recipient line was busy
+    ErrorBusy,
+    /// The recipient did not answer
+    ErrorNoAnswer,
+    /// The call was disconnected
+    ErrorDisconnected,
+    /// The recipient number is invalid
+    ErrorBadDestination,
+    /// A status this crate does not recognize
+    #[serde(other)]
+    Unknown,
+}
+
+/// A page of faxes.
+pub type FaxesResponse = Page<FaxResponse>;
+
+impl ListItem for FaxResponse {
+    const LIST_KEY: &'static str = "faxes";
+}
+
+impl FaxTransmissionStatus {
+    /// Returns whether the transmission has finished, successfully or not.
+    pub fn is_final(&self) -> bool {
+        !matches!(self, Self::Transmitting)
+    }
+
+    /// Returns whether the transmission failed.
+    pub fn is_error(&self) -> bool {
+        !matches!(self, Self::Success | Self::Transmitting)
+    }
+}
@@ -0,0 +1,140 @@
+//! Data models and types for fax line operations.
+//!
+//! This module contains the data structures needed for purchasing and managing
+//! Dropbox Fax numbers through the API.
+
+use crate::account::AccountResponse;
+use crate::id::AccountId;
+use crate::team::TeamMemberIdentifier;
+use crate::validate::{Checks, Validate, ValidationError};
+use crate::{ListItem, Page};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Request body for purchasing a fax line.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateFaxLineRequest {
+    /// Area code of the new number
+    pub area_code: u32,
+    /// Country of the new number
+    pub country: FaxLineCountry,
+    /// City of the new number
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    /// Account the number is assigned to (defaults to the caller's account)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<AccountId>,
+}
+
+/// Request body for deleting a fax line.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeleteFaxLineRequest {
+    /// The fax number to delete
+    pub number: String,
+}
+
+/// Request body for granting or revoking an account's access to a fax line.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FaxLineUserRequest {
+    /// The fax number
+    pub number: String,
+    /// Account ID or email address of the user
+    #[serde(flatten)]
+    pub user: TeamMemberIdentifier,
+}
+
+/// Countries fax lines can be purchased in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FaxLineCountry {
+    /// Canada
+    #[serde(rename = "CA")]
+    Canada,
+    /// United States
+    #[serde(rename = "US")]
+    UnitedStates,
+    /// United Kingdom
+    #[serde(rename = "UK")]
+    UnitedKingdom,
+}
+
+/// Complete response data for a fax line.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FaxLineResponse {
+    /// The fax number
+    pub number: String,
+    /// Unix timestamp when the fax line was created
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<u64>,
+    /// Unix timestamp when the fax line was last updated
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<u64>,
+    /// Accounts that can send and receive faxes on this line
+    #[serde(default)]
+    pub accounts: Vec<AccountResponse>,
+    /// Fields returned by the API that this crate does not model yet
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "crate::strict::extra",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    pub extra: HashMap<String, Value>,
+}
+
+/// A page of fax lines.
+pub type FaxLinesResponse = Page<FaxLineResponse>;
+
+impl ListItem for FaxLineResponse {
+    const LIST_KEY: &'static str = "fax_lines";
+}
+
+/// Area codes available for purchase.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FaxLineAreaCodesResponse {
+    /// Area codes with numbers available
+    pub area_codes: Vec<u32>,
+}
+
+impl Validate for CreateFaxLineRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut checks = Checks::new();
+
+        if let Some(city) = &self.city {
+            checks.not_blank(city, "city");
+        }
+        if let Some(account_id) = &self.account_id {
+            checks.not_blank(account_id.as_str(), "account_id");
+        }
+
+        checks.finish()
+    }
+}
+
+impl Validate for DeleteFaxLineRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut checks = Checks::new();
+
+        checks.not_blank(&self.number, "number");
+
+        checks.finish()
+    }
+}
+
+impl Validate for FaxLineUserRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut checks = Checks::new();
+
+        checks.not_blank(&self.number, "number");
+        match &self.user {
+            TeamMemberIdentifier::AccountId(account_id) => {
+                checks.not_blank(account_id.as_str(), "account_id");
+            }
+            TeamMemberIdentifier::EmailAddress(email_address) => {
+                checks.email(email_address, "email_address");
+            }
+        }
+
+        checks.finish()
+    }
+}
@@ -0,0 +1,267 @@
+//! File sources for document uploads.
+//!
+//! Documents can be uploaded from memory, from a path on disk, or from any
+//! `AsyncRead`. Files on disk and readers are streamed into the request body
+//! instead of being buffered in memory, and in-memory contents are held as
+//! [`Bytes`] so the same document can be shared across many requests without
+//! copying it.
+//!
+//! Uploads can be cloned, so a request can be kept and sent again. Clones of an
+//! upload read from a reader share that reader, and only the first of them that
+//! is sent can read it.
+//!
+//! Transfers can report their [`Progress`] so large documents can be shown with
+//! a progress bar.
+
+use bytes::Bytes;
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, PoisonError};
+use tokio::io::AsyncRead;
+
+/// Progress of a file upload or download.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+    /// Number of bytes transferred so far
+    pub transferred: u64,
+    /// Total number of bytes, if known
+    pub total: Option<u64>,
+}
+
+/// Callback invoked each time more of a file has been transferred.
+pub type ProgressCallback = Arc<dyn Fn(Progress) + Send + Sync>;
+
+/// A file uploaded with a request.
+///
+/// Files are streamed while the request is sent, so a request with uploads is
+/// never resent automatically. With OAuth credentials, an expired access token
+/// makes the call fail with
+/// [`DropboxSignClientError::UploadNotResent`](crate::client::DropboxSignClientError::UploadNotResent)
+/// once the token has been refreshed; make the call again with a fresh upload.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::file::UploadFile;
+/// use dropboxsign_client::signature_request::*;
+///
+/// # fn example(signers: Vec<SubSignatureRequestSigner>, contract: Vec<u8>) {
+/// let request = SendWithFilesRequest::new(
+///     signers,
+///     SignatureRequestDocuments::files([
+///         UploadFile::from_path("contracts/purchase-agreement.pdf"),
+///         UploadFile::from_bytes(contract).file_name("addendum.pdf"),
+///     ]),
+/// );
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct UploadFile {
+    /// Name the file is uploaded as
+    pub(crate) file_name: Option<String>,
+    /// MIME type the file is uploaded as
+    pub(crate) mime_type: Option<String>,
+    /// Where the file contents are read from
+    pub(crate) source: FileSource,
+    /// Callback reporting upload progress
+    pub(crate) progress: Option<ProgressCallback>,
+}
+
+/// Where the contents of an uploaded file are read from.
+#[derive(Clone)]
+pub enum FileSource {
+    /// File contents held in memory
+    Memory(Bytes),
+    /// File on disk, streamed when the request is sent
+    Path(PathBuf),
+    /// Reader streamed when the request is sent
+    Reader {
+        /// Reader producing the file contents
+        reader: SharedReader,
+        /// Length of the contents in bytes, if known
+        length: Option<u64>,
+    },
+}
+
+impl UploadFile {
+    /// Creates an upload from file contents held in memory.
+    ///
+    /// Passing a [`Bytes`] handle shares the contents instead of copying them, so
+    /// the same document can be cheaply attached to many requests.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - File contents
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use bytes::Bytes;
+    /// use dropboxsign_client::file::UploadFile;
+    ///
+    /// let contract = Bytes::from(std::fs::read("contract.pdf").unwrap_or_default());
+    ///
+    /// // Each upload shares the same buffer.
+    /// let uploads: Vec<UploadFile> = (0..100)
+    ///     .map(|_| UploadFile::from_bytes(contract.clone()).file_name("contract.pdf"))
+    ///     .collect();
+    /// ```
+    pub fn from_bytes(data: impl Into<Bytes>) -> Self {
+        Self::from_source(FileSource::Memory(data.into()))
+    }
+
+    /// Creates an upload streamed from a file on disk.
+    ///
+    /// The file name defaults to the last component of `path`, and the file is only
+    /// opened when the request is sent.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of the file to upload
+    pub fn from_path(path: impl Into<PathBuf>) -> Self {
+        Self::from_source(FileSource::Path(path.into()))
+    }
+
+    /// Creates an upload streamed from a reader.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - Reader producing the file contents
+    /// * `length` - Length of the contents in bytes, if known
+    pub fn from_reader(
+        reader: impl AsyncRead + Send + Sync + Unpin + 'static,
+        length: Option<u64>,
+    ) -> Self {
+        Self::from_source(FileSource::Reader {
+            reader: SharedReader::new(reader),
+            length,
+        })
+    }
+
+    /// Creates an upload from a file source.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Where the file contents are read from
+    pub fn from_source(source: FileSource) -> Self {
+        Self {
+            file_name: None,
+            mime_type: None,
+            source,
+            progress: None,
+        }
+    }
+
+    /// Sets the name the file is uploaded as.
+    ///
+    /// The extension is used by the API to detect the document type.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_name` - File name including its extension
+    pub fn file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.file_name = Some(file_name.into());
+        self
+    }
+
+    /// Sets the MIME type the file is uploaded as.
+    ///
+    /// When unset, the MIME type is derived from the file name or contents.
+    ///
+    /// # Arguments
+    ///
+    /// * `mime_type` - MIME type such as `application/pdf`
+    pub fn mime_type(mut self, mime_type: impl Into<String>) -> Self {
+        self.mime_type = Some(mime_type.into());
+        self
+    }
+
+    /// Sets a callback that reports how much of the file has been uploaded.
+    ///
+    /// The callback runs on the task sending the request, so it should return
+    /// quickly.
+    ///
+    /// # Arguments
+    ///
+    /// * `callback` - Called with the upload progress after each chunk is sent
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::file::UploadFile;
+    ///
+    /// let upload = UploadFile::from_path("contracts/merged-packet.pdf").on_progress(|progress| {
+    ///     if let Some(total) = progress.total {
+    ///         println!("uploaded {}/{total} bytes", progress.transferred);
+    ///     }
+    /// });
+    /// ```
+    pub fn on_progress(mut self, callback: impl Fn(Progress) + Send + Sync + 'static) -> Self {
+        self.progress = Some(Arc::new(callback));
+        self
+    }
+}
+
+/// A reader shared by all clones of an upload.
+///
+/// A reader can only be read once, so the first clone that is sent takes it and
+/// sending any other clone afterwards fails.
+#[derive(Clone)]
+pub struct SharedReader(Arc<Mutex<Option<Box<dyn AsyncRead + Send + Sync + Unpin>>>>);
+
+impl SharedReader {
+    /// Wraps a reader so it can be shared.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - Reader producing the file contents
+    pub fn new(reader: impl AsyncRead + Send + Sync + Unpin + 'static) -> Self {
+        Self(Arc::new(Mutex::new(Some(Box::new(reader)))))
+    }
+
+    /// Takes the reader, or returns `None` if a clone already took it.
+    pub(crate) fn take(&self) -> Option<Box<dyn AsyncRead + Send + Sync + Unpin>> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner).take()
+    }
+}
+
+impl From<Vec<u8>> for UploadFile {
+    fn from(data: Vec<u8>) -> Self {
+        Self::from_bytes(data)
+    }
+}
+
+impl From<Bytes> for UploadFile {
+    fn from(data: Bytes) -> Self {
+        Self::from_bytes(data)
+    }
+}
+
+impl From<PathBuf> for UploadFile {
+    fn from(path: PathBuf) -> Self {
+        Self::from_path(path)
+    }
+}
+
+impl fmt::Debug for UploadFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UploadFile")
+            .field("file_name", &self.file_name)
+            .field("mime_type", &self.mime_type)
+            .field("source", &self.source)
+            .field("progress", &self.progress.is_some())
+            .finish()
+    }
+}
+
+impl fmt::Debug for FileSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Memory(data) => write!(f, "Memory({} bytes)", data.len()),
+            Self::Path(path) => f.debug_tuple("Path").field(path).finish(),
+            Self::Reader { length, .. } => {
+                f.debug_struct("Reader").field("length", length).finish()
+            }
+        }
+    }
+}
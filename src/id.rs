@@ -0,0 +1,140 @@
+//! Strongly-typed identifiers.
+//!
+//! Every kind of identifier the API hands out has its own type, so a template ID
+//! can't be passed where a signature request ID is expected. Identifiers
+//! serialize as plain strings and convert from `&str` and `String`, so literals
+//! can be passed with `.into()`.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use dropboxsign_client::DropboxSignClient;
+//! use dropboxsign_client::id::SignatureRequestId;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = DropboxSignClient::new("your-api-key");
+//!
+//! let id: SignatureRequestId = "fa5c8a0b0f492d768749333ad6fcc214c111e967".parse()?;
+//! let (signature_request, _) = client.get_signature_request(&id).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::borrow::Borrow;
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+/// Defines a newtype around `String` for one kind of identifier.
+macro_rules! id_type {
+    ($(#[$doc:meta])* $name:ident) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+        #[serde(transparent)]
+        pub struct $name(String);
+
+        impl $name {
+            /// Creates an identifier from its string form.
+            pub fn new(id: impl Into<String>) -> Self {
+                Self(id.into())
+            }
+
+            /// Returns the identifier as a string slice.
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// Returns the identifier as a `String`.
+            pub fn into_string(self) -> String {
+                self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Infallible;
+
+            fn from_str(id: &str) -> Result<Self, Self::Err> {
+                Ok(Self::new(id))
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(id: String) -> Self {
+                Self(id)
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(id: &str) -> Self {
+                Self::new(id)
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl Borrow<str> for $name {
+            fn borrow(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+    };
+}
+
+id_type!(
+    /// Identifier of a signature request.
+    SignatureRequestId
+);
+
+id_type!(
+    /// Identifier of a template.
+    TemplateId
+);
+
+id_type!(
+    /// Identifier of a single signature within a signature request.
+    SignatureId
+);
+
+id_type!(
+    /// Identifier of an account.
+    AccountId
+);
+
+id_type!(
+    /// Identifier of a bulk send job.
+    BulkSendJobId
+);
+
+id_type!(
+    /// Client ID of an API app.
+    ClientId
+);
@@ -13,10 +13,17 @@
 //! - Proper handling of API warnings and errors
 //! - Builder patterns for complex request construction
 //!
+//! ## TLS backends
+//!
+//! HTTPS is provided by `rustls` by default, which needs no system libraries and
+//! works for static musl builds. Enable the `native-tls` feature (with
+//! `default-features = false` to drop `rustls`) to use the platform's TLS library
+//! instead.
+//!
 //! ## Example
 //!
 //! ```no_run
-//! use dropboxsign_rs::{DropboxSignClient, signature_request::*};
+//! use dropboxsign_client::{DropboxSignClient, signature_request::*};
 //!
 //! #[tokio::main]
 //! async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -28,7 +35,7 @@
 //!         "john@example.com".to_string()
 //!     );
 //!
-//!     let request = SendSignatureRequest::new(
+//!     let request = SendWithTemplateRequest::new(
 //!         vec![signer],
 //!         vec!["template-id".to_string()]
 //!     );
@@ -46,38 +53,255 @@
 #![deny(clippy::unwrap_used)]
 #![deny(clippy::expect_used)]
 #![deny(clippy::panic)]
+use client::RateLimitStatus;
 use reqwest::StatusCode;
-use serde::{Deserialize, Serialize};
+use serde::de::{self, DeserializeOwned, IgnoredAny, MapAccess, Visitor};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+/// Data models and types for account operations
+pub mod account;
+
+/// Data models and types for API app operations
+pub mod api_app;
+
+/// Concurrent batch operations with bounded parallelism
+pub mod batch;
+
+/// Data models and types for bulk send jobs
+pub mod bulk_send_job;
 
 /// HTTP client implementation for Dropbox Sign API
 pub mod client;
 
+/// Data models and types for embedded signing and template editing
+pub mod embedded;
+
+/// Data models and types for webhook callback events
+pub mod events;
+
+/// Data models and types for fax operations
+pub mod fax;
+
+/// Data models and types for fax line operations
+pub mod fax_line;
+
+/// File sources for document uploads
+pub mod file;
+
+/// Strongly-typed identifiers for API resources
+pub mod id;
+
+/// Typed metadata values and their limits
+pub mod metadata;
+
+/// OAuth token exchange for API apps
+pub mod oauth;
+
+/// Data models and types for report operations
+pub mod report;
+
 /// Data models and types for signature request operations
 pub mod signature_request;
 
+mod strict;
+
+/// Data models and types for team operations
+pub mod team;
+
+/// Data models and types for template operations
+pub mod template;
+
+/// Fixtures and a mock server for testing code built on this client
+#[cfg(feature = "test-util")]
+pub mod test_util;
+
+/// Data models and types for unclaimed draft operations
+pub mod unclaimed_draft;
+
+/// Local validation of requests before they are sent
+pub mod validate;
+
 // Re-export the main types for convenience
-pub use client::DropboxSignClient;
+pub use client::{DropboxSignClient, DropboxSignClientBuilder};
 
 /// Generic wrapper for API responses that may contain warnings alongside the main data.
 ///
 /// The `inner` field contains the actual response data, while `warnings` contains
 /// any non-fatal warnings returned by the API.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ResponseWithWarnings<T> {
     /// The main response data
     #[serde(flatten)]
     pub inner: T,
     /// Optional warnings returned by the API
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub warnings: Option<Vec<WarningResponse>>,
 }
 
+/// Pagination information returned alongside list responses.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListInfoResponse {
+    /// Total number of pages available
+    pub num_pages: u64,
+    /// Total number of results across all pages
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_results: Option<u64>,
+    /// Page number of this result set (1-based)
+    pub page: u64,
+    /// Number of results per page
+    pub page_size: u64,
+}
+
+/// An item type returned by a list endpoint.
+///
+/// Each list endpoint holds the items of a [`Page`] under its own key, e.g.
+/// `signature_requests` or `api_apps`.
+pub trait ListItem {
+    /// Key the items are held under in a page of the list response
+    const LIST_KEY: &'static str;
+}
+
+/// A page of items returned by a list endpoint.
+///
+/// Every list endpoint returns a `Page` of its item type along with the
+/// pagination information, so pages can be walked the same way everywhere.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::DropboxSignClient;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = DropboxSignClient::new("your-api-key");
+///
+/// let mut page_number = Some(1);
+/// while let Some(page) = page_number {
+///     let (api_apps, _) = client.list_api_apps(Some(page), Some(100)).await?;
+///     for api_app in &api_apps {
+///         println!("{}", api_app.name);
+///     }
+///     page_number = api_apps.next_page();
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    /// Items on this page
+    pub items: Vec<T>,
+    /// Pagination information for the list
+    pub list_info: ListInfoResponse,
+}
+
+impl<T> Page<T> {
+    /// Returns the number of the page after this one, or `None` on the last page.
+    pub fn next_page(&self) -> Option<u64> {
+        (self.list_info.page < self.list_info.num_pages).then_some(self.list_info.page + 1)
+    }
+
+    /// Returns whether this is the last page of the list.
+    pub fn is_last_page(&self) -> bool {
+        self.next_page().is_none()
+    }
+
+    /// Returns an iterator over the items on this page.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.items.iter()
+    }
+}
+
+impl<T> IntoIterator for Page<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Page<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+impl<T: ListItem + Serialize> Serialize for Page<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(2))?;
+        map.serialize_entry(T::LIST_KEY, &self.items)?;
+        map.serialize_entry("list_info", &self.list_info)?;
+        map.end()
+    }
+}
+
+impl<'de, T: ListItem + DeserializeOwned> Deserialize<'de> for Page<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (page, _) = deserializer.deserialize_map(PageVisitor(PhantomData))?;
+        Ok(page)
+    }
+}
+
+/// A page of items read straight from a list response body, along with its
+/// warnings.
+///
+/// Unlike flattening a [`Page`] into [`ResponseWithWarnings`], no intermediate
+/// copy of the JSON is built, so fields the item type skips are never turned
+/// into values. The body text itself is still held in full while parsing.
+pub(crate) struct PageWithWarnings<T>(pub Page<T>, pub Option<Vec<WarningResponse>>);
+
+impl<'de, T: ListItem + DeserializeOwned> Deserialize<'de> for PageWithWarnings<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (page, warnings) = deserializer.deserialize_map(PageVisitor(PhantomData))?;
+        Ok(Self(page, warnings))
+    }
+}
+
+/// Reads the items, pagination information and warnings of a page.
+struct PageVisitor<T>(PhantomData<T>);
+
+impl<'de, T: ListItem + DeserializeOwned> Visitor<'de> for PageVisitor<T> {
+    type Value = (Page<T>, Option<Vec<WarningResponse>>);
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a page of {}", T::LIST_KEY)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut items = None;
+        let mut list_info = None;
+        let mut warnings = None;
+        while let Some(key) = map.next_key::<String>()? {
+            if key == T::LIST_KEY {
+                items = Some(map.next_value()?);
+            } else if key == "list_info" {
+                list_info = Some(map.next_value()?);
+            } else if key == "warnings" {
+                warnings = map.next_value()?;
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+
+        let page = Page {
+            items: items.ok_or_else(|| de::Error::missing_field(T::LIST_KEY))?,
+            list_info: list_info.ok_or_else(|| de::Error::missing_field("list_info"))?,
+        };
+        Ok((page, warnings))
+    }
+}
+
 /// Represents a non-fatal warning returned by the Dropbox Sign API.
 ///
 /// Warnings indicate potential issues or important information that doesn't
 /// prevent the operation from completing successfully.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WarningResponse {
     /// Human-readable warning message
     warning_msg: String,
@@ -86,7 +310,7 @@ pub struct WarningResponse {
 }
 
 /// Top-level error response structure from the Dropbox Sign API.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ErrorResponse {
     /// The detailed error information
     pub error: ErrorResponseError,
@@ -96,7 +320,9 @@ pub struct ErrorResponse {
 ///
 /// Contains structured error details including HTTP status codes,
 /// error messages, and optional path information for field-specific errors.
-#[derive(Debug, Serialize, Deserialize)]
+/// The client also records the endpoint that failed and the rate limit and retry
+/// hints of the response, which are not part of the error body.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ErrorResponseError {
     /// HTTP status code (not serialized, set by client)
     #[serde(skip)]
@@ -107,6 +333,246 @@ pub struct ErrorResponseError {
     pub error_path: Option<String>,
     /// Machine-readable error identifier
     pub error_name: String,
+    /// Path of the endpoint that failed, e.g. `/v3/signature_request/send`
+    /// (not serialized, set by client)
+    #[serde(skip)]
+    pub endpoint: Option<String>,
+    /// Rate limit state reported by the response (not serialized, set by client)
+    #[serde(skip)]
+    pub rate_limit: Option<RateLimitStatus>,
+    /// Delay the API asked for before retrying (not serialized, set by client)
+    #[serde(skip)]
+    pub retry_after: Option<Duration>,
+}
+
+/// Categories of errors returned by the Dropbox Sign API.
+///
+/// Parsed from [`ErrorResponseError::error_name`]. Error names that this crate
+/// does not know about yet are kept in [`DropboxSignErrorKind::Unknown`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DropboxSignErrorKind {
+    /// The request was malformed or had invalid parameters
+    BadRequest,
+    /// The credentials are missing or invalid
+    Unauthorized,
+    /// The account needs to upgrade its plan
+    PaymentRequired,
+    /// The call requires a paid API plan
+    PaidApiPlanRequired,
+    /// The call requires a paid Dropbox Sign plan
+    PaidHsPlanRequired,
+    /// The credentials are not allowed to perform the call
+    Forbidden,
+    /// The requested resource does not exist
+    NotFound,
+    /// The request conflicts with the current state of the resource
+    Conflict,
+    /// The resource was deleted
+    Deleted,
+    /// Too many requests were sent
+    ExceededRate,
+    /// A team invitation could not be sent
+    TeamInviteFailed,
+    /// A recipient email address is invalid
+    InvalidRecipient,
+    /// A reminder could not be sent
+    InvalidReminder,
+    /// The signature request could not be cancelled
+    SignatureRequestCancelFailed,
+    /// The caller could not be removed from the signature request
+    SignatureRequestRemoveFailed,
+    /// The HTTP method is not supported by the endpoint
+    MethodNotSupported,
+    /// The API is down for maintenance
+    Maintenance,
+    /// An unknown error occurred on the API side
+    UnknownError,
+    /// An error name this crate does not know about
+    Unknown(String),
+}
+
+impl DropboxSignErrorKind {
+    /// Returns the error name as it appears in API responses.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::BadRequest => "bad_request",
+            Self::Unauthorized => "unauthorized",
+            Self::PaymentRequired => "payment_required",
+            Self::PaidApiPlanRequired => "paid_api_plan_required",
+            Self::PaidHsPlanRequired => "paid_hs_plan_required",
+            Self::Forbidden => "forbidden",
+            Self::NotFound => "not_found",
+            Self::Conflict => "conflict",
+            Self::Deleted => "deleted",
+            Self::ExceededRate => "exceeded_rate",
+            Self::TeamInviteFailed => "team_invite_failed",
+            Self::InvalidRecipient => "invalid_recipient",
+            Self::InvalidReminder => "invalid_reminder",
+            Self::SignatureRequestCancelFailed => "signature_request_cancel_failed",
+            Self::SignatureRequestRemoveFailed => "signature_request_remove_failed",
+            Self::MethodNotSupported => "method_not_supported",
+            Self::Maintenance => "maintenance",
+            Self::UnknownError => "unknown",
+            Self::Unknown(error_name) => error_name,
+        }
+    }
+}
+
+impl From<&str> for DropboxSignErrorKind {
+    fn from(error_name: &str) -> Self {
+        match error_name {
+            "bad_request" => Self::BadRequest,
+            "unauthorized" => Self::Unauthorized,
+            "payment_required" => Self::PaymentRequired,
+            "paid_api_plan_required" => Self::PaidApiPlanRequired,
+            "paid_hs_plan_required" => Self::PaidHsPlanRequired,
+            "forbidden" => Self::Forbidden,
+            "not_found" => Self::NotFound,
+            "conflict" => Self::Conflict,
+            "deleted" => Self::Deleted,
+            "exceeded_rate" => Self::ExceededRate,
+            "team_invite_failed" => Self::TeamInviteFailed,
+            "invalid_recipient" => Self::InvalidRecipient,
+            "invalid_reminder" => Self::InvalidReminder,
+            "signature_request_cancel_failed" => Self::SignatureRequestCancelFailed,
+            "signature_request_remove_failed" => Self::SignatureRequestRemoveFailed,
+            "method_not_supported" => Self::MethodNotSupported,
+            "maintenance" => Self::Maintenance,
+            "unknown" => Self::UnknownError,
+            other => Self::Unknown(other.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for DropboxSignErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl ErrorResponseError {
+    /// Returns the category of the error, parsed from its error name.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::{DropboxSignClient, DropboxSignErrorKind};
+    /// use dropboxsign_client::client::DropboxSignClientError;
+    ///
+    /// # async fn example() {
+    /// let client = DropboxSignClient::new("your-api-key");
+    ///
+    /// match client.get_signature_request(&"signature-request-id".into()).await {
+    ///     Ok((request, _)) => println!("{}", request.signature_request_id),
+    ///     Err(DropboxSignClientError::ResponseError(error))
+    ///         if error.kind() == DropboxSignErrorKind::NotFound =>
+    ///     {
+    ///         println!("no such signature request");
+    ///     }
+    ///     Err(error) => eprintln!("{error}"),
+    /// }
+    /// # }
+    /// ```
+    pub fn kind(&self) -> DropboxSignErrorKind {
+        DropboxSignErrorKind::from(self.error_name.as_str())
+    }
+
+    /// Returns the field that caused the error as a JSON Pointer into the request
+    /// body, e.g. `/signers/0/email_address` for `signers[0][email_address]`.
+    ///
+    /// Returns `None` when the error is not about a specific field.
+    pub fn field_pointer(&self) -> Option<String> {
+        let path = self.error_path.as_deref().filter(|path| !path.is_empty())?;
+
+        Some(
+            path.split(['[', ']'])
+                .filter(|segment| !segment.is_empty())
+                .map(|segment| format!("/{}", segment.replace('~', "~0").replace('/', "~1")))
+                .collect(),
+        )
+    }
+}
+
+impl WarningResponse {
+    /// Returns the human-readable warning message.
+    pub fn warning_msg(&self) -> &str {
+        &self.warning_msg
+    }
+
+    /// Returns the machine-readable warning name.
+    pub fn warning_name(&self) -> &str {
+        &self.warning_name
+    }
+
+    /// Returns the category of the warning, parsed from its warning name.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::{DropboxSignClient, WarningKind};
+    /// use dropboxsign_client::signature_request::SendWithTemplateRequest;
+    ///
+    /// # async fn example(request: SendWithTemplateRequest) -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = DropboxSignClient::new("your-api-key");
+    /// let (_, warnings) = client.send_with_template(request).await?;
+    ///
+    /// for warning in warnings.unwrap_or_default() {
+    ///     match warning.kind() {
+    ///         WarningKind::ParameterIgnored => eprintln!("ignored: {}", warning.warning_msg()),
+    ///         _ => println!("{warning}"),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn kind(&self) -> WarningKind {
+        WarningKind::from(self.warning_name.as_str())
+    }
+}
+
+/// Categories of warnings returned by the Dropbox Sign API.
+///
+/// Parsed from [`WarningResponse::warning_name`]. Warning names that this crate
+/// does not know about yet are kept in [`WarningKind::Unknown`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum WarningKind {
+    /// A request parameter was ignored
+    ParameterIgnored,
+    /// The request was processed in test mode
+    TestMode,
+    /// An attachment of the request could not be processed as given
+    AttachmentIssue,
+    /// A warning name this crate does not know about
+    Unknown(String),
+}
+
+impl WarningKind {
+    /// Returns the warning name as it appears in API responses.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::ParameterIgnored => "parameter_ignored",
+            Self::TestMode => "test_mode",
+            Self::AttachmentIssue => "attachment_issue",
+            Self::Unknown(warning_name) => warning_name,
+        }
+    }
+}
+
+impl From<&str> for WarningKind {
+    fn from(warning_name: &str) -> Self {
+        match warning_name {
+            "parameter_ignored" => Self::ParameterIgnored,
+            "test_mode" => Self::TestMode,
+            "attachment_issue" => Self::AttachmentIssue,
+            other => Self::Unknown(other.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for WarningKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
 }
 
 impl fmt::Display for WarningResponse {
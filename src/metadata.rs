@@ -0,0 +1,212 @@
+//! Metadata attached to signature requests and drafts.
+//!
+//! Metadata is returned in every response and event involving the signature
+//! request, so it can carry identifiers from the caller's own system. Values are
+//! usually strings, integers or booleans; anything else the API returns, such as
+//! floats, nulls or nested objects, is kept as [`MetadataValue::Other`] so the
+//! response still deserializes. The API limits metadata to
+//! [`MAX_KEYS`] keys, with keys of up to [`MAX_KEY_LENGTH`] characters and values
+//! of up to [`MAX_VALUE_LENGTH`] characters; requests exceeding the limits fail
+//! validation before they are sent.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use dropboxsign_client::metadata::MetadataValue;
+//! use dropboxsign_client::signature_request::*;
+//!
+//! # fn example(signer: SubSignatureRequestTemplateSigner) {
+//! let request = SendWithTemplateRequest::for_signer(signer, "template-id").metadata([
+//!     ("customer_id", MetadataValue::from("cus_1234")),
+//!     ("order_number", MetadataValue::from(1042)),
+//!     ("renewal", MetadataValue::from(true)),
+//! ]);
+//! # }
+//! ```
+
+use crate::validate::Checks;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Maximum number of metadata keys on a request.
+pub const MAX_KEYS: usize = 10;
+
+/// Maximum length of a metadata key, in characters.
+pub const MAX_KEY_LENGTH: usize = 40;
+
+/// Maximum length of a metadata value, in characters.
+pub const MAX_VALUE_LENGTH: usize = 1000;
+
+/// Metadata entries keyed by name.
+pub type Metadata = HashMap<String, MetadataValue>;
+
+/// A single metadata value.
+///
+/// # Examples
+///
+/// ```
+/// use dropboxsign_client::metadata::{Metadata, MetadataValue};
+///
+/// let metadata: Metadata = serde_json::from_str(
+///     r#"{"customer_id": "cus_1234", "score": 4.5, "address": {"city": "Berlin"}, "note": null}"#,
+/// )?;
+/// assert_eq!(metadata["customer_id"].as_str(), Some("cus_1234"));
+/// assert_eq!(metadata["score"], MetadataValue::Other(serde_json::json!(4.5)));
+/// assert_eq!(
+///     metadata["address"].as_value()["city"],
+///     serde_json::json!("Berlin")
+/// );
+/// assert!(metadata["note"].as_value().is_null());
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MetadataValue {
+    /// Text value
+    String(String),
+    /// Integer value
+    Integer(i64),
+    /// Boolean value
+    Bool(bool),
+    /// Any other JSON value, e.g. a float, null or nested object
+    Other(Value),
+}
+
+impl MetadataValue {
+    /// Returns the value if it is a string.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if it is an integer.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Integer(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if it is a boolean.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as JSON.
+    pub fn as_value(&self) -> Value {
+        match self {
+            Self::String(value) => Value::from(value.as_str()),
+            Self::Integer(value) => Value::from(*value),
+            Self::Bool(value) => Value::from(*value),
+            Self::Other(value) => value.clone(),
+        }
+    }
+}
+
+impl fmt::Display for MetadataValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::String(value) => f.write_str(value),
+            Self::Integer(value) => write!(f, "{value}"),
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Other(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl From<String> for MetadataValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&str> for MetadataValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<i64> for MetadataValue {
+    fn from(value: i64) -> Self {
+        Self::Integer(value)
+    }
+}
+
+impl From<i32> for MetadataValue {
+    fn from(value: i32) -> Self {
+        Self::Integer(value.into())
+    }
+}
+
+impl From<u32> for MetadataValue {
+    fn from(value: u32) -> Self {
+        Self::Integer(value.into())
+    }
+}
+
+impl From<bool> for MetadataValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<Value> for MetadataValue {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::String(value) => Self::String(value),
+            Value::Bool(value) => Self::Bool(value),
+            Value::Number(number) => match number.as_i64() {
+                Some(value) => Self::Integer(value),
+                None => Self::Other(Value::Number(number)),
+            },
+            value => Self::Other(value),
+        }
+    }
+}
+
+/// Collects metadata entries, converting their keys and values.
+pub(crate) fn collect<K, V>(metadata: impl IntoIterator<Item = (K, V)>) -> Metadata
+where
+    K: Into<String>,
+    V: Into<MetadataValue>,
+{
+    metadata
+        .into_iter()
+        .map(|(key, value)| (key.into(), value.into()))
+        .collect()
+}
+
+/// Checks metadata against the limits of the API.
+pub(crate) fn check(checks: &mut Checks, metadata: Option<&Metadata>) {
+    let Some(metadata) = metadata else {
+        return;
+    };
+
+    checks.check(
+        metadata.len() <= MAX_KEYS,
+        "metadata",
+        &format!("must not have more than {MAX_KEYS} keys"),
+    );
+
+    let mut keys: Vec<_> = metadata.keys().collect();
+    keys.sort_unstable();
+    for key in keys {
+        checks.check(
+            key.chars().count() <= MAX_KEY_LENGTH,
+            format!("metadata[{key}]"),
+            &format!("key must not be longer than {MAX_KEY_LENGTH} characters"),
+        );
+        checks.check(
+            metadata[key].to_string().chars().count() <= MAX_VALUE_LENGTH,
+            format!("metadata[{key}]"),
+            &format!("must not be longer than {MAX_VALUE_LENGTH} characters"),
+        );
+    }
+}
@@ -0,0 +1,487 @@
+//! OAuth support for API apps acting on behalf of Dropbox Sign users.
+//!
+//! This module contains the client and data structures for exchanging OAuth
+//! authorization codes for access tokens, along with the token storage used by
+//! [`DropboxSignClient::new_with_oauth`](crate::DropboxSignClient::new_with_oauth)
+//! to refresh expired tokens.
+
+use crate::DropboxSignClient;
+use crate::client::{DropboxSignClientError, check_status};
+use crate::validate::{Checks, Validate, ValidationError};
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::{Mutex, RwLock};
+use tokio::task::JoinHandle;
+
+/// URL of the Dropbox Sign OAuth token endpoint
+const OAUTH_TOKEN_URL: &str = "https://app.hellosign.com/oauth/token";
+
+/// How long before expiry a token is proactively refreshed
+const REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+/// Delay before the background refresh task retries after a failure
+const REFRESH_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+/// HTTP client for the Dropbox Sign OAuth token endpoint.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::oauth::OAuthClient;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let oauth = OAuthClient::new();
+/// let token = oauth
+///     .exchange_code("client-id", "client-secret", "code", "state")
+///     .await?;
+///
+/// println!("Access token expires in {}s", token.expires_in);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct OAuthClient {
+    /// HTTP client for making requests
+    client: Client,
+}
+
+/// Request body for exchanging an authorization code for tokens.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OAuthTokenGenerateRequest {
+    /// Client ID of the API app
+    pub client_id: String,
+    /// Client secret of the API app
+    pub client_secret: String,
+    /// Authorization code returned to the OAuth callback URL
+    pub code: String,
+    /// Grant type, always `authorization_code`
+    pub grant_type: String,
+    /// State value returned to the OAuth callback URL
+    pub state: String,
+}
+
+/// Request body for refreshing an access token.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OAuthTokenRefreshRequest {
+    /// Client ID of the API app
+    pub client_id: String,
+    /// Client secret of the API app
+    pub client_secret: String,
+    /// Grant type, always `refresh_token`
+    pub grant_type: String,
+    /// Refresh token issued with the expired access token
+    pub refresh_token: String,
+}
+
+/// Tokens issued by the Dropbox Sign OAuth token endpoint.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OAuthTokenResponse {
+    /// Access token for calling the API on behalf of the user
+    pub access_token: String,
+    /// Type of the access token (usually "Bearer")
+    pub token_type: String,
+    /// Token used to obtain a new access token once it expires
+    pub refresh_token: String,
+    /// Number of seconds until the access token expires
+    pub expires_in: u64,
+    /// State value passed through the OAuth flow
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /// Unix timestamp when the token was issued (set by this crate, not the API)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issued_at: Option<u64>,
+}
+
+impl OAuthTokenResponse {
+    /// Returns when the access token expires, if the issue time is known.
+    pub fn expires_at(&self) -> Option<SystemTime> {
+        self.issued_at.map(|issued_at| {
+            UNIX_EPOCH + Duration::from_secs(issued_at.saturating_add(self.expires_in))
+        })
+    }
+
+    /// Returns whether the access token has expired.
+    ///
+    /// Tokens with an unknown issue time are assumed to still be valid.
+    pub fn is_expired(&self) -> bool {
+        self.expires_within(Duration::ZERO)
+    }
+
+    /// Returns whether the access token expires within `window` from now.
+    ///
+    /// Tokens with an unknown issue time are assumed to still be valid.
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - How far ahead to look for the expiry
+    pub fn expires_within(&self, window: Duration) -> bool {
+        self.expires_at()
+            .is_some_and(|expires_at| expires_at <= SystemTime::now() + window)
+    }
+
+    /// Stamps the token with the current time as its issue time.
+    fn issued_now(mut self) -> Self {
+        self.issued_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .ok()
+            .map(|since_epoch| since_epoch.as_secs());
+        self
+    }
+}
+
+impl Validate for OAuthTokenGenerateRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut checks = Checks::new();
+
+        checks.not_blank(&self.client_id, "client_id");
+        checks.not_blank(&self.client_secret, "client_secret");
+        checks.not_blank(&self.code, "code");
+        checks.not_blank(&self.state, "state");
+
+        checks.finish()
+    }
+}
+
+impl Validate for OAuthTokenRefreshRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut checks = Checks::new();
+
+        checks.not_blank(&self.client_id, "client_id");
+        checks.not_blank(&self.client_secret, "client_secret");
+        checks.not_blank(&self.refresh_token, "refresh_token");
+
+        checks.finish()
+    }
+}
+
+impl OAuthClient {
+    /// Creates a new OAuth client.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an OAuth client that sends requests with `client`.
+    ///
+    /// Use this to apply the same proxy, timeouts and headers as the API client.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - HTTP client used to send requests
+    pub fn from_client(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// Exchanges an authorization code for an access token and refresh token.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - Client ID of the API app
+    /// * `client_secret` - Client secret of the API app
+    /// * `code` - Authorization code returned to the OAuth callback URL
+    /// * `state` - State value returned to the OAuth callback URL
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response (e.g., the code has expired)
+    /// - The response cannot be parsed
+    pub async fn exchange_code(
+        &self,
+        client_id: &str,
+        client_secret: &str,
+        code: &str,
+        state: &str,
+    ) -> Result<OAuthTokenResponse, DropboxSignClientError> {
+        let token_request = OAuthTokenGenerateRequest {
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+            code: code.to_string(),
+            grant_type: "authorization_code".to_string(),
+            state: state.to_string(),
+        };
+        token_request.validate()?;
+
+        let response = self
+            .client
+            .post(OAUTH_TOKEN_URL)
+            .json(&token_request)
+            .send()
+            .await?;
+        let response = check_status(response).await?;
+        let body = response.text().await?;
+        let token: OAuthTokenResponse = serde_json::from_str(&body)?;
+
+        Ok(token.issued_now())
+    }
+
+    /// Exchanges a refresh token for a new access token.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - Client ID of the API app
+    /// * `client_secret` - Client secret of the API app
+    /// * `refresh_token` - Refresh token issued with the expired access token
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientError` if:
+    /// - The HTTP request fails
+    /// - The API returns an error response (e.g., the refresh token was revoked)
+    /// - The response cannot be parsed
+    pub async fn refresh_token(
+        &self,
+        client_id: &str,
+        client_secret: &str,
+        refresh_token: &str,
+    ) -> Result<OAuthTokenResponse, DropboxSignClientError> {
+        let refresh_request = OAuthTokenRefreshRequest {
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+            grant_type: "refresh_token".to_string(),
+            refresh_token: refresh_token.to_string(),
+        };
+        refresh_request.validate()?;
+
+        let response = self
+            .client
+            .post(format!("{OAUTH_TOKEN_URL}?refresh"))
+            .json(&refresh_request)
+            .send()
+            .await?;
+        let response = check_status(response).await?;
+        let body = response.text().await?;
+        let token: OAuthTokenResponse = serde_json::from_str(&body)?;
+
+        Ok(token.issued_now())
+    }
+}
+
+/// Error type returned by [`TokenStore`] implementations.
+pub type TokenStoreError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Storage for the OAuth token used by an OAuth-authenticated client.
+///
+/// Implement this to keep tokens in a database or cache shared between
+/// processes; [`InMemoryTokenStore`] covers the single-process case.
+#[async_trait]
+pub trait TokenStore: Send + Sync {
+    /// Returns the current token, if one has been stored.
+    async fn get_token(&self) -> Result<Option<OAuthTokenResponse>, TokenStoreError>;
+
+    /// Replaces the current token.
+    async fn set_token(&self, token: OAuthTokenResponse) -> Result<(), TokenStoreError>;
+}
+
+#[async_trait]
+impl<T: TokenStore + ?Sized> TokenStore for Arc<T> {
+    async fn get_token(&self) -> Result<Option<OAuthTokenResponse>, TokenStoreError> {
+        (**self).get_token().await
+    }
+
+    async fn set_token(&self, token: OAuthTokenResponse) -> Result<(), TokenStoreError> {
+        (**self).set_token(token).await
+    }
+}
+
+/// A [`TokenStore`] that keeps the token in memory.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::{DropboxSignClient, oauth::*};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let token = OAuthClient::new()
+///     .exchange_code("client-id", "client-secret", "code", "state")
+///     .await?;
+///
+/// let client = DropboxSignClient::new_with_oauth(
+///     "client-id",
+///     "client-secret",
+///     InMemoryTokenStore::new(token),
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct InMemoryTokenStore {
+    /// The stored token
+    token: RwLock<Option<OAuthTokenResponse>>,
+}
+
+impl InMemoryTokenStore {
+    /// Creates a store holding the given token.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - The initial token
+    pub fn new(token: OAuthTokenResponse) -> Self {
+        Self {
+            token: RwLock::new(Some(token)),
+        }
+    }
+}
+
+#[async_trait]
+impl TokenStore for InMemoryTokenStore {
+    async fn get_token(&self) -> Result<Option<OAuthTokenResponse>, TokenStoreError> {
+        Ok(self.token.read().await.clone())
+    }
+
+    async fn set_token(&self, token: OAuthTokenResponse) -> Result<(), TokenStoreError> {
+        *self.token.write().await = Some(token);
+        Ok(())
+    }
+}
+
+/// Supplies bearer tokens to the client and refreshes them when they are rejected.
+pub(crate) struct OAuthTokenRefresher {
+    /// Client ID of the API app
+    client_id: String,
+    /// Client secret of the API app
+    client_secret: String,
+    /// Storage holding the current token
+    store: Box<dyn TokenStore>,
+    /// Client for the OAuth token endpoint
+    oauth: OAuthClient,
+    /// Serializes refreshes so concurrent 401s trigger a single refresh
+    refresh_lock: Mutex<()>,
+}
+
+impl OAuthTokenRefresher {
+    pub(crate) fn new(
+        client_id: String,
+        client_secret: String,
+        store: Box<dyn TokenStore>,
+        oauth: OAuthClient,
+    ) -> Self {
+        Self {
+            client_id,
+            client_secret,
+            store,
+            oauth,
+            refresh_lock: Mutex::new(()),
+        }
+    }
+
+    /// Returns the current access token, refreshing it first if it is about to expire.
+    pub(crate) async fn access_token(&self) -> Result<String, DropboxSignClientError> {
+        let token = self
+            .store
+            .get_token()
+            .await?
+            .ok_or(DropboxSignClientError::MissingOAuthToken)?;
+
+        if token.expires_within(REFRESH_MARGIN) {
+            return self.refresh(&token.access_token).await;
+        }
+
+        Ok(token.access_token)
+    }
+
+    /// Refreshes the token after `rejected` was refused by the API and returns the
+    /// new access token.
+    ///
+    /// If another task already replaced the rejected token, its token is reused
+    /// instead of refreshing a second time.
+    pub(crate) async fn refresh(&self, rejected: &str) -> Result<String, DropboxSignClientError> {
+        let _guard = self.refresh_lock.lock().await;
+
+        let token = self
+            .store
+            .get_token()
+            .await?
+            .ok_or(DropboxSignClientError::MissingOAuthToken)?;
+        if token.access_token != rejected {
+            return Ok(token.access_token);
+        }
+
+        let refreshed = self
+            .oauth
+            .refresh_token(&self.client_id, &self.client_secret, &token.refresh_token)
+            .await?;
+        let access_token = refreshed.access_token.clone();
+        self.store.set_token(refreshed).await?;
+
+        Ok(access_token)
+    }
+
+    /// Refreshes the stored token if it expires within `margin` and returns the
+    /// expiry of the token now in the store.
+    async fn refresh_if_expiring(
+        &self,
+        margin: Duration,
+    ) -> Result<Option<SystemTime>, DropboxSignClientError> {
+        let _guard = self.refresh_lock.lock().await;
+
+        let Some(token) = self.store.get_token().await? else {
+            return Ok(None);
+        };
+        if !token.expires_within(margin) {
+            return Ok(token.expires_at());
+        }
+
+        let refreshed = self
+            .oauth
+            .refresh_token(&self.client_id, &self.client_secret, &token.refresh_token)
+            .await?;
+        let expires_at = refreshed.expires_at();
+        self.store.set_token(refreshed).await?;
+
+        Ok(expires_at)
+    }
+}
+
+/// Spawns a background task that keeps the OAuth token of `client` fresh.
+///
+/// The task refreshes the token `margin` before it expires, so requests always
+/// find a valid token. It shares the refresh lock of `client` and every clone of
+/// it, and re-reads the store after taking the lock, so it never races a refresh
+/// triggered by a rejected request. Failed refreshes are retried after a short
+/// delay. Abort the returned handle to stop the task.
+///
+/// # Arguments
+///
+/// * `client` - Client authenticated with OAuth
+/// * `margin` - How long before expiry the token is refreshed
+///
+/// # Returns
+///
+/// The handle of the task, or `None` if `client` authenticates with an API key.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::{DropboxSignClient, oauth::*};
+/// use std::time::Duration;
+///
+/// # fn example(token: OAuthTokenResponse) {
+/// let client = DropboxSignClient::new_with_oauth(
+///     "client-id",
+///     "client-secret",
+///     InMemoryTokenStore::new(token),
+/// );
+/// let refresh_task = spawn_refresh_task(&client, Duration::from_secs(300));
+/// # }
+/// ```
+pub fn spawn_refresh_task(client: &DropboxSignClient, margin: Duration) -> Option<JoinHandle<()>> {
+    let refresher = client.oauth_refresher()?;
+
+    Some(tokio::spawn(async move {
+        loop {
+            let delay = match refresher.refresh_if_expiring(margin).await {
+                Ok(Some(expires_at)) => expires_at
+                    .duration_since(SystemTime::now())
+                    .unwrap_or_default()
+                    .saturating_sub(margin)
+                    .max(REFRESH_RETRY_DELAY),
+                Ok(None) | Err(_) => REFRESH_RETRY_DELAY,
+            };
+            tokio::time::sleep(delay).await;
+        }
+    }))
+}
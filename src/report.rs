@@ -0,0 +1,234 @@
+//! Data models and types for report operations.
+//!
+//! This module contains the data structures needed for requesting activity
+//! reports through the API. Reports are generated asynchronously and emailed to
+//! the requester once they are ready.
+
+use crate::validate::{Checks, Validate, ValidationError};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Request body for creating a report.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::report::*;
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let request = CreateReportRequest::new(
+///     ReportDate::new(2024, 1, 1)?,
+///     "03/31/2024".parse()?,
+///     [ReportType::UserActivity, ReportType::DocumentStatus],
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateReportRequest {
+    /// First day covered by the report
+    pub start_date: ReportDate,
+    /// Last day covered by the report
+    pub end_date: ReportDate,
+    /// Reports to generate
+    pub report_type: Vec<ReportType>,
+}
+
+impl CreateReportRequest {
+    /// Creates a request for the given reports over a range of days.
+    pub fn new(
+        start_date: ReportDate,
+        end_date: ReportDate,
+        report_type: impl IntoIterator<Item = ReportType>,
+    ) -> Self {
+        Self {
+            start_date,
+            end_date,
+            report_type: report_type.into_iter().collect(),
+        }
+    }
+}
+
+/// Kinds of reports the API can generate.
+///
+/// Report types added to the API after this crate was released deserialize as
+/// [`ReportType::Unknown`] instead of failing the whole response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportType {
+    /// Activity of each user on the account or team
+    UserActivity,
+    /// Status of each document
+    DocumentStatus,
+    /// SMS messages sent for signer authentication and delivery
+    SmsActivity,
+    /// A report type this crate does not recognize
+    #[serde(other)]
+    Unknown,
+}
+
+/// A calendar day, sent to and returned by the API in `MM/DD/YYYY` form.
+///
+/// Dates order chronologically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ReportDate {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl ReportDate {
+    /// Creates a date from its year, month (1-12) and day of the month.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReportDateError`] if the day does not exist, e.g. February 30.
+    pub fn new(year: u16, month: u8, day: u8) -> Result<Self, ReportDateError> {
+        let valid = (1..=12).contains(&month) && (1..=days_in_month(year, month)).contains(&day);
+        if !valid {
+            return Err(ReportDateError(format!(
+                "{month:02}/{day:02}/{year:04} is not a calendar day"
+            )));
+        }
+
+        Ok(Self { year, month, day })
+    }
+
+    /// Returns the year.
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Returns the month, from 1 to 12.
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month, from 1.
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+/// Returns the number of days in a month of a year.
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns whether a year has a February 29.
+fn is_leap_year(year: u16) -> bool {
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+}
+
+impl fmt::Display for ReportDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}/{:02}/{:04}", self.month, self.day, self.year)
+    }
+}
+
+impl FromStr for ReportDate {
+    type Err = ReportDateError;
+
+    fn from_str(date: &str) -> Result<Self, Self::Err> {
+        let invalid = || ReportDateError(format!("{date:?} is not a date in MM/DD/YYYY form"));
+
+        let mut parts = date.split('/');
+        let (Some(month), Some(day), Some(year), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid());
+        };
+        if [month, day, year]
+            .iter()
+            .any(|part| part.is_empty() || !part.chars().all(|c| c.is_ascii_digit()))
+        {
+            return Err(invalid());
+        }
+
+        Self::new(
+            year.parse().map_err(|_| invalid())?,
+            month.parse().map_err(|_| invalid())?,
+            day.parse().map_err(|_| invalid())?,
+        )
+    }
+}
+
+impl Serialize for ReportDate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReportDate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let date = String::deserialize(deserializer)?;
+        date.parse().map_err(D::Error::custom)
+    }
+}
+
+/// A date that could not be parsed or does not exist.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("invalid report date: {0}")]
+pub struct ReportDateError(String);
+
+/// Response data for a report request.
+///
+/// The report itself is emailed once it is ready; the response only confirms
+/// what was requested.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReportResponse {
+    /// Reports being generated
+    #[serde(default)]
+    pub report_type: Vec<ReportType>,
+    /// First day covered by the report
+    pub start_date: ReportDate,
+    /// Last day covered by the report
+    pub end_date: ReportDate,
+    /// Confirmation message from the API
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<String>,
+    /// Fields returned by the API that this crate does not model yet
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "crate::strict::extra",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    pub extra: HashMap<String, Value>,
+}
+
+impl Validate for CreateReportRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut checks = Checks::new();
+
+        checks.check(
+            self.start_date <= self.end_date,
+            "end_date",
+            "must not be before start_date",
+        );
+        checks.check(
+            !self.report_type.is_empty(),
+            "report_type",
+            "must contain at least one report type",
+        );
+        for (index, report_type) in self.report_type.iter().enumerate() {
+            checks.check(
+                *report_type != ReportType::Unknown,
+                format!("report_type[{index}]"),
+                "must be a known report type",
+            );
+        }
+
+        checks.finish()
+    }
+}
@@ -3,8 +3,50 @@
 //! This module contains all the data structures needed for creating, sending,
 //! and receiving signature requests through the Dropbox Sign API.
 
+use crate::file::UploadFile;
+use crate::id::{BulkSendJobId, ClientId, SignatureId, SignatureRequestId, TemplateId};
+use crate::metadata::{self, Metadata, MetadataValue};
+use crate::report::ReportDate;
+use crate::validate::{Checks, Validate, ValidationError};
+use crate::{ListItem, Page};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Metadata key that idempotency keys are stored under.
+pub const IDEMPOTENCY_KEY_METADATA: &str = "idempotency_key";
+
+/// Generates a new idempotency key for a signature request.
+///
+/// Keys are unique within a process and very unlikely to collide across
+/// processes. Persist the key before sending so the same key is reused when the
+/// send is retried after a crash.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::signature_request::generate_idempotency_key;
+///
+/// let key = generate_idempotency_key();
+/// assert_eq!(key.len(), 32);
+/// ```
+pub fn generate_idempotency_key() -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+    let mut hasher = Sha256::new();
+    hasher.update(nanos.to_le_bytes());
+    hasher.update(std::process::id().to_le_bytes());
+    hasher.update(COUNTER.fetch_add(1, Ordering::Relaxed).to_le_bytes());
+
+    hex::encode(&hasher.finalize()[..16])
+}
 
 /// Request structure for sending signature requests with templates.
 ///
@@ -15,7 +57,7 @@ use std::collections::HashMap;
 /// # Examples
 ///
 /// ```no_run
-/// use dropboxsign_rs::signature_request::*;
+/// use dropboxsign_client::signature_request::*;
 ///
 /// let signer = SubSignatureRequestTemplateSigner::new(
 ///     "Signer".to_string(),
@@ -23,19 +65,19 @@ use std::collections::HashMap;
 ///     "john@example.com".to_string()
 /// );
 ///
-/// let request = SendSignatureRequest::new(
+/// let request = SendWithTemplateRequest::new(
 ///     vec![signer],
 ///     vec!["template-id".to_string()]
 /// )
 /// .title("Contract Signature".to_string())
 /// .test_mode(true);
 /// ```
-#[derive(Debug, Serialize, Deserialize)]
-pub struct SendSignatureRequest {
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SendWithTemplateRequest {
     /// List of signers who will receive the signature request
     pub signers: Vec<SubSignatureRequestTemplateSigner>,
     /// List of template IDs to use for this signature request
-    pub template_ids: Vec<String>,
+    pub template_ids: Vec<TemplateId>,
     /// Whether signers can decline to sign (default: true)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub allow_decline: Option<bool>,
@@ -44,16 +86,85 @@ pub struct SendSignatureRequest {
     pub ccs: Option<Vec<SubCC>>,
     /// Client ID for API apps
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub client_id: Option<String>,
+    pub client_id: Option<ClientId>,
+    /// Custom form fields to pre-populate in the document
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_fields: Option<Vec<SubCustomField>>,
+    /// Whether to enable eIDAS compliance (European electronic signatures)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_eid: Option<bool>,
+    /// Custom message to include in the signature request email
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Key-value pairs for storing custom data with the signature request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    /// Configuration for signature methods and options
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signing_options: Option<SubSigningOptions>,
+    /// URL to redirect signers to after completing their signature
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signing_redirect_url: Option<String>,
+    /// Whether to create the signature request in test mode
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub test_mode: Option<bool>,
+    /// Title for the signature request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+/// Former name of [`SendWithTemplateRequest`].
+#[deprecated(note = "use `SendWithTemplateRequest`, or `SendWithFilesRequest` to upload documents")]
+pub type SendSignatureRequest = SendWithTemplateRequest;
+
+/// Request structure for sending signature requests with uploaded documents.
+///
+/// The counterpart of [`SendWithTemplateRequest`] for documents that are not
+/// based on a template: signers are identified by name and email address instead
+/// of template roles, and the documents are either uploaded files or URLs the
+/// API downloads them from.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::file::UploadFile;
+/// use dropboxsign_client::signature_request::*;
+///
+/// let request = SendWithFilesRequest::new(
+///     vec![SubSignatureRequestSigner::new(
+///         "John Doe".to_string(),
+///         "john@example.com".to_string(),
+///     )],
+///     SignatureRequestDocuments::files([UploadFile::from_path("contracts/nda.pdf")]),
+/// )
+/// .title("NDA".to_string())
+/// .test_mode(true);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SendWithFilesRequest {
+    /// List of signers who will receive the signature request
+    pub signers: Vec<SubSignatureRequestSigner>,
+    /// Documents to be signed
+    #[serde(flatten)]
+    pub documents: SignatureRequestDocuments,
+    /// Whether signers can decline to sign (default: true)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_decline: Option<bool>,
+    /// Email addresses that will receive copies of the signature request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cc_email_addresses: Option<Vec<String>>,
+    /// Client ID for API apps
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<ClientId>,
     /// Custom form fields to pre-populate in the document
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub custom_fields: Option<Vec<SubCustomField>>,
-    /// File data as byte arrays (alternative to file_urls)
+    /// Form fields placed on the documents
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub files: Option<Vec<Vec<u8>>>,
-    /// URLs to files to be signed (alternative to files)
+    pub form_fields_per_document: Option<Vec<SubFormField>>,
+    /// Whether text tags in the documents are hidden once they are processed
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub file_urls: Option<Vec<String>>,
+    pub hide_text_tags: Option<bool>,
     /// Whether to enable eIDAS compliance (European electronic signatures)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub is_eid: Option<bool>,
@@ -62,26 +173,141 @@ pub struct SendSignatureRequest {
     pub message: Option<String>,
     /// Key-value pairs for storing custom data with the signature request
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub metadata: Option<HashMap<String, String>>,
+    pub metadata: Option<Metadata>,
     /// Configuration for signature methods and options
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub signing_options: Option<SubSigningOptions>,
     /// URL to redirect signers to after completing their signature
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub signing_redirect_url: Option<String>,
+    /// Subject of the signature request email
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
     /// Whether to create the signature request in test mode
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub test_mode: Option<bool>,
     /// Title for the signature request
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    /// Whether text tags in the documents are turned into form fields
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub use_text_tags: Option<bool>,
+}
+
+/// A form field placed on a document of a [`SendWithFilesRequest`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::signature_request::*;
+///
+/// let terms = SubFormField::new(SubFormFieldType::Hyperlink, "terms".to_string(), "0".to_string())
+///     .position(0, 1, 100, 700)
+///     .size(120, 16)
+///     .hyperlink(
+///         "Terms of service".to_string(),
+///         "https://example.com/terms".to_string(),
+///     );
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubFormField {
+    /// Type of the form field
+    #[serde(rename = "type")]
+    pub field_type: SubFormFieldType,
+    /// Unique identifier of the field within the request
+    pub api_id: String,
+    /// Index of the signer the field is assigned to, or `sender`
+    pub signer: String,
+    /// Index of the document the field is placed on
+    pub document_index: u32,
+    /// Page the field is placed on, starting at 1
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+    /// Horizontal position of the field, in pixels
+    pub x: u32,
+    /// Vertical position of the field, in pixels
+    pub y: u32,
+    /// Width of the field, in pixels
+    pub width: u32,
+    /// Height of the field, in pixels
+    pub height: u32,
+    /// Whether the field must be filled out
+    pub required: bool,
+    /// Display name of the field
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Text shown for a hyperlink field
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// URL a hyperlink field links to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_url: Option<String>,
+    /// How the value of a text field is validated
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validation_type: Option<TextValidationType>,
+    /// Regular expression a text field's value must match, for
+    /// [`TextValidationType::CustomRegex`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validation_custom_regex: Option<String>,
+    /// Message shown to the signer when the value does not match
+    /// `validation_custom_regex`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validation_custom_message: Option<String>,
+}
+
+/// Types of form fields that can be placed on a document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubFormFieldType {
+    /// Single-line or multi-line text input
+    Text,
+    /// Checkbox that can be checked or unchecked
+    Checkbox,
+    /// Electronic signature field
+    Signature,
+    /// Initial signature field
+    Initials,
+    /// Automatically filled date when document was signed
+    DateSigned,
+    /// Clickable link; set its text and URL with [`SubFormField::hyperlink`]
+    Hyperlink,
+}
+
+/// Documents of a signature request sent with [`SendWithFilesRequest`].
+///
+/// The API takes either uploaded files or URLs to download the documents from,
+/// never both.
+#[derive(Debug, Clone)]
+pub enum SignatureRequestDocuments {
+    /// Files uploaded with the request, sent as multipart form data
+    Files(Vec<UploadFile>),
+    /// Publicly accessible URLs the API downloads the documents from
+    FileUrls(Vec<String>),
+}
+
+/// Represents a signer in a signature request sent with uploaded documents.
+///
+/// Each signer must have a name and email address. Additional authentication
+/// options like PIN or SMS can be configured.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubSignatureRequestSigner {
+    /// Full name of the signer
+    pub name: String,
+    /// Email address where the signature request will be sent
+    pub email_address: String,
+    /// Signing order (for sequential signing workflows)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<i32>,
+    /// Additional authentication, or SMS delivery, for the signer
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub auth: Option<SignerAuth>,
 }
 
 /// Represents a signer in a template-based signature request.
 ///
 /// Each signer must have a role (matching the template), name, and email address.
 /// Additional authentication options like PIN or SMS can be configured.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SubSignatureRequestTemplateSigner {
     /// Role name that matches a role defined in the template
     pub role: String,
@@ -89,19 +315,44 @@ pub struct SubSignatureRequestTemplateSigner {
     pub name: String,
     /// Email address where the signature request will be sent
     pub email_address: String,
-    /// Optional PIN for additional security (4-12 digits)
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub pin: Option<String>,
-    /// Phone number for SMS authentication or delivery
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub sms_phone_number: Option<String>,
-    /// Type of SMS usage (authentication or delivery)
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub sms_phone_number_type: Option<SMSPhoneNumberType>,
+    /// Additional authentication, or SMS delivery, for the signer
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub auth: Option<SignerAuth>,
+}
+
+/// Additional authentication for a signer, or delivery of the request by SMS.
+///
+/// Signers receive signature requests by email and sign after opening the link
+/// in it. A signer can additionally be asked for a PIN or a code sent by SMS, or
+/// receive the request by SMS instead of email. These options exclude each other,
+/// so a signer has at most one of them.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::signature_request::*;
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let signer = SubSignatureRequestSigner::new(
+///     "Jane Doe".to_string(),
+///     "jane@example.com".to_string(),
+/// )
+/// .auth(SignerAuth::try_pin("4821".to_string())?);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SignerAuth {
+    /// The signer must enter a PIN of 4 to 12 digits before signing
+    Pin(String),
+    /// The signer must enter a code sent by SMS to the phone number before signing
+    SmsAuthentication(String),
+    /// The request is delivered by SMS to the phone number instead of by email
+    SmsDelivery(String),
 }
 
 /// Specifies how SMS phone numbers are used in signature requests.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum SMSPhoneNumberType {
     /// SMS is used for two-factor authentication
@@ -114,7 +365,7 @@ pub enum SMSPhoneNumberType {
 ///
 /// CC recipients receive copies of signature request emails and completion notifications
 /// but are not required to sign the document.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SubCC {
     /// Role name for the CC recipient (must match template if using templates)
     pub role: String,
@@ -126,7 +377,7 @@ pub struct SubCC {
 ///
 /// Custom fields allow you to set default values for form fields in the document
 /// before sending it to signers.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SubCustomField {
     /// Name of the custom field (must match field name in template)
     pub name: String,
@@ -145,7 +396,7 @@ pub struct SubCustomField {
 ///
 /// Defines which signature methods are available to signers and which one
 /// is the default option.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SubSigningOptions {
     /// Default signature method that will be pre-selected
     pub default_type: SubSigningOptionsDefaultType,
@@ -156,16 +407,15 @@ pub struct SubSigningOptions {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub phone: Option<bool>,
     /// Whether signers can type their signature
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "type")]
-    pub o_type: Option<bool>,
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub typed: Option<bool>,
     /// Whether signers can upload an image of their signature
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub upload: Option<bool>,
 }
 
 /// Available signature methods for the default signing option.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum SubSigningOptionsDefaultType {
     /// Draw signature with mouse/finger
@@ -182,13 +432,13 @@ pub enum SubSigningOptionsDefaultType {
 ///
 /// Contains all information about a signature request including its status,
 /// signer information, URLs, and metadata.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SignatureRequestResponse {
     /// Whether this signature request was created in test mode
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub test_mode: Option<bool>,
     /// Unique identifier for this signature request
-    pub signature_request_id: String,
+    pub signature_request_id: SignatureRequestId,
     /// Email address of the person who created this request
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub requester_email_address: Option<String>,
@@ -203,7 +453,7 @@ pub struct SignatureRequestResponse {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
     /// Custom metadata key-value pairs
-    pub metadata: HashMap<String, String>,
+    pub metadata: Metadata,
     /// Unix timestamp when the signature request was created
     pub created_at: u64,
     /// Unix timestamp when the signature request expires (if set)
@@ -232,7 +482,7 @@ pub struct SignatureRequestResponse {
     pub final_copy_uri: Option<String>,
     /// Template IDs used to create this signature request
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub template_ids: Option<Vec<String>>,
+    pub template_ids: Option<Vec<TemplateId>>,
     /// Custom IDs associated with this signature request
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub custom_ids: Option<Vec<String>>,
@@ -246,18 +496,26 @@ pub struct SignatureRequestResponse {
     pub signatures: Vec<SignatureRequestResponseSignatures>,
     /// Bulk send job ID if this was part of a bulk operation
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub bulk_send_job_id: Option<String>,
+    pub bulk_send_job_id: Option<BulkSendJobId>,
+    /// Fields returned by the API that this crate does not model yet
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "crate::strict::extra",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    pub extra: HashMap<String, Value>,
 }
 
 /// Base structure for custom form fields in signature request responses.
 ///
 /// Represents form fields that were filled out by signers or pre-populated
 /// when the signature request was created.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SignatureRequestResponseCustomFieldBase {
     /// Type of the form field (text, checkbox, etc.)
     #[serde(rename = "type")]
-    pub o_type: SignatureRequestResponseCustomFieldBaseType,
+    pub field_type: SignatureRequestResponseCustomFieldBaseType,
     /// Name/identifier of the form field
     pub name: String,
     /// Whether this field was required to be filled out
@@ -272,23 +530,73 @@ pub struct SignatureRequestResponseCustomFieldBase {
     /// Current value of the form field
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
+    /// How the value of a text field is validated
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validation_type: Option<TextValidationType>,
+    /// Regular expression a text field's value must match, for
+    /// [`TextValidationType::CustomRegex`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validation_custom_regex: Option<String>,
+    /// Message shown to the signer when the value does not match
+    /// `validation_custom_regex`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validation_custom_message: Option<String>,
 }
 
 /// Types of custom form fields available in signature requests.
-#[derive(Debug, Serialize, Deserialize)]
+///
+/// Field types added to the API after this crate was released deserialize as
+/// [`SignatureRequestResponseCustomFieldBaseType::Unknown`] instead of failing
+/// the whole response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum SignatureRequestResponseCustomFieldBaseType {
     /// Single-line or multi-line text input field
     Text,
     /// Checkbox that can be checked or unchecked
     Checkbox,
+    /// A field type this crate does not recognize
+    #[serde(other)]
+    Unknown,
+}
+
+/// How the value entered into a text field is validated.
+///
+/// Validation types added to the API after this crate was released deserialize
+/// as [`TextValidationType::Unknown`] instead of failing the whole response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TextValidationType {
+    /// Digits only
+    NumbersOnly,
+    /// Letters only
+    LettersOnly,
+    /// A phone number
+    PhoneNumber,
+    /// A US bank routing number
+    BankRoutingNumber,
+    /// A bank account number
+    BankAccountNumber,
+    /// An email address
+    EmailAddress,
+    /// A US ZIP code
+    ZipCode,
+    /// A US social security number
+    Ssn,
+    /// A US employer identification number
+    Ein,
+    /// A custom regular expression, set in `validation_custom_regex`
+    CustomRegex,
+    /// A validation type this crate does not recognize
+    #[serde(other)]
+    Unknown,
 }
 
 /// File attachment associated with a signature request.
 ///
 /// Represents additional documents that signers can upload as part of
 /// the signing process.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SignatureRequestResponseAttachment {
     /// Unique identifier for this attachment
     pub id: String,
@@ -309,37 +617,207 @@ pub struct SignatureRequestResponseAttachment {
 /// Form field response data from a signature request.
 ///
 /// Contains the values that signers entered in form fields, along with
-/// metadata about each field.
-#[derive(Debug, Serialize, Deserialize)]
-pub struct SignatureRequestResponseData {
+/// metadata about each field. The variant follows the `type` of the field, so
+/// each value comes out with the type the API returns for that kind of field.
+/// Field types added to the API after this crate was released, and fields whose
+/// value does not have the expected type, deserialize as
+/// [`SignatureRequestResponseData::Unknown`] holding the raw field object, which
+/// serializes back unchanged.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::signature_request::*;
+///
+/// # fn example(signature_request: SignatureRequestResponse) {
+/// for data in signature_request.response_data.iter().flatten() {
+///     match data {
+///         SignatureRequestResponseData::Text(field) => {
+///             println!("{:?}: {:?}", field.name, field.value);
+///         }
+///         SignatureRequestResponseData::Checkbox(field) => {
+///             println!("{:?} checked: {}", field.name, field.value == Some(true));
+///         }
+///         _ => {}
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum SignatureRequestResponseData {
+    /// Single-line or multi-line text input
+    #[serde(rename = "text")]
+    Text(SignatureRequestResponseDataText),
+    /// Checkbox that can be checked or unchecked
+    #[serde(rename = "checkbox")]
+    Checkbox(SignatureRequestResponseDataField<bool>),
+    /// Dropdown menu; the value is the selected option
+    #[serde(rename = "dropdown")]
+    Dropdown(SignatureRequestResponseDataField<String>),
+    /// Radio button; the value is whether it is selected
+    #[serde(rename = "radio")]
+    Radio(SignatureRequestResponseDataField<bool>),
+    /// Electronic signature field
+    #[serde(rename = "signature")]
+    Signature(SignatureRequestResponseDataField<String>),
+    /// Automatically filled date when document was signed
+    ///
+    /// The value is formatted with the date format chosen for the field, such as
+    /// `MM / DD / YYYY` or `YYYY - MM - DD`, so it is kept as text; read it as a
+    /// date with [`date_signed`](Self::date_signed).
+    #[serde(rename = "date_signed")]
+    DateSigned(SignatureRequestResponseDataField<String>),
+    /// Initial signature field
+    #[serde(rename = "initials")]
+    Initials(SignatureRequestResponseDataField<String>),
+    /// Text field merged from template data
+    #[serde(rename = "text-merge")]
+    TextMerge(SignatureRequestResponseDataField<String>),
+    /// Checkbox field merged from template data
+    #[serde(rename = "checkbox-merge")]
+    CheckboxMerge(SignatureRequestResponseDataField<String>),
+    /// Clickable link placed on the document
+    #[serde(rename = "hyperlink")]
+    Hyperlink(SignatureRequestResponseDataHyperlink),
+    /// A field type this crate does not recognize, kept as the raw field object
+    #[serde(untagged)]
+    Unknown(Value),
+}
+
+/// Date formats a date signed field can be shown in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DateFormat {
+    /// `MM / DD / YYYY`
+    #[serde(rename = "MM / DD / YYYY")]
+    MonthDayYearSlash,
+    /// `MM - DD - YYYY`
+    #[serde(rename = "MM - DD - YYYY")]
+    MonthDayYearDash,
+    /// `DD / MM / YYYY`
+    #[serde(rename = "DD / MM / YYYY")]
+    DayMonthYearSlash,
+    /// `DD - MM - YYYY`
+    #[serde(rename = "DD - MM - YYYY")]
+    DayMonthYearDash,
+    /// `YYYY / MM / DD`
+    #[serde(rename = "YYYY / MM / DD")]
+    YearMonthDaySlash,
+    /// `YYYY - MM - DD`
+    #[serde(rename = "YYYY - MM - DD")]
+    YearMonthDayDash,
+}
+
+impl DateFormat {
+    /// Parses a date written in this format, with or without spaces around the
+    /// separators.
+    ///
+    /// Returns `None` if `date` is not in this format or is not a calendar day.
+    pub fn parse(&self, date: &str) -> Option<ReportDate> {
+        let separator = match self {
+            Self::MonthDayYearSlash | Self::DayMonthYearSlash | Self::YearMonthDaySlash => '/',
+            Self::MonthDayYearDash | Self::DayMonthYearDash | Self::YearMonthDayDash => '-',
+        };
+        let parts = date
+            .split(separator)
+            .map(|part| {
+                let part = part.trim();
+                let digits = !part.is_empty() && part.chars().all(|c| c.is_ascii_digit());
+                digits.then(|| part.parse::<u16>().ok()).flatten()
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let [first, second, third] = parts[..] else {
+            return None;
+        };
+
+        let (year, month, day) = match self {
+            Self::MonthDayYearSlash | Self::MonthDayYearDash => (third, first, second),
+            Self::DayMonthYearSlash | Self::DayMonthYearDash => (third, second, first),
+            Self::YearMonthDaySlash | Self::YearMonthDayDash => (first, second, third),
+        };
+        ReportDate::new(year, u8::try_from(month).ok()?, u8::try_from(day).ok()?).ok()
+    }
+}
+
+/// Response data of a form field whose value has type `V`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SignatureRequestResponseDataField<V> {
     /// API identifier for this form field
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub api_id: Option<String>,
     /// ID of the signature this data belongs to
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub signature_id: Option<String>,
+    pub signature_id: Option<SignatureId>,
     /// Name/label of the form field
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     /// Whether this field was required
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub required: Option<bool>,
-    /// Type of form field (text, checkbox, dropdown, etc.)
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub o_type: Option<SignatureRequestResponseDataType>,
     /// Value entered by the signer
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<V>,
+}
+
+/// Response data of a text field.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SignatureRequestResponseDataText {
+    /// API identifier for this form field
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_id: Option<String>,
+    /// ID of the signature this data belongs to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature_id: Option<SignatureId>,
+    /// Name/label of the form field
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Whether this field was required
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required: Option<bool>,
+    /// Text entered by the signer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
+    /// How the value of the field is validated
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validation_type: Option<TextValidationType>,
+    /// Regular expression the value must match, for
+    /// [`TextValidationType::CustomRegex`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validation_custom_regex: Option<String>,
+    /// Message shown to the signer when the value does not match
+    /// `validation_custom_regex`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validation_custom_message: Option<String>,
+}
+
+/// Response data of a hyperlink field.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SignatureRequestResponseDataHyperlink {
+    /// API identifier for this form field
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_id: Option<String>,
+    /// ID of the signature this data belongs to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature_id: Option<SignatureId>,
+    /// Name/label of the form field
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Text shown for the link
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// URL the link points to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_url: Option<String>,
 }
 
 /// Individual signature status and metadata for each signer.
 ///
 /// Contains detailed information about each signer's interaction with
 /// the signature request, including status, timestamps, and authentication details.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SignatureRequestResponseSignatures {
     /// Unique identifier for this signature
-    pub signature_id: String,
+    pub signature_id: SignatureId,
 
     /// Group GUID if this signer belongs to a signer group
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -409,12 +887,23 @@ pub struct SignatureRequestResponseSignatures {
     /// Error message if there was a problem with this signature
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
+    /// Fields returned by the API that this crate does not model yet
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "crate::strict::extra",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    pub extra: HashMap<String, Value>,
 }
 
 /// Types of form fields that can appear in signature request responses.
 ///
 /// Covers all possible field types that signers can interact with in documents.
-#[derive(Debug, Serialize, Deserialize)]
+/// Field types added to the API after this crate was released deserialize as
+/// [`SignatureRequestResponseDataType::Unknown`] instead of failing the whole
+/// response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum SignatureRequestResponseDataType {
     /// Single-line or multi-line text input
@@ -438,11 +927,193 @@ pub enum SignatureRequestResponseDataType {
     /// Checkbox field merged from template data
     #[serde(rename = "checkbox-merge")]
     CheckboxMerge,
+    /// Clickable link placed on the document
+    Hyperlink,
+    /// A field type this crate does not recognize
+    #[serde(other)]
+    Unknown,
+}
+
+/// Response data for a page of signature requests.
+pub type SignatureRequestsResponse = Page<SignatureRequestResponse>;
+
+impl ListItem for SignatureRequestResponse {
+    const LIST_KEY: &'static str = "signature_requests";
+}
+
+/// Lightweight view of a signature request, for syncing large numbers of them.
+///
+/// Holds only the identity, status flags and signer states of a request. Heavy
+/// fields such as `response_data` and `metadata` are skipped while parsing
+/// instead of being turned into values, which makes walking tens of thousands
+/// of requests much cheaper than with [`SignatureRequestResponse`]. Each page's
+/// response body is still read in full before it is parsed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SignatureRequestSummary {
+    /// Unique identifier for this signature request
+    pub signature_request_id: SignatureRequestId,
+    /// Current title of the signature request
+    pub title: String,
+    /// Unix timestamp when the signature request was created
+    pub created_at: u64,
+    /// Whether all required signatures have been completed
+    pub is_complete: bool,
+    /// Whether any signer has declined to sign
+    pub is_declined: bool,
+    /// Whether there are any errors with this signature request
+    pub has_error: bool,
+    /// State of each signer
+    #[serde(default)]
+    pub signatures: Vec<SignatureRequestSummarySignature>,
+}
+
+/// State of a single signer in a [`SignatureRequestSummary`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SignatureRequestSummarySignature {
+    /// Unique identifier for this signature
+    pub signature_id: SignatureId,
+    /// Email address of the signer
+    pub signer_email_address: String,
+    /// Full name of the signer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signer_name: Option<String>,
+    /// Current status of this signature (awaiting_signature, signed, declined, etc.)
+    pub status_code: String,
+    /// Unix timestamp when the signature was completed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signed_at: Option<i64>,
+}
+
+/// Summaries of a page of signature requests.
+pub type SignatureRequestSummariesResponse = Page<SignatureRequestSummary>;
+
+impl ListItem for SignatureRequestSummary {
+    const LIST_KEY: &'static str = "signature_requests";
+}
+
+/// Formats the documents of a signature request can be downloaded in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SignatureRequestFileType {
+    /// All documents merged into a single PDF
+    Pdf,
+    /// Each document as a separate PDF inside a zip archive
+    Zip,
+}
+
+impl SignatureRequestResponse {
+    /// Returns the idempotency key stamped into the request's metadata, if any.
+    pub fn idempotency_key(&self) -> Option<&str> {
+        self.metadata
+            .get(IDEMPOTENCY_KEY_METADATA)
+            .and_then(MetadataValue::as_str)
+    }
+}
+
+impl SignatureRequestResponseData {
+    /// Returns the type of the form field.
+    pub fn field_type(&self) -> SignatureRequestResponseDataType {
+        match self {
+            Self::Text(_) => SignatureRequestResponseDataType::Text,
+            Self::Checkbox(_) => SignatureRequestResponseDataType::Checkbox,
+            Self::Dropdown(_) => SignatureRequestResponseDataType::Dropdown,
+            Self::Radio(_) => SignatureRequestResponseDataType::Radio,
+            Self::Signature(_) => SignatureRequestResponseDataType::Signature,
+            Self::DateSigned(_) => SignatureRequestResponseDataType::DateSigned,
+            Self::Initials(_) => SignatureRequestResponseDataType::Initials,
+            Self::TextMerge(_) => SignatureRequestResponseDataType::TextMerge,
+            Self::CheckboxMerge(_) => SignatureRequestResponseDataType::CheckboxMerge,
+            Self::Hyperlink(_) => SignatureRequestResponseDataType::Hyperlink,
+            Self::Unknown(_) => SignatureRequestResponseDataType::Unknown,
+        }
+    }
+
+    /// Returns the API identifier of the form field, if known.
+    pub fn api_id(&self) -> Option<&str> {
+        match self {
+            Self::Text(field) => field.api_id.as_deref(),
+            Self::Checkbox(field) | Self::Radio(field) => field.api_id.as_deref(),
+            Self::Dropdown(field)
+            | Self::Signature(field)
+            | Self::DateSigned(field)
+            | Self::Initials(field)
+            | Self::TextMerge(field)
+            | Self::CheckboxMerge(field) => field.api_id.as_deref(),
+            Self::Hyperlink(field) => field.api_id.as_deref(),
+            Self::Unknown(field) => field.get("api_id").and_then(Value::as_str),
+        }
+    }
+
+    /// Returns the ID of the signature the data belongs to, if known.
+    pub fn signature_id(&self) -> Option<&SignatureId> {
+        match self {
+            Self::Text(field) => field.signature_id.as_ref(),
+            Self::Checkbox(field) | Self::Radio(field) => field.signature_id.as_ref(),
+            Self::Dropdown(field)
+            | Self::Signature(field)
+            | Self::DateSigned(field)
+            | Self::Initials(field)
+            | Self::TextMerge(field)
+            | Self::CheckboxMerge(field) => field.signature_id.as_ref(),
+            Self::Hyperlink(field) => field.signature_id.as_ref(),
+            Self::Unknown(_) => None,
+        }
+    }
+
+    /// Returns the name of the form field, if known.
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Self::Text(field) => field.name.as_deref(),
+            Self::Checkbox(field) | Self::Radio(field) => field.name.as_deref(),
+            Self::Dropdown(field)
+            | Self::Signature(field)
+            | Self::DateSigned(field)
+            | Self::Initials(field)
+            | Self::TextMerge(field)
+            | Self::CheckboxMerge(field) => field.name.as_deref(),
+            Self::Hyperlink(field) => field.name.as_deref(),
+            Self::Unknown(field) => field.get("name").and_then(Value::as_str),
+        }
+    }
+
+    /// Returns the date of a date signed field, read in the field's `format`.
+    ///
+    /// Returns `None` for other field types, fields without a value and values
+    /// that are not a date in `format`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dropboxsign_client::signature_request::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let data: SignatureRequestResponseData = serde_json::from_str(
+    ///     r#"{"type": "date_signed", "value": "03 / 31 / 2024"}"#,
+    /// )?;
+    ///
+    /// let date = data
+    ///     .date_signed(DateFormat::MonthDayYearSlash)
+    ///     .ok_or("not a date")?;
+    /// assert_eq!((date.year(), date.month(), date.day()), (2024, 3, 31));
+    /// assert_eq!(data.date_signed(DateFormat::DayMonthYearSlash), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn date_signed(&self, format: DateFormat) -> Option<ReportDate> {
+        match self {
+            Self::DateSigned(field) => format.parse(field.value.as_deref()?),
+            _ => None,
+        }
+    }
 }
 
-impl SendSignatureRequest {
+impl SendWithTemplateRequest {
     /// Creates a new signature request with the minimum required fields.
     ///
+    /// Empty `signers` or `template_ids` are only reported when the request is
+    /// validated; [`for_signer`](Self::for_signer) and [`try_new`](Self::try_new)
+    /// rule them out up front.
+    ///
     /// # Arguments
     ///
     /// * `signers` - List of signers who will receive the signature request
@@ -451,7 +1122,7 @@ impl SendSignatureRequest {
     /// # Examples
     ///
     /// ```no_run
-    /// use dropboxsign_rs::signature_request::*;
+    /// use dropboxsign_client::signature_request::*;
     ///
     /// let signer = SubSignatureRequestTemplateSigner::new(
     ///     "Signer".to_string(),
@@ -459,21 +1130,22 @@ impl SendSignatureRequest {
     ///     "john@example.com".to_string()
     /// );
     ///
-    /// let request = SendSignatureRequest::new(
+    /// let request = SendWithTemplateRequest::new(
     ///     vec![signer],
     ///     vec!["template-id".to_string()]
     /// );
     /// ```
-    pub fn new(signers: Vec<SubSignatureRequestTemplateSigner>, template_ids: Vec<String>) -> Self {
+    pub fn new(
+        signers: Vec<SubSignatureRequestTemplateSigner>,
+        template_ids: impl IntoIterator<Item = impl Into<TemplateId>>,
+    ) -> Self {
         Self {
             signers,
-            template_ids,
+            template_ids: template_ids.into_iter().map(Into::into).collect(),
             allow_decline: None,
             ccs: None,
             client_id: None,
             custom_fields: None,
-            files: None,
-            file_urls: None,
             is_eid: None,
             message: None,
             metadata: None,
@@ -484,63 +1156,129 @@ impl SendSignatureRequest {
         }
     }
 
-    /// Sets whether signers can decline to sign the document.
+    /// Creates a new signature request for a single signer and template.
+    ///
+    /// Unlike [`new`](Self::new), the request can't end up without signers or
+    /// templates. More of either can be added with [`signer`](Self::signer) and
+    /// [`template_id`](Self::template_id).
     ///
     /// # Arguments
     ///
-    /// * `allow_decline` - If true, signers can decline; if false, they must either sign or ignore
-    pub fn allow_decline(mut self, allow_decline: bool) -> Self {
-        self.allow_decline = Some(allow_decline);
-        self
+    /// * `signer` - First signer who will receive the signature request
+    /// * `template_id` - First template to use for this signature request
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::signature_request::*;
+    ///
+    /// let request = SendWithTemplateRequest::for_signer(
+    ///     SubSignatureRequestTemplateSigner::new(
+    ///         "Client".to_string(),
+    ///         "John Doe".to_string(),
+    ///         "john@example.com".to_string(),
+    ///     ),
+    ///     "template-id",
+    /// )
+    /// .signer(SubSignatureRequestTemplateSigner::new(
+    ///     "Witness".to_string(),
+    ///     "Jane Doe".to_string(),
+    ///     "jane@example.com".to_string(),
+    /// ));
+    /// ```
+    pub fn for_signer(
+        signer: SubSignatureRequestTemplateSigner,
+        template_id: impl Into<TemplateId>,
+    ) -> Self {
+        Self::new(vec![signer], [template_id])
     }
 
-    /// Sets the list of CC recipients for the signature request.
+    /// Creates a new signature request, checking that there is at least one
+    /// signer and one template.
     ///
     /// # Arguments
     ///
-    /// * `ccs` - List of people who will receive copies of signature request emails
-    pub fn ccs(mut self, ccs: Vec<SubCC>) -> Self {
-        self.ccs = Some(ccs);
-        self
-    }
+    /// * `signers` - List of signers who will receive the signature request
+    /// * `template_ids` - List of template IDs to use for this signature request
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError`] if `signers` or `template_ids` is empty.
+    pub fn try_new(
+        signers: Vec<SubSignatureRequestTemplateSigner>,
+        template_ids: impl IntoIterator<Item = impl Into<TemplateId>>,
+    ) -> Result<Self, ValidationError> {
+        let request = Self::new(signers, template_ids);
+
+        let mut checks = Checks::new();
+        checks.check(!request.signers.is_empty(), "signers", "must not be empty");
+        checks.check(
+            !request.template_ids.is_empty(),
+            "template_ids",
+            "must not be empty",
+        );
+        checks.finish()?;
+
+        Ok(request)
+    }
 
-    /// Sets the client ID for API apps.
+    /// Adds a signer to the signature request.
     ///
     /// # Arguments
     ///
-    /// * `client_id` - Client ID for your API app
-    pub fn client_id(mut self, client_id: String) -> Self {
-        self.client_id = Some(client_id);
+    /// * `signer` - Signer who will receive the signature request
+    pub fn signer(mut self, signer: SubSignatureRequestTemplateSigner) -> Self {
+        self.signers.push(signer);
         self
     }
 
-    /// Sets custom form fields to pre-populate in the document.
+    /// Adds a template to the signature request.
     ///
     /// # Arguments
     ///
-    /// * `custom_fields` - List of custom fields with default values
-    pub fn custom_fields(mut self, custom_fields: Vec<SubCustomField>) -> Self {
-        self.custom_fields = Some(custom_fields);
+    /// * `template_id` - Template to use for this signature request
+    pub fn template_id(mut self, template_id: impl Into<TemplateId>) -> Self {
+        self.template_ids.push(template_id.into());
+        self
+    }
+
+    /// Sets whether signers can decline to sign the document.
+    ///
+    /// # Arguments
+    ///
+    /// * `allow_decline` - If true, signers can decline; if false, they must either sign or ignore
+    pub fn allow_decline(mut self, allow_decline: bool) -> Self {
+        self.allow_decline = Some(allow_decline);
+        self
+    }
+
+    /// Sets the list of CC recipients for the signature request.
+    ///
+    /// # Arguments
+    ///
+    /// * `ccs` - List of people who will receive copies of signature request emails
+    pub fn ccs(mut self, ccs: Vec<SubCC>) -> Self {
+        self.ccs = Some(ccs);
         self
     }
 
-    /// Sets file data as byte arrays for documents to be signed.
+    /// Sets the client ID for API apps.
     ///
     /// # Arguments
     ///
-    /// * `files` - List of file contents as byte arrays
-    pub fn files(mut self, files: Vec<Vec<u8>>) -> Self {
-        self.files = Some(files);
+    /// * `client_id` - Client ID for your API app
+    pub fn client_id(mut self, client_id: impl Into<ClientId>) -> Self {
+        self.client_id = Some(client_id.into());
         self
     }
 
-    /// Sets URLs to files that should be downloaded and used as documents.
+    /// Sets custom form fields to pre-populate in the document.
     ///
     /// # Arguments
     ///
-    /// * `file_urls` - List of publicly accessible URLs to PDF files
-    pub fn file_urls(mut self, file_urls: Vec<String>) -> Self {
-        self.file_urls = Some(file_urls);
+    /// * `custom_fields` - List of custom fields with default values
+    pub fn custom_fields(mut self, custom_fields: Vec<SubCustomField>) -> Self {
+        self.custom_fields = Some(custom_fields);
         self
     }
 
@@ -568,9 +1306,29 @@ impl SendSignatureRequest {
     ///
     /// # Arguments
     ///
-    /// * `metadata` - Key-value pairs for storing custom data
-    pub fn metadata(mut self, metadata: HashMap<String, String>) -> Self {
-        self.metadata = Some(metadata);
+    /// * `metadata` - Key-value pairs for storing custom data, with string,
+    ///   integer or boolean values
+    pub fn metadata<K, V>(mut self, metadata: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: Into<String>,
+        V: Into<MetadataValue>,
+    {
+        self.metadata = Some(metadata::collect(metadata));
+        self
+    }
+
+    /// Stamps an idempotency key into the request's metadata.
+    ///
+    /// Used by [`DropboxSignClient::send_with_template_idempotent`](crate::DropboxSignClient::send_with_template_idempotent)
+    /// to detect signature requests that were already sent.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Key identifying this send, e.g. from [`generate_idempotency_key`]
+    pub fn idempotency_key(mut self, key: impl Into<String>) -> Self {
+        self.metadata
+            .get_or_insert_with(HashMap::new)
+            .insert(IDEMPOTENCY_KEY_METADATA.to_string(), key.into().into());
         self
     }
 
@@ -627,7 +1385,7 @@ impl SubSignatureRequestTemplateSigner {
     /// # Examples
     ///
     /// ```no_run
-    /// use dropboxsign_rs::signature_request::SubSignatureRequestTemplateSigner;
+    /// use dropboxsign_client::signature_request::SubSignatureRequestTemplateSigner;
     ///
     /// let signer = SubSignatureRequestTemplateSigner::new(
     ///     "Signer".to_string(),
@@ -640,39 +1398,59 @@ impl SubSignatureRequestTemplateSigner {
             role,
             name,
             email_address,
-            pin: None,
-            sms_phone_number: None,
-            sms_phone_number_type: None,
+            auth: None,
         }
     }
 
-    /// Sets a PIN that the signer must enter before signing.
+    /// Creates a new signer, checking that the role and name are not blank and
+    /// that the email address is valid.
     ///
     /// # Arguments
     ///
-    /// * `pin` - 4-12 digit PIN for additional security
-    pub fn pin(mut self, pin: String) -> Self {
-        self.pin = Some(pin);
-        self
-    }
-
-    /// Sets the phone number for SMS authentication or delivery.
+    /// * `role` - Role name that matches a role defined in the template
+    /// * `name` - Full name of the signer
+    /// * `email_address` - Email address where the signature request will be sent
     ///
-    /// # Arguments
+    /// # Errors
     ///
-    /// * `sms_phone_number` - Phone number in international format
-    pub fn sms_phone_number(mut self, sms_phone_number: String) -> Self {
-        self.sms_phone_number = Some(sms_phone_number);
-        self
+    /// Returns a [`ValidationError`] listing every invalid argument.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dropboxsign_client::signature_request::{SignerAuth, SubSignatureRequestTemplateSigner};
+    ///
+    /// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let signer = SubSignatureRequestTemplateSigner::try_new(
+    ///     "Signer".to_string(),
+    ///     "Jane Doe".to_string(),
+    ///     "jane@example.com".to_string(),
+    /// )?
+    /// .auth(SignerAuth::try_sms_authentication("+14155550100".to_string())?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_new(
+        role: String,
+        name: String,
+        email_address: String,
+    ) -> Result<Self, ValidationError> {
+        let mut checks = Checks::new();
+        checks.not_blank(&role, "role");
+        checks.not_blank(&name, "name");
+        checks.email(&email_address, "email_address");
+        checks.finish()?;
+
+        Ok(Self::new(role, name, email_address))
     }
 
-    /// Sets how the SMS phone number should be used.
+    /// Sets how the signer authenticates, or has the request delivered by SMS.
     ///
     /// # Arguments
     ///
-    /// * `sms_phone_number_type` - Whether to use SMS for authentication or delivery
-    pub fn sms_phone_number_type(mut self, sms_phone_number_type: SMSPhoneNumberType) -> Self {
-        self.sms_phone_number_type = Some(sms_phone_number_type);
+    /// * `auth` - PIN, SMS authentication or SMS delivery settings
+    pub fn auth(mut self, auth: SignerAuth) -> Self {
+        self.auth = Some(auth);
         self
     }
 }
@@ -746,7 +1524,7 @@ impl SubSigningOptions {
             default_type,
             draw: None,
             phone: None,
-            o_type: None,
+            typed: None,
             upload: None,
         }
     }
@@ -775,12 +1553,18 @@ impl SubSigningOptions {
     ///
     /// # Arguments
     ///
-    /// * `o_type` - True to enable typed signatures
-    pub fn o_type(mut self, o_type: bool) -> Self {
-        self.o_type = Some(o_type);
+    /// * `typed` - True to enable typed signatures
+    pub fn typed(mut self, typed: bool) -> Self {
+        self.typed = Some(typed);
         self
     }
 
+    /// Sets whether signers can type their signature using a font.
+    #[deprecated(note = "renamed to `typed`")]
+    pub fn o_type(self, o_type: bool) -> Self {
+        self.typed(o_type)
+    }
+
     /// Sets whether signers can upload an image of their signature.
     ///
     /// # Arguments
@@ -797,19 +1581,28 @@ impl SignatureRequestResponseCustomFieldBase {
     ///
     /// # Arguments
     ///
-    /// * `o_type` - Type of the form field (text, checkbox, etc.)
+    /// * `field_type` - Type of the form field (text, checkbox, etc.)
     /// * `name` - Name/identifier of the form field
-    pub fn new(o_type: SignatureRequestResponseCustomFieldBaseType, name: String) -> Self {
+    pub fn new(field_type: SignatureRequestResponseCustomFieldBaseType, name: String) -> Self {
         Self {
-            o_type,
+            field_type,
             name,
             required: None,
             api_id: None,
             editor: None,
             value: None,
+            validation_type: None,
+            validation_custom_regex: None,
+            validation_custom_message: None,
         }
     }
 
+    /// Returns the type of the form field.
+    #[deprecated(note = "use the `field_type` field")]
+    pub fn o_type(&self) -> &SignatureRequestResponseCustomFieldBaseType {
+        &self.field_type
+    }
+
     pub fn required(mut self, required: bool) -> Self {
         self.required = Some(required);
         self
@@ -834,6 +1627,31 @@ impl SignatureRequestResponseCustomFieldBase {
         self.value = Some(value);
         self
     }
+
+    /// Sets how the value of a text field is validated.
+    ///
+    /// # Arguments
+    ///
+    /// * `validation_type` - Kind of values the field accepts
+    pub fn validation_type(mut self, validation_type: TextValidationType) -> Self {
+        self.validation_type = Some(validation_type);
+        self
+    }
+
+    /// Validates the value of a text field against a regular expression.
+    ///
+    /// Also sets the validation type to [`TextValidationType::CustomRegex`].
+    ///
+    /// # Arguments
+    ///
+    /// * `regex` - Regular expression the value must match
+    /// * `message` - Message shown to the signer when the value does not match
+    pub fn validation_custom_regex(mut self, regex: String, message: String) -> Self {
+        self.validation_type = Some(TextValidationType::CustomRegex);
+        self.validation_custom_regex = Some(regex);
+        self.validation_custom_message = Some(message);
+        self
+    }
 }
 
 impl SignatureRequestResponseAttachment {
@@ -876,3 +1694,690 @@ impl SignatureRequestResponseAttachment {
         self
     }
 }
+
+impl SendWithFilesRequest {
+    /// Creates a new signature request with the minimum required fields.
+    ///
+    /// # Arguments
+    ///
+    /// * `signers` - List of signers who will receive the signature request
+    /// * `documents` - Files or file URLs of the documents to be signed
+    pub fn new(
+        signers: Vec<SubSignatureRequestSigner>,
+        documents: SignatureRequestDocuments,
+    ) -> Self {
+        Self {
+            signers,
+            documents,
+            allow_decline: None,
+            cc_email_addresses: None,
+            client_id: None,
+            custom_fields: None,
+            form_fields_per_document: None,
+            hide_text_tags: None,
+            is_eid: None,
+            message: None,
+            metadata: None,
+            signing_options: None,
+            signing_redirect_url: None,
+            subject: None,
+            test_mode: None,
+            title: None,
+            use_text_tags: None,
+        }
+    }
+
+    /// Adds a signer to the signature request.
+    ///
+    /// # Arguments
+    ///
+    /// * `signer` - Signer who will receive the signature request
+    pub fn signer(mut self, signer: SubSignatureRequestSigner) -> Self {
+        self.signers.push(signer);
+        self
+    }
+
+    /// Sets whether signers can decline to sign the document.
+    ///
+    /// # Arguments
+    ///
+    /// * `allow_decline` - If true, signers can decline; if false, they must either sign or ignore
+    pub fn allow_decline(mut self, allow_decline: bool) -> Self {
+        self.allow_decline = Some(allow_decline);
+        self
+    }
+
+    /// Sets the email addresses that receive copies of the signature request.
+    ///
+    /// # Arguments
+    ///
+    /// * `cc_email_addresses` - Email addresses of the CC recipients
+    pub fn cc_email_addresses(mut self, cc_email_addresses: Vec<String>) -> Self {
+        self.cc_email_addresses = Some(cc_email_addresses);
+        self
+    }
+
+    /// Sets the client ID for API apps.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - Client ID for your API app
+    pub fn client_id(mut self, client_id: impl Into<ClientId>) -> Self {
+        self.client_id = Some(client_id.into());
+        self
+    }
+
+    /// Sets custom form fields to pre-populate in the document.
+    ///
+    /// # Arguments
+    ///
+    /// * `custom_fields` - List of custom fields with default values
+    pub fn custom_fields(mut self, custom_fields: Vec<SubCustomField>) -> Self {
+        self.custom_fields = Some(custom_fields);
+        self
+    }
+
+    /// Sets the form fields placed on the documents.
+    ///
+    /// # Arguments
+    ///
+    /// * `form_fields_per_document` - Form fields with their positions on the documents
+    pub fn form_fields_per_document(mut self, form_fields_per_document: Vec<SubFormField>) -> Self {
+        self.form_fields_per_document = Some(form_fields_per_document);
+        self
+    }
+
+    /// Sets whether text tags are hidden once they are processed.
+    ///
+    /// # Arguments
+    ///
+    /// * `hide_text_tags` - True to hide text tags in the signed documents
+    pub fn hide_text_tags(mut self, hide_text_tags: bool) -> Self {
+        self.hide_text_tags = Some(hide_text_tags);
+        self
+    }
+
+    /// Sets whether to enable eIDAS compliance for European electronic signatures.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_eid` - True to enable eIDAS compliance
+    pub fn is_eid(mut self, is_eid: bool) -> Self {
+        self.is_eid = Some(is_eid);
+        self
+    }
+
+    /// Sets a custom message to include in signature request emails.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - Custom message text (supports basic HTML)
+    pub fn message(mut self, message: String) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sets custom metadata key-value pairs for the signature request.
+    ///
+    /// # Arguments
+    ///
+    /// * `metadata` - Key-value pairs for storing custom data, with string,
+    ///   integer or boolean values
+    pub fn metadata<K, V>(mut self, metadata: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: Into<String>,
+        V: Into<MetadataValue>,
+    {
+        self.metadata = Some(metadata::collect(metadata));
+        self
+    }
+
+    /// Sets configuration for available signature methods.
+    ///
+    /// # Arguments
+    ///
+    /// * `signing_options` - Configuration for signature method preferences
+    pub fn signing_options(mut self, signing_options: SubSigningOptions) -> Self {
+        self.signing_options = Some(signing_options);
+        self
+    }
+
+    /// Sets the URL to redirect signers to after they complete signing.
+    ///
+    /// # Arguments
+    ///
+    /// * `signing_redirect_url` - URL where signers will be redirected after signing
+    pub fn signing_redirect_url(mut self, signing_redirect_url: String) -> Self {
+        self.signing_redirect_url = Some(signing_redirect_url);
+        self
+    }
+
+    /// Sets the subject of signature request emails.
+    ///
+    /// # Arguments
+    ///
+    /// * `subject` - Email subject line
+    pub fn subject(mut self, subject: String) -> Self {
+        self.subject = Some(subject);
+        self
+    }
+
+    /// Sets whether this signature request should be created in test mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `test_mode` - True for test mode (documents won't be legally binding)
+    pub fn test_mode(mut self, test_mode: bool) -> Self {
+        self.test_mode = Some(test_mode);
+        self
+    }
+
+    /// Sets the title of the signature request.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - Title that will appear in the signature request
+    pub fn title(mut self, title: String) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets whether text tags in the documents are turned into form fields.
+    ///
+    /// # Arguments
+    ///
+    /// * `use_text_tags` - True to process text tags
+    pub fn use_text_tags(mut self, use_text_tags: bool) -> Self {
+        self.use_text_tags = Some(use_text_tags);
+        self
+    }
+}
+
+impl SubFormField {
+    /// Creates a form field at the top-left corner of the first document.
+    ///
+    /// Place it with [`position`](Self::position) and [`size`](Self::size).
+    ///
+    /// # Arguments
+    ///
+    /// * `field_type` - Type of the form field
+    /// * `api_id` - Unique identifier of the field within the request
+    /// * `signer` - Index of the signer the field is assigned to, or `sender`
+    pub fn new(field_type: SubFormFieldType, api_id: String, signer: String) -> Self {
+        Self {
+            field_type,
+            api_id,
+            signer,
+            document_index: 0,
+            page: None,
+            x: 0,
+            y: 0,
+            width: 0,
+            height: 0,
+            required: false,
+            name: None,
+            content: None,
+            content_url: None,
+            validation_type: None,
+            validation_custom_regex: None,
+            validation_custom_message: None,
+        }
+    }
+
+    /// Returns the type of the form field.
+    #[deprecated(note = "use the `field_type` field")]
+    pub fn o_type(&self) -> &SubFormFieldType {
+        &self.field_type
+    }
+
+    /// Sets where the field is placed.
+    ///
+    /// # Arguments
+    ///
+    /// * `document_index` - Index of the document the field is placed on
+    /// * `page` - Page the field is placed on, starting at 1
+    /// * `x` - Horizontal position of the field, in pixels
+    /// * `y` - Vertical position of the field, in pixels
+    pub fn position(mut self, document_index: u32, page: u32, x: u32, y: u32) -> Self {
+        self.document_index = document_index;
+        self.page = Some(page);
+        self.x = x;
+        self.y = y;
+        self
+    }
+
+    /// Sets the size of the field.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - Width of the field, in pixels
+    /// * `height` - Height of the field, in pixels
+    pub fn size(mut self, width: u32, height: u32) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Sets whether the field must be filled out.
+    ///
+    /// # Arguments
+    ///
+    /// * `required` - True if the field must be completed
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// Sets the display name of the field.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name shown for the field
+    pub fn name(mut self, name: String) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Sets the text and target of a hyperlink field.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - Text shown for the link
+    /// * `content_url` - URL the link points to
+    pub fn hyperlink(mut self, content: String, content_url: String) -> Self {
+        self.content = Some(content);
+        self.content_url = Some(content_url);
+        self
+    }
+
+    /// Sets how the value of a text field is validated.
+    ///
+    /// # Arguments
+    ///
+    /// * `validation_type` - Kind of values the field accepts
+    pub fn validation_type(mut self, validation_type: TextValidationType) -> Self {
+        self.validation_type = Some(validation_type);
+        self
+    }
+
+    /// Validates the value of a text field against a regular expression.
+    ///
+    /// Also sets the validation type to [`TextValidationType::CustomRegex`].
+    ///
+    /// # Arguments
+    ///
+    /// * `regex` - Regular expression the value must match
+    /// * `message` - Message shown to the signer when the value does not match
+    pub fn validation_custom_regex(mut self, regex: String, message: String) -> Self {
+        self.validation_type = Some(TextValidationType::CustomRegex);
+        self.validation_custom_regex = Some(regex);
+        self.validation_custom_message = Some(message);
+        self
+    }
+}
+
+impl SignatureRequestDocuments {
+    /// Creates documents uploaded with the request.
+    ///
+    /// # Arguments
+    ///
+    /// * `files` - File contents, paths or readers to upload
+    pub fn files<F: Into<UploadFile>>(files: impl IntoIterator<Item = F>) -> Self {
+        Self::Files(files.into_iter().map(Into::into).collect())
+    }
+
+    /// Creates documents the API downloads from URLs.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_urls` - Publicly accessible URLs to the documents
+    pub fn file_urls<U: Into<String>>(file_urls: impl IntoIterator<Item = U>) -> Self {
+        Self::FileUrls(file_urls.into_iter().map(Into::into).collect())
+    }
+
+    /// Takes the files to upload, leaving the URLs to be sent as fields.
+    pub(crate) fn take_files(&mut self) -> Option<Vec<UploadFile>> {
+        match self {
+            Self::Files(files) => Some(std::mem::take(files)),
+            Self::FileUrls(_) => None,
+        }
+    }
+}
+
+impl Serialize for SignatureRequestDocuments {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        // Files are attached as multipart parts rather than serialized.
+        let mut map = serializer.serialize_map(None)?;
+        if let Self::FileUrls(file_urls) = self {
+            map.serialize_entry("file_urls", file_urls)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for SignatureRequestDocuments {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Fields {
+            #[serde(default)]
+            file_urls: Option<Vec<String>>,
+        }
+
+        let fields = Fields::deserialize(deserializer)?;
+        Ok(match fields.file_urls {
+            Some(file_urls) => Self::FileUrls(file_urls),
+            None => Self::Files(Vec::new()),
+        })
+    }
+}
+
+impl SignerAuth {
+    /// Creates PIN authentication, checking that the PIN is 4 to 12 digits.
+    ///
+    /// # Arguments
+    ///
+    /// * `pin` - PIN the signer must enter before signing
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError`] if the PIN is not 4 to 12 digits.
+    pub fn try_pin(pin: String) -> Result<Self, ValidationError> {
+        let auth = Self::Pin(pin);
+        auth.validate()?;
+        Ok(auth)
+    }
+
+    /// Creates SMS authentication, checking that the phone number is in E.164
+    /// format.
+    ///
+    /// # Arguments
+    ///
+    /// * `phone_number` - Phone number in E.164 format, e.g. `+14155550100`
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError`] if the phone number is not in E.164 format.
+    pub fn try_sms_authentication(phone_number: String) -> Result<Self, ValidationError> {
+        let auth = Self::SmsAuthentication(phone_number);
+        auth.validate()?;
+        Ok(auth)
+    }
+
+    /// Creates SMS delivery, checking that the phone number is in E.164 format.
+    ///
+    /// # Arguments
+    ///
+    /// * `phone_number` - Phone number in E.164 format, e.g. `+14155550100`
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError`] if the phone number is not in E.164 format.
+    pub fn try_sms_delivery(phone_number: String) -> Result<Self, ValidationError> {
+        let auth = Self::SmsDelivery(phone_number);
+        auth.validate()?;
+        Ok(auth)
+    }
+}
+
+/// Fields a [`SignerAuth`] is sent as.
+#[derive(Serialize, Deserialize)]
+struct SignerAuthFields {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pin: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    sms_phone_number: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    sms_phone_number_type: Option<SMSPhoneNumberType>,
+}
+
+impl Serialize for SignerAuth {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let fields = match self {
+            Self::Pin(pin) => SignerAuthFields {
+                pin: Some(pin.clone()),
+                sms_phone_number: None,
+                sms_phone_number_type: None,
+            },
+            Self::SmsAuthentication(phone_number) => SignerAuthFields {
+                pin: None,
+                sms_phone_number: Some(phone_number.clone()),
+                sms_phone_number_type: Some(SMSPhoneNumberType::Authentication),
+            },
+            Self::SmsDelivery(phone_number) => SignerAuthFields {
+                pin: None,
+                sms_phone_number: Some(phone_number.clone()),
+                sms_phone_number_type: Some(SMSPhoneNumberType::Delivery),
+            },
+        };
+
+        fields.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SignerAuth {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let fields = SignerAuthFields::deserialize(deserializer)?;
+
+        match (
+            fields.pin,
+            fields.sms_phone_number,
+            fields.sms_phone_number_type,
+        ) {
+            (Some(pin), None, None) => Ok(Self::Pin(pin)),
+            (None, Some(phone_number), Some(SMSPhoneNumberType::Delivery)) => {
+                Ok(Self::SmsDelivery(phone_number))
+            }
+            (None, Some(phone_number), _) => Ok(Self::SmsAuthentication(phone_number)),
+            _ => Err(serde::de::Error::custom(
+                "expected either a pin or an sms_phone_number",
+            )),
+        }
+    }
+}
+
+impl SubSignatureRequestSigner {
+    /// Creates a new signer with the minimum required information.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Full name of the signer
+    /// * `email_address` - Email address where the signature request will be sent
+    pub fn new(name: String, email_address: String) -> Self {
+        Self {
+            name,
+            email_address,
+            order: None,
+            auth: None,
+        }
+    }
+
+    /// Creates a new signer, checking that the name is not blank and that the
+    /// email address is valid.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Full name of the signer
+    /// * `email_address` - Email address where the signature request will be sent
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError`] listing every invalid argument.
+    pub fn try_new(name: String, email_address: String) -> Result<Self, ValidationError> {
+        let mut checks = Checks::new();
+        checks.not_blank(&name, "name");
+        checks.email(&email_address, "email_address");
+        checks.finish()?;
+
+        Ok(Self::new(name, email_address))
+    }
+
+    /// Sets the signing order for this signer.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - Position of the signer in a sequential signing workflow
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets how the signer authenticates, or has the request delivered by SMS.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` - PIN, SMS authentication or SMS delivery settings
+    pub fn auth(mut self, auth: SignerAuth) -> Self {
+        self.auth = Some(auth);
+        self
+    }
+}
+
+impl Validate for SendWithFilesRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut checks = Checks::new();
+
+        checks.check(!self.signers.is_empty(), "signers", "must not be empty");
+        for (index, signer) in self.signers.iter().enumerate() {
+            checks.nested(format!("signers[{index}]"), signer.validate());
+        }
+        match &self.documents {
+            SignatureRequestDocuments::Files(files) => {
+                checks.check(!files.is_empty(), "files", "must not be empty");
+            }
+            SignatureRequestDocuments::FileUrls(file_urls) => {
+                checks.check(!file_urls.is_empty(), "file_urls", "must not be empty");
+                for (index, file_url) in file_urls.iter().enumerate() {
+                    checks.not_blank(file_url, format!("file_urls[{index}]"));
+                }
+            }
+        }
+        for (index, email_address) in self.cc_email_addresses.iter().flatten().enumerate() {
+            checks.email(email_address, format!("cc_email_addresses[{index}]"));
+        }
+        for (index, field) in self.custom_fields.iter().flatten().enumerate() {
+            checks.not_blank(&field.name, format!("custom_fields[{index}][name]"));
+        }
+        for (index, field) in self.form_fields_per_document.iter().flatten().enumerate() {
+            checks.nested(
+                format!("form_fields_per_document[{index}]"),
+                field.validate(),
+            );
+        }
+        metadata::check(&mut checks, self.metadata.as_ref());
+
+        checks.finish()
+    }
+}
+
+impl Validate for SubFormField {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut checks = Checks::new();
+
+        checks.not_blank(&self.api_id, "api_id");
+        checks.not_blank(&self.signer, "signer");
+        checks.check(self.width > 0, "width", "must be greater than 0");
+        checks.check(self.height > 0, "height", "must be greater than 0");
+        let is_hyperlink = self.field_type == SubFormFieldType::Hyperlink;
+        checks.check(
+            !is_hyperlink
+                || self
+                    .content
+                    .as_deref()
+                    .is_some_and(|c| !c.trim().is_empty()),
+            "content",
+            "is required for hyperlink fields",
+        );
+        checks.check(
+            !is_hyperlink
+                || self
+                    .content_url
+                    .as_deref()
+                    .is_some_and(|c| !c.trim().is_empty()),
+            "content_url",
+            "is required for hyperlink fields",
+        );
+        checks.check(
+            self.validation_type != Some(TextValidationType::CustomRegex)
+                || self.validation_custom_regex.is_some(),
+            "validation_custom_regex",
+            "is required for custom_regex validation",
+        );
+
+        checks.finish()
+    }
+}
+
+impl Validate for SignerAuth {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut checks = Checks::new();
+
+        match self {
+            Self::Pin(pin) => checks.pin(pin, "pin"),
+            Self::SmsAuthentication(phone_number) | Self::SmsDelivery(phone_number) => {
+                checks.phone_number(phone_number, "sms_phone_number");
+            }
+        }
+
+        checks.finish()
+    }
+}
+
+impl Validate for SubSignatureRequestSigner {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut checks = Checks::new();
+
+        checks.not_blank(&self.name, "name");
+        checks.email(&self.email_address, "email_address");
+        if let Some(auth) = &self.auth {
+            checks.nested_flat(auth.validate());
+        }
+
+        checks.finish()
+    }
+}
+
+impl Validate for SendWithTemplateRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut checks = Checks::new();
+
+        checks.check(!self.signers.is_empty(), "signers", "must not be empty");
+        for (index, signer) in self.signers.iter().enumerate() {
+            checks.nested(format!("signers[{index}]"), signer.validate());
+        }
+        checks.check(
+            !self.template_ids.is_empty(),
+            "template_ids",
+            "must not be empty",
+        );
+        for (index, template_id) in self.template_ids.iter().enumerate() {
+            checks.not_blank(template_id.as_str(), format!("template_ids[{index}]"));
+        }
+        for (index, cc) in self.ccs.iter().flatten().enumerate() {
+            checks.not_blank(&cc.role, format!("ccs[{index}][role]"));
+            checks.email(&cc.email, format!("ccs[{index}][email]"));
+        }
+        for (index, field) in self.custom_fields.iter().flatten().enumerate() {
+            checks.not_blank(&field.name, format!("custom_fields[{index}][name]"));
+        }
+        metadata::check(&mut checks, self.metadata.as_ref());
+
+        checks.finish()
+    }
+}
+
+impl Validate for SubSignatureRequestTemplateSigner {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut checks = Checks::new();
+
+        checks.not_blank(&self.role, "role");
+        checks.not_blank(&self.name, "name");
+        checks.email(&self.email_address, "email_address");
+        if let Some(auth) = &self.auth {
+            checks.nested_flat(auth.validate());
+        }
+
+        checks.finish()
+    }
+}
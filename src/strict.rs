@@ -0,0 +1,91 @@
+//! Strict parsing of responses, for catching drift between the API and the
+//! models of this crate.
+//!
+//! Responses are parsed leniently by default: fields the models do not know are
+//! skipped, or kept in the `extra` map of the main response types. A client
+//! built with
+//! [`strict_parsing`](crate::client::DropboxSignClientBuilder::strict_parsing)
+//! rejects such fields instead and reports the path of the first field that does
+//! not match the models.
+
+use crate::client::DropboxSignClientError;
+use serde::de::{DeserializeOwned, Error};
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+use std::cell::Cell;
+use std::collections::HashMap;
+
+thread_local! {
+    /// Whether the response being parsed on this thread is parsed strictly
+    static STRICT: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Deserializes `value` into `T`, rejecting fields `T` does not model when
+/// `strict` is set.
+///
+/// Paths in errors are prefixed with `path`, the location of `value` within the
+/// response body.
+pub(crate) fn from_value<T: DeserializeOwned>(
+    value: &Value,
+    path: &str,
+    strict: bool,
+) -> Result<T, DropboxSignClientError> {
+    if !strict {
+        return Ok(T::deserialize(value)?);
+    }
+
+    let mut unknown = Vec::new();
+    let mut record = |ignored: serde_ignored::Path<'_>| {
+        unknown.push(join(path, &ignored.to_string()));
+    };
+    let deserializer = serde_ignored::Deserializer::new(value, &mut record);
+
+    STRICT.with(|flag| flag.set(true));
+    let result = serde_path_to_error::deserialize(deserializer);
+    STRICT.with(|flag| flag.set(false));
+
+    let parsed = result.map_err(|error| DropboxSignClientError::ModelMismatch {
+        path: join(path, &error.path().to_string()),
+        message: error.inner().to_string(),
+    })?;
+    if !unknown.is_empty() {
+        return Err(DropboxSignClientError::UnknownFields(unknown));
+    }
+
+    Ok(parsed)
+}
+
+/// Deserializes the `extra` map of a response type, failing during strict
+/// parsing if it holds any field.
+pub(crate) fn extra<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<HashMap<String, Value>, D::Error> {
+    let extra = HashMap::<String, Value>::deserialize(deserializer)?;
+    if STRICT.with(Cell::get) && !extra.is_empty() {
+        let mut fields: Vec<_> = extra.keys().map(String::as_str).collect();
+        fields.sort_unstable();
+        return Err(D::Error::custom(format!(
+            "unknown fields: {}",
+            fields.join(", ")
+        )));
+    }
+
+    Ok(extra)
+}
+
+/// Joins the location of a value and a path within it, dropping the `?`
+/// segments that stand for `Option`s.
+fn join(path: &str, inner: &str) -> String {
+    let joined = path
+        .split('.')
+        .chain(inner.split('.'))
+        .filter(|segment| !segment.is_empty() && *segment != "?")
+        .collect::<Vec<_>>()
+        .join(".");
+
+    if joined.is_empty() {
+        ".".to_string()
+    } else {
+        joined
+    }
+}
@@ -0,0 +1,329 @@
+//! Data models and types for team operations.
+//!
+//! This module contains the data structures needed for reading and managing
+//! Dropbox Sign teams and their members through the API.
+
+use crate::account::AccountResponse;
+use crate::id::AccountId;
+use crate::validate::{Checks, Validate, ValidationError};
+use crate::{ListItem, Page};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Request body for creating a team.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateTeamRequest {
+    /// Name of the new team
+    pub name: String,
+}
+
+/// Request body for updating a team.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpdateTeamRequest {
+    /// New name for the team
+    pub name: String,
+}
+
+/// Request body for adding a member to a team.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AddTeamMemberRequest {
+    /// Account ID or email address of the member to add
+    #[serde(flatten)]
+    pub member: TeamMemberIdentifier,
+    /// Role the member is given on the team (default: Member)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<TeamMemberRole>,
+}
+
+/// Request body for removing a member from a team.
+///
+/// Documents owned by the removed member can be handed over to another account,
+/// and the member can be moved to another team instead of being dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::team::*;
+///
+/// let request = RemoveTeamMemberRequest::new(
+///     TeamMemberIdentifier::EmailAddress("leaver@example.com".to_string())
+/// )
+/// .new_owner_email_address("manager@example.com".to_string());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RemoveTeamMemberRequest {
+    /// Account ID or email address of the member to remove
+    #[serde(flatten)]
+    pub member: TeamMemberIdentifier,
+    /// Email address of the account that takes over the member's documents
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub new_owner_email_address: Option<String>,
+    /// Identifier of the team the member is moved to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub new_team_id: Option<String>,
+    /// Role the member is given on the new team
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub new_role: Option<TeamMemberRole>,
+}
+
+/// Identifies an account either by its ID or by its email address.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TeamMemberIdentifier {
+    /// Identify the member by account ID
+    AccountId(AccountId),
+    /// Identify the member by email address
+    EmailAddress(String),
+}
+
+/// Roles a member can have on a team.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TeamMemberRole {
+    /// Regular team member
+    Member,
+    /// Member with access to API settings
+    Developer,
+    /// Member who can manage other members
+    #[serde(rename = "Team Manager")]
+    TeamManager,
+    /// Team administrator
+    Admin,
+}
+
+/// Complete response data for a team.
+///
+/// Contains the team's current members as well as pending invitations.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TeamResponse {
+    /// Name of the team
+    pub name: String,
+    /// Accounts that are members of the team
+    #[serde(default)]
+    pub accounts: Vec<AccountResponse>,
+    /// Accounts that have been invited but have not joined yet
+    #[serde(default)]
+    pub invited_accounts: Vec<AccountResponse>,
+    /// Email addresses that have been invited but do not have an account yet
+    #[serde(default)]
+    pub invited_emails: Vec<String>,
+    /// Fields returned by the API that this crate does not model yet
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "crate::strict::extra",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    pub extra: HashMap<String, Value>,
+}
+
+/// A page of team members.
+pub type TeamMembersResponse = Page<TeamMemberResponse>;
+
+impl ListItem for TeamMemberResponse {
+    const LIST_KEY: &'static str = "team_members";
+}
+
+/// A single member of a team.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TeamMemberResponse {
+    /// Unique identifier of the member's account
+    pub account_id: AccountId,
+    /// Email address of the member
+    pub email_address: String,
+    /// Role of the member on the team
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+}
+
+/// A page of sub-teams.
+pub type SubTeamsResponse = Page<SubTeamResponse>;
+
+impl ListItem for SubTeamResponse {
+    const LIST_KEY: &'static str = "sub_teams";
+}
+
+/// A team nested under another team.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubTeamResponse {
+    /// Unique identifier of the sub-team
+    pub team_id: String,
+    /// Name of the sub-team
+    pub name: String,
+}
+
+/// Pending team invitations.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TeamInvitesResponse {
+    /// Invitations that have been sent
+    pub team_invites: Vec<TeamInviteResponse>,
+}
+
+/// A single invitation to join a team.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TeamInviteResponse {
+    /// Email address the invitation was sent to
+    pub email_address: String,
+    /// Identifier of the team the invitation is for
+    pub team_id: String,
+    /// Role the invitee is given on joining
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+    /// Unix timestamp when the invitation was sent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sent_at: Option<u64>,
+    /// Unix timestamp when the invitation was accepted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redeemed_at: Option<u64>,
+    /// Unix timestamp when the invitation expires
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<u64>,
+}
+
+impl CreateTeamRequest {
+    /// Creates a new request for a team with the given name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the new team
+    pub fn new(name: String) -> Self {
+        Self { name }
+    }
+}
+
+impl UpdateTeamRequest {
+    /// Creates a new request to rename the team.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - New name for the team
+    pub fn new(name: String) -> Self {
+        Self { name }
+    }
+}
+
+impl AddTeamMemberRequest {
+    /// Creates a new request to add the given member with the default role.
+    ///
+    /// # Arguments
+    ///
+    /// * `member` - Account ID or email address of the member to add
+    pub fn new(member: TeamMemberIdentifier) -> Self {
+        Self { member, role: None }
+    }
+
+    /// Sets the role the member is given on the team.
+    ///
+    /// # Arguments
+    ///
+    /// * `role` - Role on the team
+    pub fn role(mut self, role: TeamMemberRole) -> Self {
+        self.role = Some(role);
+        self
+    }
+}
+
+impl RemoveTeamMemberRequest {
+    /// Creates a new request to remove the given member.
+    ///
+    /// # Arguments
+    ///
+    /// * `member` - Account ID or email address of the member to remove
+    pub fn new(member: TeamMemberIdentifier) -> Self {
+        Self {
+            member,
+            new_owner_email_address: None,
+            new_team_id: None,
+            new_role: None,
+        }
+    }
+
+    /// Sets the account that takes over the member's documents.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_owner_email_address` - Email address of the new document owner
+    pub fn new_owner_email_address(mut self, new_owner_email_address: String) -> Self {
+        self.new_owner_email_address = Some(new_owner_email_address);
+        self
+    }
+
+    /// Sets the team the member is moved to.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_team_id` - Identifier of the destination team
+    pub fn new_team_id(mut self, new_team_id: String) -> Self {
+        self.new_team_id = Some(new_team_id);
+        self
+    }
+
+    /// Sets the role the member is given on the new team.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_role` - Role on the destination team
+    pub fn new_role(mut self, new_role: TeamMemberRole) -> Self {
+        self.new_role = Some(new_role);
+        self
+    }
+}
+
+impl TeamMemberIdentifier {
+    /// Checks that the identifier is not blank, and is an email address when it
+    /// identifies the member by email.
+    fn check(&self, checks: &mut Checks) {
+        match self {
+            Self::AccountId(account_id) => checks.not_blank(account_id.as_str(), "account_id"),
+            Self::EmailAddress(email_address) => checks.email(email_address, "email_address"),
+        }
+    }
+}
+
+impl Validate for CreateTeamRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut checks = Checks::new();
+
+        checks.not_blank(&self.name, "name");
+
+        checks.finish()
+    }
+}
+
+impl Validate for UpdateTeamRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut checks = Checks::new();
+
+        checks.not_blank(&self.name, "name");
+
+        checks.finish()
+    }
+}
+
+impl Validate for AddTeamMemberRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut checks = Checks::new();
+
+        self.member.check(&mut checks);
+
+        checks.finish()
+    }
+}
+
+impl Validate for RemoveTeamMemberRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut checks = Checks::new();
+
+        self.member.check(&mut checks);
+        if let Some(email_address) = &self.new_owner_email_address {
+            checks.email(email_address, "new_owner_email_address");
+        }
+        checks.check(
+            self.new_role.is_none() || self.new_team_id.is_some(),
+            "new_role",
+            "requires new_team_id",
+        );
+
+        checks.finish()
+    }
+}
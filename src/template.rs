@@ -0,0 +1,242 @@
+//! Data models and types for template operations.
+//!
+//! This module contains the data structures returned by the Dropbox Sign API
+//! for templates, and the roles and merge fields that template provisioning
+//! requests define.
+
+use crate::id::{AccountId, TemplateId};
+use crate::metadata::Metadata;
+use crate::validate::{Checks, Validate, ValidationError};
+use crate::{ListItem, Page};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// A signer role defined when a template is created.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::template::*;
+///
+/// let roles = vec![
+///     SubTemplateRole::new("Client".to_string()).order(0),
+///     SubTemplateRole::new("Witness".to_string()).order(1),
+/// ];
+/// let cc_roles = vec![SubCCRole::new("Accounting".to_string())];
+/// let merge_fields = vec![
+///     SubMergeField::new("Full Name".to_string(), SubMergeFieldType::Text),
+///     SubMergeField::new("Is Registered?".to_string(), SubMergeFieldType::Checkbox),
+/// ];
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubTemplateRole {
+    /// Name of the role
+    pub name: String,
+    /// Signing order of the role (for sequential signing workflows)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<i32>,
+}
+
+/// A CC role defined when a template is created.
+///
+/// Sent as the bare role name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SubCCRole {
+    /// Name of the role
+    pub name: String,
+}
+
+/// A merge field defined when a template is created.
+///
+/// Merge fields are filled in by the sender through custom fields when a
+/// signature request is sent from the template.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubMergeField {
+    /// Name of the merge field
+    pub name: String,
+    /// Type of the merge field
+    #[serde(rename = "type")]
+    pub field_type: SubMergeFieldType,
+}
+
+/// Types of merge fields.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SubMergeFieldType {
+    /// Text merged into the document
+    Text,
+    /// Checkbox checked or unchecked by the sender
+    Checkbox,
+}
+
+/// Complete response data for a template.
+///
+/// Contains the template's title, roles and the accounts that can use it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TemplateResponse {
+    /// Unique identifier for this template
+    pub template_id: TemplateId,
+    /// Title of the template
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Default message used in signature request emails
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Unix timestamp when the template was last updated
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<u64>,
+    /// Whether the template was created through embedded templates
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_embedded: Option<bool>,
+    /// Whether the caller created this template
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_creator: Option<bool>,
+    /// Whether the caller can edit this template
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub can_edit: Option<bool>,
+    /// Whether the template is locked because of a plan change
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_locked: Option<bool>,
+    /// Custom metadata key-value pairs
+    #[serde(default)]
+    pub metadata: Metadata,
+    /// Signer roles defined by the template
+    #[serde(default)]
+    pub signer_roles: Vec<TemplateResponseSignerRole>,
+    /// CC roles defined by the template
+    #[serde(default)]
+    pub cc_roles: Vec<TemplateResponseCCRole>,
+    /// Accounts that have access to the template
+    #[serde(default)]
+    pub accounts: Vec<TemplateResponseAccount>,
+    /// Fields returned by the API that this crate does not model yet
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "crate::strict::extra",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    pub extra: HashMap<String, Value>,
+}
+
+/// A signer role defined by a template.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TemplateResponseSignerRole {
+    /// Name of the role
+    pub name: String,
+    /// Signing order of the role (for sequential signing workflows)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<i32>,
+}
+
+/// A CC role defined by a template.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TemplateResponseCCRole {
+    /// Name of the role
+    pub name: String,
+}
+
+/// An account that has access to a template.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TemplateResponseAccount {
+    /// Unique identifier of the account
+    pub account_id: AccountId,
+    /// Email address of the account
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<String>,
+    /// Whether the account is locked
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_locked: Option<bool>,
+    /// Whether the account has a paid Dropbox Sign plan
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_paid_hs: Option<bool>,
+    /// Whether the account has a paid Dropbox Fax plan
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_paid_hf: Option<bool>,
+}
+
+/// A page of templates.
+pub type TemplatesResponse = Page<TemplateResponse>;
+
+impl ListItem for TemplateResponse {
+    const LIST_KEY: &'static str = "templates";
+}
+
+impl SubTemplateRole {
+    /// Creates a new signer role with the given name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the role
+    pub fn new(name: String) -> Self {
+        Self { name, order: None }
+    }
+
+    /// Sets the signing order of the role.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - Position of the role in the signing order, starting at 0
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+}
+
+impl SubCCRole {
+    /// Creates a new CC role with the given name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the role
+    pub fn new(name: String) -> Self {
+        Self { name }
+    }
+}
+
+impl SubMergeField {
+    /// Creates a new merge field.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the merge field
+    /// * `field_type` - Type of the merge field
+    pub fn new(name: String, field_type: SubMergeFieldType) -> Self {
+        Self { name, field_type }
+    }
+}
+
+impl Validate for SubTemplateRole {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut checks = Checks::new();
+
+        checks.not_blank(&self.name, "name");
+        if let Some(order) = self.order {
+            checks.check(order >= 0, "order", "must not be negative");
+        }
+
+        checks.finish()
+    }
+}
+
+impl Validate for SubCCRole {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut checks = Checks::new();
+
+        checks.not_blank(&self.name, "name");
+
+        checks.finish()
+    }
+}
+
+impl Validate for SubMergeField {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut checks = Checks::new();
+
+        checks.not_blank(&self.name, "name");
+
+        checks.finish()
+    }
+}
@@ -0,0 +1,410 @@
+//! Test helpers for code built on this client.
+//!
+//! Enabled with the `test-util` feature. [`fixtures`] contains canned API response
+//! bodies and [`MockDropboxSign`] serves them from a local mock server, so signing
+//! flows can be integration-tested offline.
+
+pub mod fixtures;
+
+use crate::DropboxSignClient;
+use crate::client::DropboxSignClientBuildError;
+use crate::events::{find_bytes, multipart_boundary, split_bytes};
+use wiremock::matchers::{method, path, path_regex};
+use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+/// API key the mock server's clients authenticate with.
+///
+/// The event hash in [`fixtures::EVENT_CALLBACK`] is signed with this key.
+pub const TEST_API_KEY: &str = "test-api-key";
+
+/// Local mock of the Dropbox Sign API.
+///
+/// Every endpoint the client supports is pre-registered and answers with the
+/// matching fixture. Register additional mocks on [`MockDropboxSign::server`] to
+/// override a response: the defaults are mounted at the lowest priorities, so
+/// any mock mounted with wiremock's default priority wins over them.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::test_util::MockDropboxSign;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let mock = MockDropboxSign::start().await;
+/// let client = mock.client()?;
+///
+/// let (account, _) = client.get_account().await?;
+/// assert_eq!(account.email_address.as_deref(), Some("me@dropboxsign.com"));
+/// # Ok(())
+/// # }
+/// ```
+pub struct MockDropboxSign {
+    server: MockServer,
+}
+
+impl MockDropboxSign {
+    /// Starts a mock server with every endpoint registered.
+    pub async fn start() -> Self {
+        let server = MockServer::start().await;
+
+        for (verb, route, body) in ROUTES {
+            let matcher = Mock::given(method(*verb));
+            let mock = match route {
+                Route::Exact(route) => matcher.and(path(*route)),
+                Route::Pattern(pattern) => matcher.and(path_regex(*pattern)),
+            };
+
+            mock.respond_with(response(body))
+                .with_priority(route.priority())
+                .mount(&server)
+                .await;
+        }
+
+        Self { server }
+    }
+
+    /// Returns the base URL of the mock server.
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// Returns the underlying mock server for registering custom mocks.
+    pub fn server(&self) -> &MockServer {
+        &self.server
+    }
+
+    /// Creates a client that sends its requests to the mock server.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DropboxSignClientBuildError` if the HTTP client cannot be built.
+    pub fn client(&self) -> Result<DropboxSignClient, DropboxSignClientBuildError> {
+        DropboxSignClient::builder()
+            .api_key(TEST_API_KEY)
+            .base_url(self.uri())
+            .build()
+    }
+
+    /// Returns the requests the mock server has received so far.
+    pub async fn received_requests(&self) -> Vec<Request> {
+        self.server.received_requests().await.unwrap_or_default()
+    }
+}
+
+/// Returns the text fields of a `multipart/form-data` request in the order they
+/// were sent.
+///
+/// File parts are left out. Returns no fields if the request body is not
+/// multipart, e.g. a request without file uploads sent as JSON.
+///
+/// # Examples
+///
+/// ```
+/// use dropboxsign_client::metadata::MetadataValue;
+/// use dropboxsign_client::signature_request::*;
+/// use dropboxsign_client::test_util::{MockDropboxSign, form_fields};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mock = MockDropboxSign::start().await;
+/// let request = SendWithFilesRequest::new(
+///     vec![SubSignatureRequestSigner::new(
+///         "John Doe".to_string(),
+///         "john@example.com".to_string(),
+///     )],
+///     SignatureRequestDocuments::files([b"%PDF-1.4".to_vec()]),
+/// )
+/// .test_mode(true)
+/// .allow_decline(false)
+/// .metadata([
+///     ("customer_id", MetadataValue::from("1234")),
+///     (
+///         "address",
+///         MetadataValue::from(serde_json::json!({ "city": "Berlin" })),
+///     ),
+/// ]);
+/// mock.client()?.send_with_files(request).await?;
+///
+/// let fields = form_fields(&mock.received_requests().await[0]);
+/// let field = |name: &str| {
+///     fields
+///         .iter()
+///         .find(|(field, _)| field == name)
+///         .map(|(_, value)| value.as_str())
+/// };
+/// assert_eq!(field("signers[0][name]"), Some("John Doe"));
+/// assert_eq!(field("signers[0][email_address]"), Some("john@example.com"));
+/// assert_eq!(field("test_mode"), Some("1"));
+/// assert_eq!(field("allow_decline"), Some("0"));
+/// assert_eq!(field("metadata[customer_id]"), Some("1234"));
+/// assert_eq!(field("metadata[address][city]"), Some("Berlin"));
+/// assert_eq!(field("files[0]"), None);
+/// # Ok(())
+/// # }
+/// ```
+pub fn form_fields(request: &Request) -> Vec<(String, String)> {
+    let Some(boundary) = request
+        .headers
+        .get("content-type")
+        .and_then(|content_type| content_type.to_str().ok())
+        .and_then(multipart_boundary)
+    else {
+        return Vec::new();
+    };
+    let delimiter = format!("--{boundary}");
+
+    split_bytes(&request.body, delimiter.as_bytes())
+        .skip(1)
+        .filter_map(|part| {
+            let part = part.strip_prefix(b"\r\n")?;
+            let header_end = find_bytes(part, b"\r\n\r\n")?;
+            let headers = std::str::from_utf8(&part[..header_end]).ok()?;
+            let disposition = headers.lines().find(|line| {
+                line.to_ascii_lowercase()
+                    .starts_with("content-disposition:")
+            })?;
+            if disposition.contains("filename=") {
+                return None;
+            }
+
+            let (_, name) = disposition.split_once("name=\"")?;
+            let name = name.split('"').next()?;
+            let content = &part[header_end + 4..];
+            let content = content.strip_suffix(b"\r\n").unwrap_or(content);
+            Some((
+                name.to_string(),
+                String::from_utf8_lossy(content).into_owned(),
+            ))
+        })
+        .collect()
+}
+
+/// Path matcher for a pre-registered endpoint.
+enum Route {
+    /// Matches the path exactly
+    Exact(&'static str),
+    /// Matches paths against a regular expression
+    Pattern(&'static str),
+}
+
+impl Route {
+    /// Exact routes win over patterns, so `/fax/list` is not taken for a fax ID.
+    /// Both rank below wiremock's default priority, so user mocks win.
+    fn priority(&self) -> u8 {
+        match self {
+            Self::Exact(_) => 250,
+            Self::Pattern(_) => 255,
+        }
+    }
+}
+
+/// Body of a pre-registered endpoint.
+enum Body {
+    /// JSON response body
+    Json(&'static str),
+    /// File download
+    Pdf,
+    /// Successful response without a payload
+    Empty,
+}
+
+/// Builds the response for a pre-registered endpoint.
+fn response(body: &Body) -> ResponseTemplate {
+    let template = ResponseTemplate::new(200);
+    match body {
+        Body::Json(json) => template.set_body_raw(*json, "application/json"),
+        Body::Pdf => template.set_body_raw(fixtures::PDF, "application/pdf"),
+        Body::Empty => template,
+    }
+}
+
+/// Endpoints registered by [`MockDropboxSign::start`].
+const ROUTES: &[(&str, Route, Body)] = &[
+    (
+        "GET",
+        Route::Exact("/signature_request/list"),
+        Body::Json(fixtures::SIGNATURE_REQUEST_LIST),
+    ),
+    (
+        "GET",
+        Route::Pattern("^/signature_request/files/[^/]+$"),
+        Body::Pdf,
+    ),
+    (
+        "GET",
+        Route::Pattern("^/signature_request/[^/]+$"),
+        Body::Json(fixtures::SIGNATURE_REQUEST),
+    ),
+    (
+        "POST",
+        Route::Exact("/signature_request/send_with_template"),
+        Body::Json(fixtures::SIGNATURE_REQUEST),
+    ),
+    (
+        "POST",
+        Route::Exact("/signature_request/send"),
+        Body::Json(fixtures::SIGNATURE_REQUEST),
+    ),
+    (
+        "POST",
+        Route::Pattern("^/signature_request/cancel/[^/]+$"),
+        Body::Empty,
+    ),
+    (
+        "POST",
+        Route::Exact("/unclaimed_draft/create"),
+        Body::Json(fixtures::UNCLAIMED_DRAFT),
+    ),
+    (
+        "POST",
+        Route::Exact("/unclaimed_draft/create_embedded"),
+        Body::Json(fixtures::UNCLAIMED_DRAFT),
+    ),
+    (
+        "GET",
+        Route::Pattern("^/embedded/sign_url/[^/]+$"),
+        Body::Json(fixtures::EMBEDDED_SIGN_URL),
+    ),
+    (
+        "POST",
+        Route::Pattern("^/embedded/edit_url/[^/]+$"),
+        Body::Json(fixtures::EMBEDDED_EDIT_URL),
+    ),
+    (
+        "GET",
+        Route::Exact("/account"),
+        Body::Json(fixtures::ACCOUNT),
+    ),
+    (
+        "POST",
+        Route::Exact("/account"),
+        Body::Json(fixtures::ACCOUNT),
+    ),
+    (
+        "POST",
+        Route::Exact("/account/create"),
+        Body::Json(fixtures::ACCOUNT_CREATE),
+    ),
+    (
+        "POST",
+        Route::Exact("/account/verify"),
+        Body::Json(fixtures::ACCOUNT_VERIFY),
+    ),
+    ("GET", Route::Exact("/team"), Body::Json(fixtures::TEAM)),
+    (
+        "POST",
+        Route::Exact("/team/create"),
+        Body::Json(fixtures::TEAM),
+    ),
+    ("PUT", Route::Exact("/team"), Body::Json(fixtures::TEAM)),
+    ("DELETE", Route::Exact("/team/destroy"), Body::Empty),
+    (
+        "POST",
+        Route::Exact("/team/add_member"),
+        Body::Json(fixtures::TEAM),
+    ),
+    (
+        "POST",
+        Route::Exact("/team/remove_member"),
+        Body::Json(fixtures::TEAM),
+    ),
+    (
+        "GET",
+        Route::Pattern("^/team/members/[^/]+$"),
+        Body::Json(fixtures::TEAM_MEMBERS),
+    ),
+    (
+        "GET",
+        Route::Pattern("^/team/sub_teams/[^/]+$"),
+        Body::Json(fixtures::SUB_TEAMS),
+    ),
+    (
+        "GET",
+        Route::Exact("/team/invites"),
+        Body::Json(fixtures::TEAM_INVITES),
+    ),
+    (
+        "GET",
+        Route::Exact("/api_app/list"),
+        Body::Json(fixtures::API_APP_LIST),
+    ),
+    (
+        "GET",
+        Route::Pattern("^/api_app/[^/]+$"),
+        Body::Json(fixtures::API_APP),
+    ),
+    (
+        "POST",
+        Route::Exact("/api_app"),
+        Body::Json(fixtures::API_APP),
+    ),
+    (
+        "PUT",
+        Route::Pattern("^/api_app/[^/]+$"),
+        Body::Json(fixtures::API_APP),
+    ),
+    ("DELETE", Route::Pattern("^/api_app/[^/]+$"), Body::Empty),
+    (
+        "GET",
+        Route::Exact("/fax/list"),
+        Body::Json(fixtures::FAX_LIST),
+    ),
+    ("GET", Route::Pattern("^/fax/files/[^/]+$"), Body::Pdf),
+    (
+        "GET",
+        Route::Pattern("^/fax/[^/]+$"),
+        Body::Json(fixtures::FAX),
+    ),
+    ("DELETE", Route::Pattern("^/fax/[^/]+$"), Body::Empty),
+    (
+        "POST",
+        Route::Exact("/fax_line/create"),
+        Body::Json(fixtures::FAX_LINE),
+    ),
+    (
+        "GET",
+        Route::Exact("/fax_line/list"),
+        Body::Json(fixtures::FAX_LINE_LIST),
+    ),
+    (
+        "GET",
+        Route::Exact("/fax_line/area_codes"),
+        Body::Json(fixtures::FAX_LINE_AREA_CODES),
+    ),
+    (
+        "GET",
+        Route::Exact("/fax_line"),
+        Body::Json(fixtures::FAX_LINE),
+    ),
+    ("DELETE", Route::Exact("/fax_line"), Body::Empty),
+    (
+        "PUT",
+        Route::Exact("/fax_line/add_user"),
+        Body::Json(fixtures::FAX_LINE),
+    ),
+    (
+        "PUT",
+        Route::Exact("/fax_line/remove_user"),
+        Body::Json(fixtures::FAX_LINE),
+    ),
+    (
+        "GET",
+        Route::Exact("/bulk_send_job/list"),
+        Body::Json(fixtures::BULK_SEND_JOB_LIST),
+    ),
+    (
+        "POST",
+        Route::Exact("/report/create"),
+        Body::Json(fixtures::REPORT),
+    ),
+    (
+        "GET",
+        Route::Exact("/template/list"),
+        Body::Json(fixtures::TEMPLATE_LIST),
+    ),
+    (
+        "GET",
+        Route::Pattern("^/template/[^/]+$"),
+        Body::Json(fixtures::TEMPLATE),
+    ),
+];
@@ -0,0 +1,84 @@
+//! Canned response bodies for every API response type.
+//!
+//! Each constant holds the JSON body the API returns for an endpoint.
+
+/// Response body of `GET /account`.
+pub const ACCOUNT: &str = include_str!("fixtures/account.json");
+
+/// Response body of `POST /account/create`.
+pub const ACCOUNT_CREATE: &str = include_str!("fixtures/account_create.json");
+
+/// Response body of `POST /account/verify`.
+pub const ACCOUNT_VERIFY: &str = include_str!("fixtures/account_verify.json");
+
+/// Response body of `GET /api_app/{client_id}`.
+pub const API_APP: &str = include_str!("fixtures/api_app.json");
+
+/// Response body of `GET /api_app/list`.
+pub const API_APP_LIST: &str = include_str!("fixtures/api_app_list.json");
+
+/// Response body of `GET /bulk_send_job/list`.
+pub const BULK_SEND_JOB_LIST: &str = include_str!("fixtures/bulk_send_job_list.json");
+
+/// Response body of `POST /embedded/edit_url/{template_id}`.
+pub const EMBEDDED_EDIT_URL: &str = include_str!("fixtures/embedded_edit_url.json");
+
+/// Response body of `GET /embedded/sign_url/{signature_id}`.
+pub const EMBEDDED_SIGN_URL: &str = include_str!("fixtures/embedded_sign_url.json");
+
+/// Response body of an API error.
+pub const ERROR: &str = include_str!("fixtures/error.json");
+
+/// Payload of a `signature_request_sent` callback, signed with `TEST_API_KEY`.
+pub const EVENT_CALLBACK: &str = include_str!("fixtures/event_callback.json");
+
+/// Response body of `GET /fax/{fax_id}`.
+pub const FAX: &str = include_str!("fixtures/fax.json");
+
+/// Response body of `GET /fax_line`.
+pub const FAX_LINE: &str = include_str!("fixtures/fax_line.json");
+
+/// Response body of `GET /fax_line/area_codes`.
+pub const FAX_LINE_AREA_CODES: &str = include_str!("fixtures/fax_line_area_codes.json");
+
+/// Response body of `GET /fax_line/list`.
+pub const FAX_LINE_LIST: &str = include_str!("fixtures/fax_line_list.json");
+
+/// Response body of `GET /fax/list`.
+pub const FAX_LIST: &str = include_str!("fixtures/fax_list.json");
+
+/// Response body of an OAuth token exchange.
+pub const OAUTH_TOKEN: &str = include_str!("fixtures/oauth_token.json");
+
+/// Response body of `POST /report/create`.
+pub const REPORT: &str = include_str!("fixtures/report.json");
+
+/// Response body of `GET /signature_request/{signature_request_id}`.
+pub const SIGNATURE_REQUEST: &str = include_str!("fixtures/signature_request.json");
+
+/// Response body of `GET /signature_request/list`.
+pub const SIGNATURE_REQUEST_LIST: &str = include_str!("fixtures/signature_request_list.json");
+
+/// Response body of `GET /team/sub_teams/{team_id}`.
+pub const SUB_TEAMS: &str = include_str!("fixtures/sub_teams.json");
+
+/// Response body of `GET /team`.
+pub const TEAM: &str = include_str!("fixtures/team.json");
+
+/// Response body of `GET /team/invites`.
+pub const TEAM_INVITES: &str = include_str!("fixtures/team_invites.json");
+
+/// Response body of `GET /team/members/{team_id}`.
+pub const TEAM_MEMBERS: &str = include_str!("fixtures/team_members.json");
+
+/// Response body of `GET /template/{template_id}`.
+pub const TEMPLATE: &str = include_str!("fixtures/template.json");
+
+/// Response body of `GET /template/list`.
+pub const TEMPLATE_LIST: &str = include_str!("fixtures/template_list.json");
+
+/// Response body of `POST /unclaimed_draft/create`.
+pub const UNCLAIMED_DRAFT: &str = include_str!("fixtures/unclaimed_draft.json");
+
+/// Minimal PDF returned by file download endpoints.
+pub const PDF: &[u8] = b"%PDF-1.4\n1 0 obj << /Type /Catalog /Pages 2 0 R >> endobj\n2 0 obj << /Type /Pages /Kids [] /Count 0 >> endobj\ntrailer << /Root 1 0 R >>\n%%EOF\n";
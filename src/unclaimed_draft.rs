@@ -0,0 +1,953 @@
+//! Data models and types for unclaimed draft operations.
+//!
+//! This module contains the data structures needed for creating unclaimed drafts
+//! through the Dropbox Sign API. An unclaimed draft is a signature request that
+//! has been prepared but not sent, allowing the end user to finish preparing it.
+
+use crate::file::UploadFile;
+use crate::id::{ClientId, SignatureRequestId};
+use crate::metadata::{self, Metadata, MetadataValue};
+use crate::signature_request::{SignerAuth, SubCustomField, SubSigningOptions};
+use crate::validate::{Checks, Validate, ValidationError};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Request structure for creating an unclaimed draft.
+///
+/// The draft type decides what the claiming user can do: send the document for
+/// signature to others, or sign it themselves.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::unclaimed_draft::*;
+///
+/// let signer = SubUnclaimedDraftSigner::new(
+///     "John Doe".to_string(),
+///     "john@example.com".to_string()
+/// );
+///
+/// let request = CreateUnclaimedDraftRequest::new(UnclaimedDraftType::RequestSignature)
+///     .file_urls(vec!["https://example.com/contract.pdf".to_string()])
+///     .signers(vec![signer])
+///     .test_mode(true);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateUnclaimedDraftRequest {
+    /// Type of unclaimed draft to create
+    #[serde(rename = "type")]
+    pub draft_type: UnclaimedDraftType,
+    /// Files to upload (alternative to file_urls), sent as multipart form data
+    #[serde(skip)]
+    pub files: Option<Vec<UploadFile>>,
+    /// URLs to files to be signed (alternative to files)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_urls: Option<Vec<String>>,
+    /// Whether signers can decline to sign (default: false)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_decline: Option<bool>,
+    /// Email addresses that should be CCed on the signature request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cc_email_addresses: Option<Vec<String>>,
+    /// Client ID for API apps
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<ClientId>,
+    /// Custom form fields to pre-populate in the document
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_fields: Option<Vec<SubCustomField>>,
+    /// Unix timestamp when the resulting signature request will expire
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<u64>,
+    /// Whether text tags should be hidden in the final document
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hide_text_tags: Option<bool>,
+    /// Custom message to include in the signature request email
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Key-value pairs for storing custom data with the signature request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    /// Whether the progress stepper is shown to the signer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub show_progress_stepper: Option<bool>,
+    /// Signers to pre-populate on the draft
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signers: Option<Vec<SubUnclaimedDraftSigner>>,
+    /// Configuration for signature methods and options
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signing_options: Option<SubSigningOptions>,
+    /// URL to redirect signers to after completing their signature
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signing_redirect_url: Option<String>,
+    /// Subject line used in the signature request email
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    /// Whether to create the draft in test mode
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub test_mode: Option<bool>,
+    /// Whether text tags in the documents should be converted to form fields
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub use_text_tags: Option<bool>,
+}
+
+/// Request structure for creating an embedded unclaimed draft.
+///
+/// Embedded drafts are claimed inside your own application through the embedded
+/// requesting flow rather than on the Dropbox Sign website. The requester email
+/// address and API app client ID are required.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::unclaimed_draft::*;
+///
+/// let request = CreateEmbeddedUnclaimedDraftRequest::new(
+///     "client-id".to_string(),
+///     "requester@example.com".to_string()
+/// )
+/// .file_urls(vec!["https://example.com/contract.pdf".to_string()])
+/// .requesting_redirect_url("https://example.com/sent".to_string())
+/// .test_mode(true);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateEmbeddedUnclaimedDraftRequest {
+    /// Client ID of the API app the draft is created through
+    pub client_id: ClientId,
+    /// Email address of the user who will claim and send the draft
+    pub requester_email_address: String,
+    /// Whether the requester can add CC recipients (default: true)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_ccs: Option<bool>,
+    /// Which parts of the request the requester is allowed to edit
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub editor_options: Option<SubEditorOptions>,
+    /// Whether the requester is prevented from changing signer roles
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub force_signer_roles: Option<bool>,
+    /// Whether the requester is prevented from changing the subject and message
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub force_subject_message: Option<bool>,
+    /// Whether the requester is prevented from adding themselves as a signer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skip_me_now: Option<bool>,
+    /// Type of unclaimed draft to create (default: request_signature)
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub draft_type: Option<UnclaimedDraftType>,
+    /// Files to upload (alternative to file_urls), sent as multipart form data
+    #[serde(skip)]
+    pub files: Option<Vec<UploadFile>>,
+    /// URLs to files to be signed (alternative to files)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_urls: Option<Vec<String>>,
+    /// Whether signers can decline to sign (default: false)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_decline: Option<bool>,
+    /// Email addresses that should be CCed on the signature request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cc_email_addresses: Option<Vec<String>>,
+    /// Custom form fields to pre-populate in the document
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_fields: Option<Vec<SubCustomField>>,
+    /// Unix timestamp when the resulting signature request will expire
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<u64>,
+    /// Whether text tags should be hidden in the final document
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hide_text_tags: Option<bool>,
+    /// Whether the request is held for approval after it is sent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hold_request: Option<bool>,
+    /// Whether the resulting signature request is signed through embedded signing
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_for_embedded_signing: Option<bool>,
+    /// Custom message to include in the signature request email
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// Key-value pairs for storing custom data with the signature request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    /// URL to redirect the requester to after they send the request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requesting_redirect_url: Option<String>,
+    /// Whether the requester sees a preview before sending
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub show_preview: Option<bool>,
+    /// Whether the progress stepper is shown to the signer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub show_progress_stepper: Option<bool>,
+    /// Signers to pre-populate on the draft
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signers: Option<Vec<SubUnclaimedDraftSigner>>,
+    /// Configuration for signature methods and options
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signing_options: Option<SubSigningOptions>,
+    /// URL to redirect signers to after completing their signature
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signing_redirect_url: Option<String>,
+    /// Subject line used in the signature request email
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    /// Whether to create the draft in test mode
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub test_mode: Option<bool>,
+    /// Title for the signature request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Whether text tags in the documents should be converted to form fields
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub use_text_tags: Option<bool>,
+}
+
+/// Controls which parts of an embedded request the requester can edit.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubEditorOptions {
+    /// Whether the requester can add, remove or change signers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_edit_signers: Option<bool>,
+    /// Whether the requester can add or remove documents
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_edit_documents: Option<bool>,
+}
+
+/// Specifies what the claiming user does with an unclaimed draft.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UnclaimedDraftType {
+    /// The claiming user sends the document to others for signature
+    SendDocument,
+    /// The claiming user signs the document themselves
+    RequestSignature,
+}
+
+/// Represents a signer pre-populated on an unclaimed draft.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubUnclaimedDraftSigner {
+    /// Full name of the signer
+    pub name: String,
+    /// Email address of the signer
+    pub email_address: String,
+    /// Signing order (for sequential signing workflows)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<i32>,
+    /// Additional authentication, or SMS delivery, for the signer
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub auth: Option<SignerAuth>,
+}
+
+/// Response data for a created unclaimed draft.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnclaimedDraftResponse {
+    /// Identifier of the signature request the draft will become
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature_request_id: Option<SignatureRequestId>,
+    /// URL the end user opens to claim and finish the draft
+    pub claim_url: String,
+    /// URL to redirect signers after they complete signing
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signing_redirect_url: Option<String>,
+    /// URL to redirect the requester after they send the request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requesting_redirect_url: Option<String>,
+    /// Unix timestamp when the claim URL expires
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<u64>,
+    /// Whether this draft was created in test mode
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub test_mode: Option<bool>,
+    /// Fields returned by the API that this crate does not model yet
+    #[serde(
+        flatten,
+        default,
+        deserialize_with = "crate::strict::extra",
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    pub extra: HashMap<String, Value>,
+}
+
+impl CreateUnclaimedDraftRequest {
+    /// Creates a new unclaimed draft request of the given type.
+    ///
+    /// # Arguments
+    ///
+    /// * `draft_type` - Whether the claiming user sends the document or signs it
+    pub fn new(draft_type: UnclaimedDraftType) -> Self {
+        Self {
+            draft_type,
+            files: None,
+            file_urls: None,
+            allow_decline: None,
+            cc_email_addresses: None,
+            client_id: None,
+            custom_fields: None,
+            expires_at: None,
+            hide_text_tags: None,
+            message: None,
+            metadata: None,
+            show_progress_stepper: None,
+            signers: None,
+            signing_options: None,
+            signing_redirect_url: None,
+            subject: None,
+            test_mode: None,
+            use_text_tags: None,
+        }
+    }
+
+    /// Sets the files to upload as documents to be signed.
+    ///
+    /// # Arguments
+    ///
+    /// * `files` - List of file contents, paths or readers to upload
+    pub fn files<F: Into<UploadFile>>(mut self, files: Vec<F>) -> Self {
+        self.files = Some(files.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Adds a file to upload as a document to be signed.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - File contents, path or reader to upload
+    pub fn file(mut self, file: impl Into<UploadFile>) -> Self {
+        self.files.get_or_insert_with(Vec::new).push(file.into());
+        self
+    }
+
+    /// Sets URLs to files that should be downloaded and used as documents.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_urls` - List of publicly accessible URLs to PDF files
+    pub fn file_urls(mut self, file_urls: Vec<String>) -> Self {
+        self.file_urls = Some(file_urls);
+        self
+    }
+
+    /// Sets whether signers can decline to sign the document.
+    ///
+    /// # Arguments
+    ///
+    /// * `allow_decline` - If true, signers can decline to sign
+    pub fn allow_decline(mut self, allow_decline: bool) -> Self {
+        self.allow_decline = Some(allow_decline);
+        self
+    }
+
+    /// Sets the email addresses to CC on the signature request.
+    ///
+    /// # Arguments
+    ///
+    /// * `cc_email_addresses` - Email addresses that receive copies of the request
+    pub fn cc_email_addresses(mut self, cc_email_addresses: Vec<String>) -> Self {
+        self.cc_email_addresses = Some(cc_email_addresses);
+        self
+    }
+
+    /// Sets the client ID for API apps.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - Client ID for your API app
+    pub fn client_id(mut self, client_id: impl Into<ClientId>) -> Self {
+        self.client_id = Some(client_id.into());
+        self
+    }
+
+    /// Sets custom form fields to pre-populate in the document.
+    ///
+    /// # Arguments
+    ///
+    /// * `custom_fields` - List of custom fields with default values
+    pub fn custom_fields(mut self, custom_fields: Vec<SubCustomField>) -> Self {
+        self.custom_fields = Some(custom_fields);
+        self
+    }
+
+    /// Sets when the resulting signature request expires.
+    ///
+    /// # Arguments
+    ///
+    /// * `expires_at` - Unix timestamp of the expiry time
+    pub fn expires_at(mut self, expires_at: u64) -> Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+
+    /// Sets whether text tags should be hidden in the final document.
+    ///
+    /// # Arguments
+    ///
+    /// * `hide_text_tags` - True to hide text tags
+    pub fn hide_text_tags(mut self, hide_text_tags: bool) -> Self {
+        self.hide_text_tags = Some(hide_text_tags);
+        self
+    }
+
+    /// Sets a custom message to include in signature request emails.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - Custom message text
+    pub fn message(mut self, message: String) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sets custom metadata key-value pairs for the signature request.
+    ///
+    /// # Arguments
+    ///
+    /// * `metadata` - Key-value pairs for storing custom data, with string,
+    ///   integer or boolean values
+    pub fn metadata<K, V>(mut self, metadata: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: Into<String>,
+        V: Into<MetadataValue>,
+    {
+        self.metadata = Some(metadata::collect(metadata));
+        self
+    }
+
+    /// Sets whether the progress stepper is shown to the signer.
+    ///
+    /// # Arguments
+    ///
+    /// * `show_progress_stepper` - True to show the progress stepper
+    pub fn show_progress_stepper(mut self, show_progress_stepper: bool) -> Self {
+        self.show_progress_stepper = Some(show_progress_stepper);
+        self
+    }
+
+    /// Sets the signers to pre-populate on the draft.
+    ///
+    /// # Arguments
+    ///
+    /// * `signers` - List of signers for the draft
+    pub fn signers(mut self, signers: Vec<SubUnclaimedDraftSigner>) -> Self {
+        self.signers = Some(signers);
+        self
+    }
+
+    /// Sets configuration for available signature methods.
+    ///
+    /// # Arguments
+    ///
+    /// * `signing_options` - Configuration for signature method preferences
+    pub fn signing_options(mut self, signing_options: SubSigningOptions) -> Self {
+        self.signing_options = Some(signing_options);
+        self
+    }
+
+    /// Sets the URL to redirect signers to after they complete signing.
+    ///
+    /// # Arguments
+    ///
+    /// * `signing_redirect_url` - URL for post-signing redirect
+    pub fn signing_redirect_url(mut self, signing_redirect_url: String) -> Self {
+        self.signing_redirect_url = Some(signing_redirect_url);
+        self
+    }
+
+    /// Sets the subject line of the signature request email.
+    ///
+    /// # Arguments
+    ///
+    /// * `subject` - Subject line text
+    pub fn subject(mut self, subject: String) -> Self {
+        self.subject = Some(subject);
+        self
+    }
+
+    /// Sets whether to create the draft in test mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `test_mode` - True for test mode (no emails sent, no charges apply)
+    pub fn test_mode(mut self, test_mode: bool) -> Self {
+        self.test_mode = Some(test_mode);
+        self
+    }
+
+    /// Sets whether text tags in the documents are converted to form fields.
+    ///
+    /// # Arguments
+    ///
+    /// * `use_text_tags` - True to process text tags
+    pub fn use_text_tags(mut self, use_text_tags: bool) -> Self {
+        self.use_text_tags = Some(use_text_tags);
+        self
+    }
+}
+
+impl CreateEmbeddedUnclaimedDraftRequest {
+    /// Creates a new embedded unclaimed draft request with the required fields.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_id` - Client ID of your API app
+    /// * `requester_email_address` - Email address of the user who will send the draft
+    pub fn new(client_id: impl Into<ClientId>, requester_email_address: String) -> Self {
+        Self {
+            client_id: client_id.into(),
+            requester_email_address,
+            allow_ccs: None,
+            editor_options: None,
+            force_signer_roles: None,
+            force_subject_message: None,
+            skip_me_now: None,
+            draft_type: None,
+            files: None,
+            file_urls: None,
+            allow_decline: None,
+            cc_email_addresses: None,
+            custom_fields: None,
+            expires_at: None,
+            hide_text_tags: None,
+            hold_request: None,
+            is_for_embedded_signing: None,
+            message: None,
+            metadata: None,
+            requesting_redirect_url: None,
+            show_preview: None,
+            show_progress_stepper: None,
+            signers: None,
+            signing_options: None,
+            signing_redirect_url: None,
+            subject: None,
+            test_mode: None,
+            title: None,
+            use_text_tags: None,
+        }
+    }
+
+    /// Sets whether the requester can add CC recipients.
+    ///
+    /// # Arguments
+    ///
+    /// * `allow_ccs` - False to prevent the requester from adding CCs
+    pub fn allow_ccs(mut self, allow_ccs: bool) -> Self {
+        self.allow_ccs = Some(allow_ccs);
+        self
+    }
+
+    /// Sets which parts of the request the requester can edit.
+    ///
+    /// # Arguments
+    ///
+    /// * `editor_options` - Editing permissions for the requester
+    pub fn editor_options(mut self, editor_options: SubEditorOptions) -> Self {
+        self.editor_options = Some(editor_options);
+        self
+    }
+
+    /// Sets whether the requester is prevented from changing signer roles.
+    ///
+    /// # Arguments
+    ///
+    /// * `force_signer_roles` - True to lock the signer roles
+    pub fn force_signer_roles(mut self, force_signer_roles: bool) -> Self {
+        self.force_signer_roles = Some(force_signer_roles);
+        self
+    }
+
+    /// Sets whether the requester is prevented from changing the subject and message.
+    ///
+    /// # Arguments
+    ///
+    /// * `force_subject_message` - True to lock the subject and message
+    pub fn force_subject_message(mut self, force_subject_message: bool) -> Self {
+        self.force_subject_message = Some(force_subject_message);
+        self
+    }
+
+    /// Sets whether the requester is prevented from adding themselves as a signer.
+    ///
+    /// # Arguments
+    ///
+    /// * `skip_me_now` - True to hide the "Me (Now)" signing option
+    pub fn skip_me_now(mut self, skip_me_now: bool) -> Self {
+        self.skip_me_now = Some(skip_me_now);
+        self
+    }
+
+    /// Sets the type of unclaimed draft to create.
+    ///
+    /// # Arguments
+    ///
+    /// * `draft_type` - Whether the claiming user sends the document or signs it
+    pub fn draft_type(mut self, draft_type: UnclaimedDraftType) -> Self {
+        self.draft_type = Some(draft_type);
+        self
+    }
+
+    /// Sets the files to upload as documents to be signed.
+    ///
+    /// # Arguments
+    ///
+    /// * `files` - List of file contents, paths or readers to upload
+    pub fn files<F: Into<UploadFile>>(mut self, files: Vec<F>) -> Self {
+        self.files = Some(files.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Adds a file to upload as a document to be signed.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - File contents, path or reader to upload
+    pub fn file(mut self, file: impl Into<UploadFile>) -> Self {
+        self.files.get_or_insert_with(Vec::new).push(file.into());
+        self
+    }
+
+    /// Sets URLs to files that should be downloaded and used as documents.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_urls` - List of publicly accessible URLs to PDF files
+    pub fn file_urls(mut self, file_urls: Vec<String>) -> Self {
+        self.file_urls = Some(file_urls);
+        self
+    }
+
+    /// Sets whether signers can decline to sign the document.
+    ///
+    /// # Arguments
+    ///
+    /// * `allow_decline` - If true, signers can decline to sign
+    pub fn allow_decline(mut self, allow_decline: bool) -> Self {
+        self.allow_decline = Some(allow_decline);
+        self
+    }
+
+    /// Sets the email addresses to CC on the signature request.
+    ///
+    /// # Arguments
+    ///
+    /// * `cc_email_addresses` - Email addresses that receive copies of the request
+    pub fn cc_email_addresses(mut self, cc_email_addresses: Vec<String>) -> Self {
+        self.cc_email_addresses = Some(cc_email_addresses);
+        self
+    }
+
+    /// Sets custom form fields to pre-populate in the document.
+    ///
+    /// # Arguments
+    ///
+    /// * `custom_fields` - List of custom fields with default values
+    pub fn custom_fields(mut self, custom_fields: Vec<SubCustomField>) -> Self {
+        self.custom_fields = Some(custom_fields);
+        self
+    }
+
+    /// Sets when the resulting signature request expires.
+    ///
+    /// # Arguments
+    ///
+    /// * `expires_at` - Unix timestamp of the expiry time
+    pub fn expires_at(mut self, expires_at: u64) -> Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+
+    /// Sets whether text tags should be hidden in the final document.
+    ///
+    /// # Arguments
+    ///
+    /// * `hide_text_tags` - True to hide text tags
+    pub fn hide_text_tags(mut self, hide_text_tags: bool) -> Self {
+        self.hide_text_tags = Some(hide_text_tags);
+        self
+    }
+
+    /// Sets whether the request is held for approval after it is sent.
+    ///
+    /// # Arguments
+    ///
+    /// * `hold_request` - True to hold the request for approval
+    pub fn hold_request(mut self, hold_request: bool) -> Self {
+        self.hold_request = Some(hold_request);
+        self
+    }
+
+    /// Sets whether the resulting signature request uses embedded signing.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_for_embedded_signing` - True if signers sign inside your application
+    pub fn is_for_embedded_signing(mut self, is_for_embedded_signing: bool) -> Self {
+        self.is_for_embedded_signing = Some(is_for_embedded_signing);
+        self
+    }
+
+    /// Sets a custom message to include in signature request emails.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - Custom message text
+    pub fn message(mut self, message: String) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sets custom metadata key-value pairs for the signature request.
+    ///
+    /// # Arguments
+    ///
+    /// * `metadata` - Key-value pairs for storing custom data, with string,
+    ///   integer or boolean values
+    pub fn metadata<K, V>(mut self, metadata: impl IntoIterator<Item = (K, V)>) -> Self
+    where
+        K: Into<String>,
+        V: Into<MetadataValue>,
+    {
+        self.metadata = Some(metadata::collect(metadata));
+        self
+    }
+
+    /// Sets the URL to redirect the requester to after they send the request.
+    ///
+    /// # Arguments
+    ///
+    /// * `requesting_redirect_url` - URL for post-sending redirect
+    pub fn requesting_redirect_url(mut self, requesting_redirect_url: String) -> Self {
+        self.requesting_redirect_url = Some(requesting_redirect_url);
+        self
+    }
+
+    /// Sets whether the requester sees a preview before sending.
+    ///
+    /// # Arguments
+    ///
+    /// * `show_preview` - True to show the preview step
+    pub fn show_preview(mut self, show_preview: bool) -> Self {
+        self.show_preview = Some(show_preview);
+        self
+    }
+
+    /// Sets whether the progress stepper is shown to the signer.
+    ///
+    /// # Arguments
+    ///
+    /// * `show_progress_stepper` - True to show the progress stepper
+    pub fn show_progress_stepper(mut self, show_progress_stepper: bool) -> Self {
+        self.show_progress_stepper = Some(show_progress_stepper);
+        self
+    }
+
+    /// Sets the signers to pre-populate on the draft.
+    ///
+    /// # Arguments
+    ///
+    /// * `signers` - List of signers for the draft
+    pub fn signers(mut self, signers: Vec<SubUnclaimedDraftSigner>) -> Self {
+        self.signers = Some(signers);
+        self
+    }
+
+    /// Sets configuration for available signature methods.
+    ///
+    /// # Arguments
+    ///
+    /// * `signing_options` - Configuration for signature method preferences
+    pub fn signing_options(mut self, signing_options: SubSigningOptions) -> Self {
+        self.signing_options = Some(signing_options);
+        self
+    }
+
+    /// Sets the URL to redirect signers to after they complete signing.
+    ///
+    /// # Arguments
+    ///
+    /// * `signing_redirect_url` - URL for post-signing redirect
+    pub fn signing_redirect_url(mut self, signing_redirect_url: String) -> Self {
+        self.signing_redirect_url = Some(signing_redirect_url);
+        self
+    }
+
+    /// Sets the subject line of the signature request email.
+    ///
+    /// # Arguments
+    ///
+    /// * `subject` - Subject line text
+    pub fn subject(mut self, subject: String) -> Self {
+        self.subject = Some(subject);
+        self
+    }
+
+    /// Sets whether to create the draft in test mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `test_mode` - True for test mode (no emails sent, no charges apply)
+    pub fn test_mode(mut self, test_mode: bool) -> Self {
+        self.test_mode = Some(test_mode);
+        self
+    }
+
+    /// Sets the title for the signature request.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - Title that will appear in emails and the signing interface
+    pub fn title(mut self, title: String) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets whether text tags in the documents are converted to form fields.
+    ///
+    /// # Arguments
+    ///
+    /// * `use_text_tags` - True to process text tags
+    pub fn use_text_tags(mut self, use_text_tags: bool) -> Self {
+        self.use_text_tags = Some(use_text_tags);
+        self
+    }
+}
+
+impl SubEditorOptions {
+    /// Creates new editor options with every permission left at the API default.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether the requester can add, remove or change signers.
+    ///
+    /// # Arguments
+    ///
+    /// * `allow_edit_signers` - True to allow editing signers
+    pub fn allow_edit_signers(mut self, allow_edit_signers: bool) -> Self {
+        self.allow_edit_signers = Some(allow_edit_signers);
+        self
+    }
+
+    /// Sets whether the requester can add or remove documents.
+    ///
+    /// # Arguments
+    ///
+    /// * `allow_edit_documents` - True to allow editing documents
+    pub fn allow_edit_documents(mut self, allow_edit_documents: bool) -> Self {
+        self.allow_edit_documents = Some(allow_edit_documents);
+        self
+    }
+}
+
+impl SubUnclaimedDraftSigner {
+    /// Creates a new unclaimed draft signer.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Full name of the signer
+    /// * `email_address` - Email address of the signer
+    pub fn new(name: String, email_address: String) -> Self {
+        Self {
+            name,
+            email_address,
+            order: None,
+            auth: None,
+        }
+    }
+
+    /// Creates a new unclaimed draft signer, checking that the name is not blank
+    /// and that the email address is valid.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Full name of the signer
+    /// * `email_address` - Email address of the signer
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError`] listing every invalid argument.
+    pub fn try_new(name: String, email_address: String) -> Result<Self, ValidationError> {
+        let mut checks = Checks::new();
+        checks.not_blank(&name, "name");
+        checks.email(&email_address, "email_address");
+        checks.finish()?;
+
+        Ok(Self::new(name, email_address))
+    }
+
+    /// Sets the signing order for this signer.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - Position of the signer in a sequential signing workflow
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets how the signer authenticates, or has the request delivered by SMS.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` - PIN, SMS authentication or SMS delivery settings
+    pub fn auth(mut self, auth: SignerAuth) -> Self {
+        self.auth = Some(auth);
+        self
+    }
+}
+
+impl Validate for CreateUnclaimedDraftRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut checks = Checks::new();
+
+        checks.exclusive(
+            ("files", self.files.is_some()),
+            ("file_urls", self.file_urls.is_some()),
+        );
+        validate_recipients(
+            &mut checks,
+            self.signers.as_deref(),
+            self.cc_email_addresses.as_deref(),
+        );
+        metadata::check(&mut checks, self.metadata.as_ref());
+
+        checks.finish()
+    }
+}
+
+impl Validate for CreateEmbeddedUnclaimedDraftRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut checks = Checks::new();
+
+        checks.not_blank(self.client_id.as_str(), "client_id");
+        checks.email(&self.requester_email_address, "requester_email_address");
+        checks.exclusive(
+            ("files", self.files.is_some()),
+            ("file_urls", self.file_urls.is_some()),
+        );
+        validate_recipients(
+            &mut checks,
+            self.signers.as_deref(),
+            self.cc_email_addresses.as_deref(),
+        );
+        metadata::check(&mut checks, self.metadata.as_ref());
+
+        checks.finish()
+    }
+}
+
+/// Checks the signers and CC email addresses of a draft.
+fn validate_recipients(
+    checks: &mut Checks,
+    signers: Option<&[SubUnclaimedDraftSigner]>,
+    cc_email_addresses: Option<&[String]>,
+) {
+    for (index, signer) in signers.into_iter().flatten().enumerate() {
+        checks.not_blank(&signer.name, format!("signers[{index}][name]"));
+        checks.email(
+            &signer.email_address,
+            format!("signers[{index}][email_address]"),
+        );
+        if let Some(auth) = &signer.auth {
+            checks.nested(format!("signers[{index}]"), auth.validate());
+        }
+    }
+    for (index, email_address) in cc_email_addresses.into_iter().flatten().enumerate() {
+        checks.email(email_address, format!("cc_email_addresses[{index}]"));
+    }
+}
@@ -0,0 +1,175 @@
+//! Local validation of requests before they are sent.
+//!
+//! Every request struct implements [`Validate`], and the client validates a
+//! request before sending it, so mistakes the API would reject (a signature
+//! request without signers, both `files` and `file_urls`, ...) fail immediately
+//! without a round trip. Field paths use the same bracketed form as the API's
+//! `error_path`, e.g. `signers[0][email_address]`.
+//!
+//! Values that are easy to get wrong, such as signer email addresses, PINs and
+//! SMS phone numbers, can also be checked when a request is built, through the
+//! `try_*` constructors of the request types.
+
+use std::fmt;
+use thiserror::Error;
+
+/// Checks a request for mistakes the API would reject.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dropboxsign_client::signature_request::SendWithTemplateRequest;
+/// use dropboxsign_client::validate::Validate;
+///
+/// let request = SendWithTemplateRequest::new(vec![], vec!["template-id".to_string()]);
+///
+/// let error = request.validate().unwrap_err();
+/// assert_eq!(error.errors[0].error_path, "signers");
+/// ```
+pub trait Validate {
+    /// Validates the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ValidationError`] listing every invalid field.
+    fn validate(&self) -> Result<(), ValidationError>;
+}
+
+/// A request that failed local validation.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("invalid request: {}", DisplayErrors(.errors))]
+pub struct ValidationError {
+    /// Every invalid field, in the order the fields are checked
+    pub errors: Vec<FieldError>,
+}
+
+/// A single invalid field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    /// Path of the field, in the same form as the API's `error_path`
+    pub error_path: String,
+    /// Human-readable description of the problem
+    pub error_msg: String,
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.error_path, self.error_msg)
+    }
+}
+
+/// Formats field errors as a `; `-separated list.
+struct DisplayErrors<'a>(&'a [FieldError]);
+
+impl fmt::Display for DisplayErrors<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, error) in self.0.iter().enumerate() {
+            if index > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{error}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Collects field errors while a request is validated.
+#[derive(Debug, Default)]
+pub(crate) struct Checks {
+    errors: Vec<FieldError>,
+}
+
+impl Checks {
+    /// Creates an empty set of checks.
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records an error for `path` unless `valid` holds.
+    pub(crate) fn check(&mut self, valid: bool, path: impl fmt::Display, error_msg: &str) {
+        if !valid {
+            self.errors.push(FieldError {
+                error_path: path.to_string(),
+                error_msg: error_msg.to_string(),
+            });
+        }
+    }
+
+    /// Records an error unless `value` is non-blank.
+    pub(crate) fn not_blank(&mut self, value: &str, path: impl fmt::Display) {
+        self.check(!value.trim().is_empty(), path, "must not be blank");
+    }
+
+    /// Records an error unless `value` looks like an email address.
+    pub(crate) fn email(&mut self, value: &str, path: impl fmt::Display) {
+        let valid = value
+            .split_once('@')
+            .is_some_and(|(local, domain)| !local.is_empty() && domain.contains('.'));
+        self.check(valid, path, "must be an email address");
+    }
+
+    /// Records an error unless `value` is a PIN of 4 to 12 digits.
+    pub(crate) fn pin(&mut self, value: &str, path: impl fmt::Display) {
+        let valid = (4..=12).contains(&value.len()) && value.chars().all(|c| c.is_ascii_digit());
+        self.check(valid, path, "must be 4 to 12 digits");
+    }
+
+    /// Records an error unless `value` is a phone number in E.164 format, e.g.
+    /// `+14155550100`.
+    pub(crate) fn phone_number(&mut self, value: &str, path: impl fmt::Display) {
+        let valid = value.strip_prefix('+').is_some_and(|digits| {
+            (2..=15).contains(&digits.len())
+                && !digits.starts_with('0')
+                && digits.chars().all(|c| c.is_ascii_digit())
+        });
+        self.check(valid, path, "must be a phone number in E.164 format");
+    }
+
+    /// Records an error if both of two mutually exclusive fields are set.
+    pub(crate) fn exclusive(&mut self, first: (&str, bool), second: (&str, bool)) {
+        self.check(
+            !(first.1 && second.1),
+            second.0,
+            &format!("cannot be combined with {}", first.0),
+        );
+    }
+
+    /// Adds the errors of a nested value, prefixing their paths with `path`.
+    pub(crate) fn nested(&mut self, path: impl fmt::Display, result: Result<(), ValidationError>) {
+        if let Err(error) = result {
+            self.errors
+                .extend(error.errors.into_iter().map(|error| FieldError {
+                    error_path: nest(&path, &error.error_path),
+                    error_msg: error.error_msg,
+                }));
+        }
+    }
+
+    /// Adds the errors of a value whose fields are sent alongside the fields
+    /// being checked, keeping their paths.
+    pub(crate) fn nested_flat(&mut self, result: Result<(), ValidationError>) {
+        if let Err(error) = result {
+            self.errors.extend(error.errors);
+        }
+    }
+
+    /// Returns the collected errors, if any.
+    pub(crate) fn finish(self) -> Result<(), ValidationError> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationError {
+                errors: self.errors,
+            })
+        }
+    }
+}
+
+/// Joins a parent path and a nested field path, e.g. `signers[0]` and `name`
+/// into `signers[0][name]`.
+fn nest(parent: &impl fmt::Display, path: &str) -> String {
+    match path.split_once('[') {
+        Some((field, rest)) => format!("{parent}[{field}][{rest}"),
+        None => format!("{parent}[{path}]"),
+    }
+}